@@ -0,0 +1,18 @@
+#import bevy_pbr::forward_io::VertexOutput
+
+struct SolidsShaderModel {
+    light_direction: vec4<f32>,
+};
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(0) var<uniform> shader_model: SolidsShaderModel;
+
+// A simple diffuse shading using the normals emitted by the solid primitives.
+@fragment
+fn fragment(
+    mesh: VertexOutput,
+) -> @location(0) vec4<f32> {
+    let normal = normalize(mesh.world_normal);
+    let light = normalize(shader_model.light_direction.xyz);
+    let shade = 0.25 + 0.75 * max(dot(normal, light), 0.0);
+    return vec4<f32>(mesh.color.rgb * shade, mesh.color.a);
+}
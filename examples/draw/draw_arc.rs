@@ -0,0 +1,64 @@
+//! Arcs, pies and rings: a gauge, a pie chart and a spinner.
+
+use nannou::prelude::*;
+
+fn main() {
+    nannou::sketch(view).run()
+}
+
+fn view(app: &App) {
+    let win = app.window_rect();
+    let t = app.time();
+    let draw = app.draw();
+
+    draw.background().color(BLACK);
+
+    // A gauge whose needle sweeps back and forth.
+    let gauge = draw.x(-win.w() * 0.3);
+    let value = t.sin() * 0.5 + 0.5;
+    let (start, end) = (PI * 1.25, -PI * 0.25);
+    gauge
+        .arc()
+        .radius(80.0)
+        .angles(start, end)
+        .weight(12.0)
+        .caps_round()
+        .color(DIMGRAY);
+    gauge
+        .arc()
+        .radius(80.0)
+        .angles(start, start + (end - start) * value)
+        .weight(12.0)
+        .caps_round()
+        .color(ORANGE);
+
+    // A pie chart.
+    let slices = [0.4, 0.25, 0.2, 0.15];
+    let colors = [TOMATO, GOLD, MEDIUMSEAGREEN, STEELBLUE];
+    let mut angle = PI * 0.5;
+    for (slice, color) in slices.iter().zip(colors) {
+        let sweep = -slice * TAU;
+        draw.pie()
+            .radius(80.0)
+            .angles(angle, angle + sweep)
+            .color(color)
+            .stroke(BLACK)
+            .stroke_weight(2.0);
+        angle += sweep;
+    }
+
+    // A spinner made of a full ring and a rotating partial ring.
+    let spinner = draw.x(win.w() * 0.3);
+    spinner
+        .ring()
+        .radius(80.0)
+        .inner_radius(60.0)
+        .color(Color::srgba(1.0, 1.0, 1.0, 0.2));
+    spinner
+        .rotate(-t * 3.0)
+        .ring()
+        .radius(80.0)
+        .inner_radius(60.0)
+        .angles(0.0, PI * 0.5)
+        .color(WHITE);
+}
@@ -0,0 +1,57 @@
+//! Smooth curves: a bezier, a Catmull-Rom curve through a set of points and a B-spline shaped by
+//! the same points.
+
+use nannou::prelude::*;
+
+fn main() {
+    nannou::sketch(view).run()
+}
+
+fn view(app: &App) {
+    let win = app.window_rect();
+    let t = app.time();
+    let draw = app.draw();
+
+    draw.background().color(BLACK);
+
+    // Points wandering about a circle.
+    let points: Vec<Vec2> = (0..7)
+        .map(|i| {
+            let angle = i as f32 / 7.0 * TAU;
+            let radius = 120.0 + (t * 1.5 + i as f32).sin() * 40.0;
+            Vec2::new(angle.cos(), angle.sin()) * radius
+        })
+        .collect();
+    for &p in &points {
+        draw.ellipse().xy(p).radius(4.0).color(GRAY);
+    }
+
+    // The curve passes through each point, tightening with time.
+    draw.curve()
+        .closed()
+        .tension(t.sin() * 0.5 + 0.5)
+        .no_fill()
+        .stroke(ORANGE)
+        .stroke_weight(3.0)
+        .points(points.iter().copied());
+
+    // The spline is pulled towards each point.
+    draw.spline()
+        .closed()
+        .color(Color::srgba(0.3, 0.6, 1.0, 0.3))
+        .points(points.iter().copied());
+
+    // A cubic bezier along the bottom of the window with its control points following the mouse.
+    let y = win.bottom() + 60.0;
+    let mouse = app.mouse();
+    draw.bezier()
+        .no_fill()
+        .stroke(WHITE)
+        .stroke_weight(2.0)
+        .cubic(
+            Vec2::new(win.left() + 40.0, y),
+            mouse,
+            Vec2::new(-mouse.x, mouse.y),
+            Vec2::new(win.right() - 40.0, y),
+        );
+}
@@ -0,0 +1,57 @@
+//! Load custom fonts at runtime and draw text with them.
+//!
+//! The bold italic font is loaded as an asset, so edits to the file are picked up while the app
+//! runs when asset hot reloading is enabled. The italic font is registered from bytes embedded in
+//! the binary.
+
+use nannou::prelude::*;
+
+fn main() {
+    nannou::app(model).run();
+}
+
+struct Model {
+    loaded: text::font::FontHandle,
+    embedded: Option<text::font::FontHandle>,
+}
+
+fn model(app: &App) -> Model {
+    app.new_window().size(720, 360).primary().view(view).build();
+    let path = app
+        .assets_path()
+        .join("fonts/NotoSans/NotoSans-BoldItalic.ttf");
+    let loaded = app.load_font(path);
+    let embedded = app.register_font_bytes(include_bytes!(
+        "../assets/fonts/NotoSans/NotoSans-Italic.ttf"
+    ));
+    Model { loaded, embedded }
+}
+
+fn view(app: &App, model: &Model) {
+    let draw = app.draw();
+    draw.background().color(WHITE);
+    let win = app.window_rect();
+
+    // Text is drawn with the default family until the asset has loaded.
+    let status = if model.loaded.is_loaded() {
+        "loaded from an asset"
+    } else {
+        "loading..."
+    };
+    draw.text(status)
+        .font(&model.loaded)
+        .font_size(48)
+        .color(BLACK)
+        .y(win.h() / 6.0)
+        .w(win.w());
+
+    if let Some(embedded) = &model.embedded {
+        draw.text("registered from bytes")
+            .font(embedded)
+            .italic()
+            .font_size(48)
+            .color(ROYAL_BLUE)
+            .y(-win.h() / 6.0)
+            .w(win.w());
+    }
+}
@@ -0,0 +1,47 @@
+//! A demonstration of dashed and dotted strokes via `.dashes(..)` and `.dash_offset(..)`.
+//!
+//! Animating the dash offset produces the "marching ants" effect around the selection rect.
+
+use nannou::prelude::*;
+
+fn main() {
+    nannou::sketch(view).run()
+}
+
+fn view(app: &App) {
+    let draw = app.draw();
+    draw.background().color(BLACK);
+    let win = app.window_rect();
+    let t = app.time();
+
+    // Marching ants around a selection.
+    draw.rect()
+        .w_h(300.0, 200.0)
+        .no_fill()
+        .stroke(WHITE)
+        .stroke_weight(2.0)
+        .dashes(&[8.0, 6.0])
+        .dash_offset(-t * 30.0);
+
+    // A dotted circle. Zero length dashes become dots with round caps.
+    draw.ellipse()
+        .radius(150.0)
+        .no_fill()
+        .stroke(CORNFLOWER_BLUE)
+        .stroke_weight(6.0)
+        .caps_round()
+        .dashes(&[0.0, 14.0]);
+
+    // A dash-dot sine wave.
+    let points = (0..=200).map(|i| {
+        let x = map_range(i, 0, 200, win.left(), win.right());
+        let y = (x * 0.02 + t).sin() * 60.0 + win.bottom() + 100.0;
+        pt2(x, y)
+    });
+    draw.polyline()
+        .weight(3.0)
+        .caps_round()
+        .dashes(&[20.0, 8.0, 0.0, 8.0])
+        .points(points)
+        .color(STEEL_BLUE);
+}
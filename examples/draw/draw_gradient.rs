@@ -0,0 +1,51 @@
+//! A demonstration of linear and radial gradients via `.linear_gradient(..)` and
+//! `.radial_gradient(..)`.
+//!
+//! The three bars compare interpolating the same stops in sRGB, linear RGB and Oklab.
+
+use nannou::prelude::draw::properties::gradient::Interpolation;
+use nannou::prelude::*;
+
+fn main() {
+    nannou::sketch(view).run()
+}
+
+fn view(app: &App) {
+    let draw = app.draw();
+    draw.background().color(BLACK);
+    let win = app.window_rect();
+    let t = app.time();
+
+    // The same stops interpolated in each color space.
+    let stops = [
+        (0.0, Color::srgb(0.0, 0.2, 1.0)),
+        (1.0, Color::srgb(1.0, 0.8, 0.0)),
+    ];
+    let spaces = [
+        Interpolation::Srgb,
+        Interpolation::Linear,
+        Interpolation::Oklab,
+    ];
+    let (w, h) = (win.w() * 0.8, 40.0);
+    for (i, space) in spaces.into_iter().enumerate() {
+        let y = win.top() - 60.0 - i as f32 * (h + 10.0);
+        draw.rect()
+            .x_y(0.0, y)
+            .w_h(w, h)
+            .linear_gradient(vec2(-w * 0.5, 0.0), vec2(w * 0.5, 0.0), stops)
+            .gradient_interpolation(space)
+            .gradient_subdivisions(5);
+    }
+
+    // A radial gradient with several stops, pulsing over time.
+    let radius = 120.0 + (t * 2.0).sin() * 20.0;
+    draw.ellipse()
+        .y(-60.0)
+        .radius(140.0)
+        .radial_gradient(
+            Vec2::ZERO,
+            radius,
+            [(0.0, WHITE), (0.3, CORNFLOWER_BLUE), (1.0, BLACK)],
+        )
+        .gradient_subdivisions(3);
+}
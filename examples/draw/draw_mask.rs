@@ -0,0 +1,47 @@
+//! A demonstration of `draw.mask(..)` and `draw.inverse_mask(..)`, clipping drawings to the
+//! shapes drawn within a mask.
+//!
+//! Stripes sweep across the window. On the left they are only visible within a pulsing circle,
+//! on the right they are only visible outside of a rotating star-like polygon.
+
+use nannou::prelude::*;
+
+fn main() {
+    nannou::sketch(view).run()
+}
+
+fn view(app: &App) {
+    let draw = app.draw();
+    draw.background().color(BLACK);
+    let win = app.window_rect();
+    let t = app.time();
+
+    let radius = 150.0 + (t * 2.0).sin() * 40.0;
+    let left = draw.x(-win.w() * 0.25).mask(|m| {
+        m.ellipse().radius(radius);
+    });
+    stripes(&left, win, t, 0.0);
+
+    let right = draw.x(win.w() * 0.25).rotate(t * 0.3).inverse_mask(|m| {
+        let points = (0..10).map(|i| {
+            let angle = i as f32 / 10.0 * TAU;
+            let r = if i % 2 == 0 { 150.0 } else { 70.0 };
+            vec2(angle.cos() * r, angle.sin() * r)
+        });
+        m.polygon().points(points);
+    });
+    // Keep the stripes upright while the mask rotates.
+    let right = right.rotate(-t * 0.3);
+    stripes(&right, geom::Rect::from_w_h(win.w() * 0.5, win.h()), t, 0.5);
+}
+
+fn stripes(draw: &Draw, rect: geom::Rect, t: f32, hue: f32) {
+    let n = 20;
+    for i in 0..n {
+        let x = rect.left() + (i as f32 / n as f32 * rect.w() + t * 40.0) % rect.w();
+        draw.rect()
+            .x_y(x, 0.0)
+            .w_h(rect.w() / n as f32 * 0.5, rect.h())
+            .hsl(hue + i as f32 / n as f32 * 0.2, 0.7, 0.5);
+    }
+}
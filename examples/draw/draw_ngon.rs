@@ -0,0 +1,40 @@
+//! Regular polygons with an increasing number of sides, and a pulsing star.
+
+use nannou::prelude::*;
+
+fn main() {
+    nannou::sketch(view).run()
+}
+
+fn view(app: &App) {
+    let win = app.window_rect();
+    let t = app.time();
+    let draw = app.draw();
+
+    draw.background().color(BLACK);
+
+    // A row of polygons from a triangle up to an octagon.
+    let sides = 3..=8;
+    let count = sides.clone().count();
+    for (i, n) in sides.enumerate() {
+        let x = map_range(i, 0, count - 1, win.left() + 80.0, win.right() - 80.0);
+        let hue = i as f32 / count as f32;
+        draw.ngon()
+            .sides(n)
+            .radius(40.0)
+            .x_y(x, win.h() * 0.2)
+            .rotate(t * 0.5)
+            .color(Color::hsl(hue * 360.0, 0.7, 0.5))
+            .stroke(WHITE)
+            .stroke_weight(2.0);
+    }
+
+    // A star whose inner radius pulses.
+    let inner = 30.0 + (t * 2.0).sin() * 20.0;
+    draw.star()
+        .points(7)
+        .radius(80.0)
+        .inner_radius(inner)
+        .y(-win.h() * 0.2)
+        .color(GOLD);
+}
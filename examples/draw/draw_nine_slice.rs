@@ -0,0 +1,39 @@
+//! Demonstrates resizing a texture via a nine-slice, keeping its corners undistorted.
+//!
+//! Move the mouse to resize the panels. The left panel is drawn as a nine-slice, while the right
+//! panel stretches the whole texture for comparison.
+
+use nannou::prelude::*;
+
+fn main() {
+    nannou::app(model).run();
+}
+
+struct Model {
+    texture: Handle<Image>,
+}
+
+fn model(app: &App) -> Model {
+    app.new_window().size(800, 480).primary().view(view).build();
+    let assets = app.assets_path();
+    let img_path = assets.join("images").join("nannou.png");
+    let texture = app.asset_server().load(img_path);
+    Model { texture }
+}
+
+fn view(app: &App, model: &Model) {
+    let draw = app.draw();
+    draw.background().color(DIM_GRAY);
+
+    let mouse = app.mouse();
+    let w = map_range(mouse.x.abs(), 0.0, 400.0, 100.0, 360.0);
+    let h = map_range(mouse.y.abs(), 0.0, 240.0, 100.0, 440.0);
+
+    // The texture is 543 pixels square, with corners we want to preserve 120 pixels across.
+    draw.nine_slice(&model.texture)
+        .texture_size(543.0, 543.0)
+        .borders(120.0, 120.0, 120.0, 120.0)
+        .x(-200.0)
+        .w_h(w, h);
+    draw.rect().texture(&model.texture).x(200.0).w_h(w, h);
+}
@@ -0,0 +1,69 @@
+//! Record a complex drawing once as a `Picture`, then replay it many times each frame.
+//!
+//! The picture is tessellated only when it is recorded. Each replay reuses the resulting meshes
+//! under its own transform and color tint.
+
+use std::sync::OnceLock;
+
+use nannou::prelude::*;
+
+fn main() {
+    nannou::app(model).run();
+}
+
+struct Model {
+    flower: OnceLock<Picture>,
+}
+
+fn model(app: &App) -> Model {
+    app.new_window().size(720, 720).primary().view(view).build();
+    Model {
+        flower: OnceLock::new(),
+    }
+}
+
+// A flower made up of many petals and a dotted centre.
+fn record_flower(draw: &Draw) -> Picture {
+    draw.record_picture(|d| {
+        let petals = 24;
+        for i in 0..petals {
+            let angle = i as f32 / petals as f32 * TAU;
+            d.rotate(angle)
+                .ellipse()
+                .x(22.0)
+                .w_h(36.0, 10.0)
+                .color(Color::srgba(1.0, 1.0, 1.0, 0.6));
+        }
+        for i in 0..40 {
+            let angle = i as f32 * 2.4;
+            let radius = (i as f32).sqrt() * 1.6;
+            d.ellipse()
+                .x_y(angle.cos() * radius, angle.sin() * radius)
+                .radius(1.2)
+                .color(Color::srgb(0.3, 0.2, 0.1));
+        }
+    })
+}
+
+fn view(app: &App, model: &Model) {
+    let draw = app.draw();
+    draw.background().color(Color::srgb(0.1, 0.12, 0.1));
+
+    let flower = model.flower.get_or_init(|| record_flower(&draw));
+    let t = app.time();
+    let win = app.window_rect();
+    let step = 60.0;
+    let (cols, rows) = ((win.w() / step) as i32, (win.h() / step) as i32);
+    for row in 0..=rows {
+        for col in 0..=cols {
+            let x = win.left() + col as f32 * step;
+            let y = win.bottom() + row as f32 * step;
+            let phase = (x + y) * 0.01;
+            draw.picture(flower).x_y(x, y).rotate(t * 0.5 + phase).hsl(
+                (phase * 0.1 + t * 0.05).fract(),
+                0.7,
+                0.6,
+            );
+        }
+    }
+}
@@ -0,0 +1,39 @@
+//! A hundred thousand points swirling around the centre of the window, drawn in a single call.
+
+use nannou::prelude::*;
+
+const COUNT: usize = 100_000;
+
+fn main() {
+    nannou::sketch(view).run()
+}
+
+fn view(app: &App) {
+    let win = app.window_rect();
+    let t = app.time();
+    let draw = app.draw();
+
+    draw.background().color(BLACK);
+
+    // Each point orbits at its own radius and speed, coloured by its distance from the centre.
+    let max_radius = win.w().min(win.h()) * 0.45;
+    let points = (0..COUNT).map(|i| {
+        let fract = i as f32 / COUNT as f32;
+        let radius = fract.sqrt() * max_radius;
+        let angle = i as f32 * 2.399_963 + t * (1.0 - fract) * 0.5;
+        let wobble = (t * 2.0 + fract * 40.0).sin() * 4.0;
+        let position = pt2(angle.cos(), angle.sin()) * (radius + wobble);
+        let color = Color::hsla(200.0 + fract * 140.0, 0.8, 0.6, 0.8);
+        (position, color)
+    });
+    draw.points(points).size(2.0);
+
+    // A handful of larger, square points sized in world space follow the mouse.
+    let mouse = app.mouse();
+    let markers = (0..8).map(|i| {
+        let angle = i as f32 / 8.0 * TAU + t;
+        let size = 8.0 + (t * 3.0 + i as f32).sin() * 4.0;
+        (mouse + pt2(angle.cos(), angle.sin()) * 40.0, size)
+    });
+    draw.points(markers).square().world_space().color(WHITE);
+}
@@ -0,0 +1,37 @@
+//! Demonstrates drawing a single paragraph of text made of differently styled spans via
+//! `draw.rich_text()`.
+
+use nannou::prelude::*;
+
+fn main() {
+    nannou::sketch(view).run()
+}
+
+fn view(app: &App) {
+    let draw = app.draw();
+    draw.background().color(WHITE);
+
+    let win_rect = app.window_rect().pad(40.0);
+
+    draw.rich_text()
+        .span("Rich text ", |s| s.bold().font_size(48))
+        .span("mixes ", |s| s)
+        .span("weights", |s| s.weight(900.0))
+        .span(", ", |s| s)
+        .span("italics", |s| s.italic())
+        .span(", ", |s| s)
+        .span("colours", |s| s.color(ORANGE))
+        .span(", ", |s| s)
+        .span("sizes", |s| s.font_size(40))
+        .span(", ", |s| s)
+        .span("underlines", |s| s.underline().color(BLUE))
+        .span(" and ", |s| s)
+        .span("strikethroughs", |s| s.strikethrough().color(RED))
+        .span(
+            " within one paragraph that wraps and aligns as a whole.",
+            |s| s,
+        )
+        .color(BLACK)
+        .font_size(28)
+        .wh(win_rect.wh());
+}
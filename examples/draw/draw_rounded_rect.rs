@@ -0,0 +1,32 @@
+//! Rects with rounded corners, each of which may have its own radius.
+
+use nannou::prelude::*;
+
+fn main() {
+    nannou::sketch(view).run()
+}
+
+fn view(app: &App) {
+    let win = app.window_rect();
+    let t = app.time();
+    let draw = app.draw();
+
+    draw.background().color(BLACK);
+
+    // The same radius on every corner, growing until the rect becomes a pill.
+    let radius = (t.sin() * 0.5 + 0.5) * 60.0;
+    draw.rect()
+        .x(-win.w() * 0.25)
+        .w_h(240.0, 120.0)
+        .corner_radius(radius)
+        .color(STEELBLUE);
+
+    // A radius per corner, clockwise from the top-left.
+    draw.rect()
+        .x(win.w() * 0.25)
+        .w_h(160.0, 160.0)
+        .corner_radii([60.0, 0.0, 60.0, 10.0])
+        .no_fill()
+        .stroke(WHITE)
+        .stroke_weight(4.0);
+}
@@ -0,0 +1,44 @@
+//! A demonstration of `draw.scissor(rect)`, clipping drawings to a region of the window.
+//!
+//! The window is split into four panels. Each panel gets its own scissored `Draw` instance, so
+//! the shapes drawn into it are clipped to its bounds without any extra cameras.
+
+use nannou::prelude::*;
+
+fn main() {
+    nannou::sketch(view).run()
+}
+
+fn view(app: &App) {
+    let draw = app.draw();
+    draw.background().color(BLACK);
+    let win = app.window_rect();
+    let t = app.time();
+
+    let panels = win.subdivisions_iter().map(|r| r.pad(10.0));
+    for (i, panel) in panels.enumerate() {
+        let draw = draw.scissor(panel);
+        let hue = i as f32 / 4.0;
+        draw.rect()
+            .xy(panel.xy())
+            .wh(panel.wh())
+            .hsl(hue, 0.3, 0.15);
+
+        // The circles sweep across the whole window, but are only visible within the panel.
+        for j in 0..8 {
+            let phase = t + j as f32 * 0.8;
+            let x = phase.sin() * win.w() * 0.5;
+            let y = (phase * 1.3).cos() * win.h() * 0.5;
+            draw.ellipse()
+                .x_y(x, y)
+                .radius(60.0)
+                .hsla(hue, 0.8, 0.6, 0.8);
+        }
+    }
+
+    // Nested scissors clip to the overlap of each rect.
+    let inner = draw
+        .scissor(geom::Rect::from_w_h(300.0, 300.0))
+        .scissor(geom::Rect::from_x_y_w_h(100.0, 100.0, 300.0, 300.0));
+    inner.ellipse().radius(200.0).color(WHITE);
+}
@@ -0,0 +1,62 @@
+//! The built-in 3D solids, shaded by their normals via a custom shader model.
+
+use nannou::prelude::*;
+
+fn main() {
+    nannou::app(model)
+        .simple_window(view)
+        .shader_model::<SolidsShaderModel>()
+        .run()
+}
+
+struct Model {}
+
+// The direction towards the light, used for simple diffuse shading.
+#[shader_model(fragment = "draw_solids.wgsl")]
+struct SolidsShaderModel {
+    #[uniform(0)]
+    light_direction: Vec4,
+}
+
+fn model(_app: &App) -> Model {
+    Model {}
+}
+
+fn view(app: &App, _model: &Model, _window: Entity) {
+    let win = app.window_rect();
+    let t = app.time();
+    let draw = app.draw().shader_model(SolidsShaderModel {
+        light_direction: Vec4::new(-1.0, 1.0, 1.0, 0.0),
+    });
+
+    draw.background().color(BLACK);
+
+    // Lay the solids out in a grid of two rows, each spinning about its own centre.
+    let spacing = Vec2::new(win.w() / 3.0, win.h() / 2.0);
+    let cell = |col: f32, row: f32| {
+        let xy = Vec2::new((col - 1.0) * spacing.x, (0.5 - row) * spacing.y);
+        draw.xy(xy).euler(Vec3::new(t * 0.6, t, 0.0))
+    };
+
+    cell(0.0, 0.0)
+        .cuboid()
+        .w_h_d(100.0, 100.0, 100.0)
+        .color(TOMATO);
+    cell(1.0, 0.0).sphere().radius(60.0).color(GOLD);
+    cell(2.0, 0.0)
+        .cylinder()
+        .radius(45.0)
+        .h(120.0)
+        .color(MEDIUMSEAGREEN);
+    cell(0.0, 1.0).cone().radius(50.0).h(120.0).color(STEELBLUE);
+    cell(1.0, 1.0)
+        .torus()
+        .radius(50.0)
+        .tube_radius(18.0)
+        .color(ORCHID);
+    cell(2.0, 1.0)
+        .plane()
+        .w_h(120.0, 120.0)
+        .subdivisions(8)
+        .color(WHITE);
+}
@@ -0,0 +1,56 @@
+//! Demonstrates drawing the frames of a sprite sheet, sliced from a regular grid over a texture.
+//!
+//! The left sprite plays through every frame in a loop, the right sprite plays back and forth
+//! through the first row, and the quad along the bottom shows the top half of the texture.
+
+use nannou::prelude::*;
+
+fn main() {
+    nannou::app(model).run();
+}
+
+struct Model {
+    sheet: SpriteSheet,
+    all_frames: SpriteAnimation,
+    first_row: SpriteAnimation,
+}
+
+fn model(app: &App) -> Model {
+    app.new_window().size(720, 480).primary().view(view).build();
+    let assets = app.assets_path();
+    let img_path = assets.join("images").join("nature").join("nature_1.jpg");
+    let texture = app.asset_server().load(img_path);
+    let sheet = SpriteSheet::from_grid(texture, 4, 4);
+    let all_frames = SpriteAnimation::new(0..sheet.len(), 4.0);
+    let first_row = SpriteAnimation::new(0..4, 2.0).ping_pong();
+    Model {
+        sheet,
+        all_frames,
+        first_row,
+    }
+}
+
+fn view(app: &App, model: &Model) {
+    let draw = app.draw();
+    draw.background().color(BLACK);
+
+    let t = app.time();
+    draw.sprite(&model.sheet, model.all_frames.frame(t))
+        .x_y(-160.0, 60.0)
+        .w_h(240.0, 240.0);
+    draw.sprite(&model.sheet, model.first_row.frame(t))
+        .x_y(160.0, 60.0)
+        .w_h(240.0, 240.0)
+        .corner_radius(24.0);
+
+    // Any textured rect or quad may show a normalised area of its texture.
+    draw.quad()
+        .points(
+            pt2(-260.0, -110.0),
+            pt2(300.0, -110.0),
+            pt2(260.0, -200.0),
+            pt2(-300.0, -200.0),
+        )
+        .texture(model.sheet.texture())
+        .area(geom::Rect::from_corners(Vec2::ZERO, Vec2::new(1.0, 0.5)));
+}
@@ -0,0 +1,45 @@
+//! Load SVG files as assets and draw them with the `draw` API.
+//!
+//! SVG documents are drawn as regular nannou paths, so they may be positioned, scaled, rotated
+//! and recolored like any other primitive.
+
+use nannou::prelude::*;
+
+fn main() {
+    nannou::app(model).run();
+}
+
+struct Model {
+    svgs: Vec<Handle<svg::Svg>>,
+}
+
+fn model(app: &App) -> Model {
+    app.new_window().size(720, 360).primary().view(view).build();
+    let assets = app.assets_path();
+    let dir = assets.join("svg").join("generative_examples");
+    let svgs = ["056.svg", "076.svg", "082.svg"]
+        .iter()
+        .map(|name| app.asset_server().load(dir.join(name)))
+        .collect();
+    Model { svgs }
+}
+
+fn view(app: &App, model: &Model) {
+    let draw = app.draw();
+    draw.background().color(WHITE);
+    let win = app.window_rect();
+    let t = app.time();
+    let step = win.w() / model.svgs.len() as f32;
+    for (i, svg) in model.svgs.iter().enumerate() {
+        let x = win.left() + step * (i as f32 + 0.5);
+        let drawing = draw
+            .svg(svg)
+            .x(x)
+            .w(step * 0.6)
+            .rotate(t * 0.5 * (i as f32 - 1.0));
+        // Override the colors of the middle document.
+        if i == 1 {
+            drawing.color(STEEL_BLUE);
+        }
+    }
+}
@@ -0,0 +1,56 @@
+//! Demonstrates laying text out along a path via `draw.text(s).along_path(&path)`.
+//!
+//! A sentence circles the window while a second line of text rides along a wave.
+
+use nannou::lyon::math::point;
+use nannou::lyon::path::{Path, Winding};
+use nannou::prelude::*;
+
+fn main() {
+    nannou::sketch(view).run()
+}
+
+fn view(app: &App) {
+    let draw = app.draw();
+    draw.background().color(WHITE);
+    let t = app.time();
+    let win = app.window_rect();
+
+    // A circle, beginning at its right-most point and heading counter-clockwise.
+    let radius = win.w().min(win.h()) * 0.3;
+    let mut builder = Path::builder();
+    builder.add_circle(point(0.0, 0.0), radius, Winding::Positive);
+    let circle = builder.build();
+    draw.path().stroke().weight(1.0).color(GRAY).events(&circle);
+
+    // Wrap the overflowing glyphs around the closed path and scroll the text over time.
+    draw.text("text laid out along a path wraps around closed shapes")
+        .along_path(&circle)
+        .path_overflow(text::path::Overflow::Wrap)
+        .path_offset(t * 40.0)
+        .font_size(24)
+        .color(BLACK);
+
+    // A wave across the bottom of the window.
+    let mut builder = Path::builder();
+    let n = 64;
+    for i in 0..=n {
+        let x = map_range(i, 0, n, win.left() + 20.0, win.right() - 20.0);
+        let y = win.bottom() * 0.75 + (x * 0.02 + t).sin() * 30.0;
+        if i == 0 {
+            builder.begin(point(x, y));
+        } else {
+            builder.line_to(point(x, y));
+        }
+    }
+    builder.end(false);
+    let wave = builder.build();
+    draw.path().stroke().weight(1.0).color(GRAY).events(&wave);
+
+    draw.text("riding the wave")
+        .along_path(&wave)
+        .left_justify()
+        .path_offset(20.0)
+        .font_size(32)
+        .color(ROYAL_BLUE);
+}
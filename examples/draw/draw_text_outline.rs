@@ -0,0 +1,56 @@
+//! Demonstrates rendering text from its vector glyph outlines rather than the font atlas.
+//!
+//! The top line is stroked, the middle line is filled and remains sharp as it rotates in 3D, and
+//! the bottom line is extruded into a solid mesh.
+
+use nannou::prelude::*;
+
+fn main() {
+    nannou::sketch(view).run()
+}
+
+fn view(app: &App) {
+    let draw = app.draw();
+    draw.background().color(WHITE);
+    let t = app.time();
+    let win = app.window_rect();
+    let row_h = win.h() / 3.0;
+
+    // Stroke the outline of each glyph.
+    draw.text("stroked")
+        .stroke()
+        .stroke_weight(2.0)
+        .join_round()
+        .font_size(96)
+        .color(BLACK)
+        .y(row_h)
+        .w_h(win.w(), row_h);
+
+    // Filled outlines may be scaled and rotated without the blurring of atlas glyphs.
+    draw.y_radians(t.sin())
+        .text("outlined")
+        .outline()
+        .font_size(96)
+        .color(ROYAL_BLUE)
+        .w_h(win.w(), row_h);
+
+    // Extrude the outlines into a solid mesh.
+    let text = draw
+        .text_layout("extruded")
+        .font_size(96)
+        .build(geom::Rect::from_w_h(win.w(), row_h));
+    let mesh = text.extrude(24.0);
+    let points = mesh
+        .attribute(Mesh::ATTRIBUTE_POSITION)
+        .and_then(|points| points.as_float3())
+        .unwrap_or_default();
+    let Some(indices) = mesh.indices() else {
+        return;
+    };
+    draw.y(-row_h)
+        .x_radians(-0.4)
+        .y_radians(t * 0.5)
+        .mesh()
+        .indexed(points.iter().map(|&p| Vec3::from(p)), indices.iter())
+        .color(ORANGE);
+}
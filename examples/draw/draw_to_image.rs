@@ -0,0 +1,53 @@
+//! Render a `Draw` into an offscreen image, then draw that image as a texture in the window.
+//!
+//! The image is redrawn each frame, so its contents may be animated.
+
+use nannou::prelude::*;
+
+fn main() {
+    nannou::app(model).run();
+}
+
+struct Model {
+    image: Handle<Image>,
+}
+
+fn model(app: &App) -> Model {
+    app.new_window().size(720, 720).primary().view(view).build();
+    let image = app.new_draw_image(256, 256);
+    Model { image }
+}
+
+fn view(app: &App, model: &Model) {
+    let t = app.time();
+
+    // Draw a spinning pattern into the image.
+    let canvas = app.draw_to_image(&model.image);
+    canvas.background().color(Color::srgb(0.1, 0.1, 0.15));
+    for i in 0..6 {
+        let angle = t + i as f32 * TAU / 6.0;
+        let hue = i as f32 / 6.0;
+        canvas
+            .ellipse()
+            .x_y(angle.cos() * 80.0, angle.sin() * 80.0)
+            .radius(30.0)
+            .color(Color::hsl(hue * 360.0, 0.8, 0.6));
+    }
+
+    // Tile the image across the window.
+    let draw = app.draw();
+    draw.background().color(BLACK);
+    let win = app.window_rect();
+    let size = win.w() / 3.0;
+    for row in 0..3 {
+        for col in 0..3 {
+            let x = win.left() + (col as f32 + 0.5) * size;
+            let y = win.bottom() + (row as f32 + 0.5) * size;
+            draw.rect()
+                .x_y(x, y)
+                .w_h(size * 0.9, size * 0.9)
+                .rotate((row + col) as f32 * 0.1 * t.sin())
+                .texture(&model.image);
+        }
+    }
+}
@@ -0,0 +1,44 @@
+//! Draw onto a canvas that accumulates across frames, leaving fading trails behind moving shapes.
+//!
+//! Click to clear the canvas.
+
+use nannou::prelude::*;
+
+fn main() {
+    nannou::app(model).run();
+}
+
+struct Model;
+
+fn model(app: &App) -> Model {
+    app.new_window()
+        .size(720, 720)
+        .primary()
+        .accumulate(Accumulate::fade(Color::srgb(0.05, 0.05, 0.08), 0.04))
+        .view(view)
+        .build();
+    Model
+}
+
+fn view(app: &App, _model: &Model) {
+    let draw = app.draw();
+
+    // The canvas is only cleared when a background is drawn.
+    if app.mouse_buttons().just_pressed(MouseButton::Left) {
+        draw.background().color(Color::srgb(0.05, 0.05, 0.08));
+    }
+
+    // Orbiting circles leave trails as the canvas fades towards the background.
+    let t = app.time();
+    let win = app.window_rect();
+    let n = 5;
+    for i in 0..n {
+        let phase = i as f32 / n as f32;
+        let angle = t * (1.0 + phase) + phase * TAU;
+        let radius = win.w().min(win.h()) * (0.15 + 0.25 * phase);
+        draw.ellipse()
+            .x_y(angle.cos() * radius, (angle * 1.5).sin() * radius)
+            .radius(12.0)
+            .color(Color::hsl(phase * 360.0, 0.8, 0.6));
+    }
+}
@@ -0,0 +1,58 @@
+//! Demonstrates the typographic controls of `draw.text()`: weight, style, width, letter and word
+//! spacing and OpenType features such as small caps and tabular numerals.
+
+use nannou::prelude::*;
+
+fn main() {
+    nannou::sketch(view).run()
+}
+
+fn view(app: &App) {
+    let draw = app.draw();
+    draw.background().color(WHITE);
+
+    let win = app.window_rect().pad(40.0);
+    let t = app.time();
+    let row_h = win.h() / 5.0;
+    let row = |i: f32| win.top() - row_h * (i + 0.5);
+
+    // Sweep the weight and letter spacing over time.
+    let weight = map_range((t * 0.5).sin(), -1.0, 1.0, 100.0, 900.0);
+    draw.text("POSTER")
+        .font_size(64)
+        .font_weight(weight)
+        .letter_spacing(map_range((t * 0.3).cos(), -1.0, 1.0, -4.0, 24.0))
+        .color(BLACK)
+        .x_y(0.0, row(0.0))
+        .w_h(win.w(), row_h);
+
+    draw.text("Italic and condensed")
+        .font_size(32)
+        .italic()
+        .font_stretch(0.75)
+        .color(BLACK)
+        .x_y(0.0, row(1.0))
+        .w_h(win.w(), row_h);
+
+    draw.text("Wide word spacing")
+        .font_size(32)
+        .word_spacing(32.0)
+        .color(BLACK)
+        .x_y(0.0, row(2.0))
+        .w_h(win.w(), row_h);
+
+    draw.text("Small Caps")
+        .font_size(32)
+        .small_caps()
+        .color(BLACK)
+        .x_y(0.0, row(3.0))
+        .w_h(win.w(), row_h);
+
+    // Tabular numerals keep the counter from jittering as its digits change.
+    draw.text(&format!("{:08.2}", t * 100.0))
+        .font_size(32)
+        .tabular_numerals()
+        .color(BLACK)
+        .x_y(0.0, row(4.0))
+        .w_h(win.w(), row_h);
+}
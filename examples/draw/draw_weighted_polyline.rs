@@ -0,0 +1,47 @@
+use nannou::prelude::*;
+
+fn main() {
+    nannou::sketch(view).run()
+}
+
+fn view(app: &App) {
+    // Begin drawing
+    let draw = app.draw();
+
+    // Clear the background.
+    draw.background().color(BLACK);
+
+    let win = app.window_rect();
+    let t = app.time();
+
+    // A brush-like stroke whose weight swells and thins along a sine wave.
+    let n_points = 200;
+    let max_weight = 40.0;
+    let points_weighted = (0..n_points).map(|i| {
+        let fract = i as f32 / (n_points - 1) as f32;
+        let x = map_range(fract, 0.0, 1.0, win.left() * 0.9, win.right() * 0.9);
+        let y = (t + fract * TAU).sin() * win.h() * 0.25;
+        let weight = (fract * PI).sin() * (0.5 + 0.5 * (t * 2.0 + fract * TAU * 3.0).sin());
+        let hue = (t * 0.1 + fract * 0.5) * 360.0;
+        let color = Color::hsl(hue % 360.0, 0.8, 0.6);
+        (pt2(x, y), 1.0 + weight * max_weight, color)
+    });
+
+    // Draw the polyline, varying the weight and color at each point.
+    draw.polyline()
+        .join_round()
+        .weighted_points_colored(points_weighted);
+
+    // A closed loop with a uniform color, thicker towards the top.
+    let n_points = 64;
+    let radius = win.h().min(win.w()) * 0.15;
+    let loop_weighted = (0..n_points).map(|i| {
+        let angle = i as f32 / n_points as f32 * TAU;
+        let p = pt2(angle.cos(), angle.sin()) * radius;
+        (p, 2.0 + (angle.sin() * 0.5 + 0.5) * 16.0)
+    });
+    draw.polyline()
+        .join_round()
+        .color(WHITE)
+        .weighted_points_closed(loop_weighted);
+}
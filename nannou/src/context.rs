@@ -52,7 +52,7 @@ use bevy::{
 };
 use nannou_core::geom;
 use nannou_draw::draw::Draw;
-use nannou_draw::text::font::SharedTextCx;
+use nannou_draw::text::font::{FontFamilies, FontHandle, SharedTextCx};
 use std::cell::{Cell, RefCell};
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
@@ -95,6 +95,8 @@ pub struct App<'w, 's> {
     asset_server: Res<'w, AssetServer>,
     images: Res<'w, Assets<Image>>,
     text_cx: Res<'w, SharedTextCx>,
+    font_families: Res<'w, FontFamilies>,
+    image_draws: Res<'w, nannou_draw::ImageDraws>,
     // The render device and queue, available in the main world for wgpu interop. Optional so `App`
     // still resolves under a minimal plugin set without a renderer.
     render_device: Option<Res<'w, RenderDevice>>,
@@ -318,6 +320,25 @@ impl<'w, 's> App<'w, 's> {
             .clone()
     }
 
+    /// The [`Draw`] API rendering into the given [`Image`], rather than a window.
+    ///
+    /// The image is rendered by its own camera after each frame's commands are recorded, after
+    /// which it may be sampled by other draws, e.g. via `draw.rect().texture(&handle)`. Create a
+    /// suitable image with [`new_draw_image`](Self::new_draw_image). The camera is despawned once
+    /// the given handle and all of its clones are dropped.
+    pub fn draw_to_image(&self, image: &Handle<Image>) -> Draw {
+        self.command_scope(|mut commands| {
+            self.image_draws.draw(&mut commands, image, &self.text_cx)
+        })
+    }
+
+    /// Add a new transparent image of the given size that may be rendered into via
+    /// [`draw_to_image`](Self::draw_to_image).
+    pub fn new_draw_image(&self, width: u32, height: u32) -> Handle<Image> {
+        self.asset_server
+            .add(nannou_draw::render::new_target_image(width, height))
+    }
+
     /// Set the window whose `view` is currently being run, so [`draw`](Self::draw) targets it.
     ///
     /// Used by the classic driver systems; pass `None` to fall back to the focused window.
@@ -371,6 +392,22 @@ impl<'w, 's> App<'w, 's> {
         nannou_draw::text::Builder::new(s, self.text_cx.clone())
     }
 
+    /// Load the font file at the given path within the assets directory.
+    ///
+    /// Returns a handle to the font's family for use with `draw.text(..).font(&handle)`. Text is
+    /// drawn with the default family until the font has loaded, and picks up changes to the file
+    /// when asset hot reloading is enabled.
+    pub fn load_font<'b>(&self, path: impl Into<bevy::asset::AssetPath<'b>>) -> FontHandle {
+        self.font_families.load(&self.asset_server, path)
+    }
+
+    /// Register the font(s) within the given TrueType or OpenType file data.
+    ///
+    /// Returns a handle to the family of the first font, or `None` if the data contains no fonts.
+    pub fn register_font_bytes(&self, bytes: impl Into<Vec<u8>>) -> Option<FontHandle> {
+        self.text_cx.register_font_bytes(bytes)
+    }
+
     /// Run a closure with deferred [`Commands`] access, returning its result.
     ///
     /// A convenience around [`ParallelCommands::command_scope`] for spawning entities or queueing
@@ -149,6 +149,55 @@ impl Builder {
         self
     }
 
+    /// Add a sub-path passing smoothly through each of the given points.
+    ///
+    /// A `tension` of `0.0` produces a Catmull-Rom curve, while `1.0` connects the points with
+    /// straight lines.
+    pub fn curve_through<I>(self, points: I, tension: f32, close: bool) -> Self
+    where
+        I: IntoIterator<Item = Point2>,
+    {
+        let points: Vec<Point2> = points.into_iter().collect();
+        self.path_events(nannou_draw::draw::primitive::curve::catmull_rom(
+            &points, tension, close,
+        ))
+    }
+
+    /// Add a sub-path following the uniform cubic B-spline with the given control points.
+    pub fn spline<I>(self, points: I, close: bool) -> Self
+    where
+        I: IntoIterator<Item = Point2>,
+    {
+        let points: Vec<Point2> = points.into_iter().collect();
+        self.path_events(nannou_draw::draw::primitive::spline::b_spline(
+            &points, close,
+        ))
+    }
+
+    // Add each of the given events to the path.
+    fn path_events(mut self, events: Vec<lyon::path::PathEvent>) -> Self {
+        for event in events {
+            match event {
+                lyon::path::PathEvent::Begin { at } => {
+                    self.builder.begin(at);
+                }
+                lyon::path::PathEvent::Line { to, .. } => {
+                    self.builder.line_to(to);
+                }
+                lyon::path::PathEvent::Quadratic { ctrl, to, .. } => {
+                    self.builder.quadratic_bezier_to(ctrl, to);
+                }
+                lyon::path::PathEvent::Cubic {
+                    ctrl1, ctrl2, to, ..
+                } => {
+                    self.builder.cubic_bezier_to(ctrl1, ctrl2, to);
+                }
+                lyon::path::PathEvent::End { close, .. } => self.builder.end(close),
+            }
+        }
+        self
+    }
+
     /// Build the path and return it.
     pub fn build(self) -> Path {
         self.builder.build().into()
@@ -29,8 +29,11 @@ pub use bevy_egui::egui;
 
 pub use nannou_derive::shader_model;
 
+pub use nannou_draw::accumulate::Accumulate;
 pub use nannou_draw::color::*;
 pub use nannou_draw::draw::*;
+#[cfg(feature = "serde")]
+pub use nannou_draw::record::{DrawPlayer, DrawRecorder};
 pub use nannou_draw::render::NannouShaderModelPlugin;
 pub use nannou_draw::render::blend::*;
 pub use nannou_draw::text::*;
@@ -6,7 +6,7 @@
 use std::{fmt, path::PathBuf};
 
 use crate::context::App;
-use crate::prelude::{MonitorSelection, render::NannouCamera};
+use crate::prelude::{Accumulate, MonitorSelection, render::NannouCamera};
 use crate::{geom::Point2, glam::Vec2, prelude::WindowResizeConstraints};
 use bevy::{
     camera::Hdr,
@@ -29,6 +29,9 @@ pub struct Builder<'a, 'w, 's, M = ()> {
     user_functions: UserFunctions<M>,
     clear_color: Option<Color>,
     hdr: bool,
+    accumulate: Option<Accumulate>,
+    #[cfg(feature = "serde")]
+    recorder: Option<nannou_draw::record::DrawRecorder>,
 }
 
 /// For storing all user functions within the window.
@@ -219,6 +222,9 @@ where
             user_functions: UserFunctions::<M>::default(),
             clear_color: None,
             hdr: false,
+            accumulate: None,
+            #[cfg(feature = "serde")]
+            recorder: None,
         }
     }
 
@@ -367,6 +373,23 @@ where
         self
     }
 
+    /// Render each frame's drawing on top of the previous frames rather than a cleared frame.
+    ///
+    /// Use [`Accumulate::fade`] to fade earlier frames towards a color, e.g. for trails.
+    pub fn accumulate(mut self, accumulate: Accumulate) -> Self {
+        self.accumulate = Some(accumulate);
+        self
+    }
+
+    /// Record each frame drawn to the window via the given recorder.
+    ///
+    /// See the [`nannou_draw::record`] module for details.
+    #[cfg(feature = "serde")]
+    pub fn record(mut self, recorder: nannou_draw::record::DrawRecorder) -> Self {
+        self.recorder = Some(recorder);
+        self
+    }
+
     /// Builds the window and its camera, returning the window's [`Entity`].
     ///
     /// The window and camera are spawned via the `App`'s deferred command queue, so they become
@@ -382,6 +405,9 @@ where
             user_functions,
             clear_color,
             hdr,
+            accumulate,
+            #[cfg(feature = "serde")]
+            recorder,
         } = self;
 
         if cfg!(target_arch = "wasm32") && !primary {
@@ -453,6 +479,15 @@ where
                 }
             }
 
+            if let Some(accumulate) = accumulate {
+                commands.entity(window_entity).insert(accumulate);
+            }
+
+            #[cfg(feature = "serde")]
+            if let Some(recorder) = recorder {
+                commands.entity(window_entity).insert(recorder);
+            }
+
             if let Some(light) = light {
                 commands.entity(light).insert(layer.clone());
             }
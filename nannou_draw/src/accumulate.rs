@@ -0,0 +1,242 @@
+//! Persistent window canvases, allowing drawings to accumulate across frames.
+//!
+//! By default each frame's [Draw](crate::draw::Draw) output is rendered from scratch. Adding the
+//! [Accumulate] component to a window instead renders its draw on top of a persistent canvas
+//! image, which is then presented to the window. This allows for trails, painting and other
+//! sketches that rely on not clearing between frames.
+
+use bevy::camera::visibility::RenderLayers;
+use bevy::prelude::*;
+
+use crate::draw::mesh::MeshExt;
+use crate::render::{NannouCamera, new_target_image_filled};
+
+/// Render a window's drawings on top of those of previous frames.
+///
+/// Add this component to a window entity. The canvas is initially filled with `fade_color` and is
+/// only cleared via `draw.background()`. The canvas is recreated, losing its contents, when the
+/// window is resized.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct Accumulate {
+    /// The color that the canvas is initially filled with and fades towards.
+    pub fade_color: Color,
+    /// The fraction by which the canvas fades towards `fade_color` each frame, in `0.0..=1.0`.
+    ///
+    /// At `0.0`, drawings remain on the canvas indefinitely.
+    pub fade: f32,
+}
+
+/// The persistent canvas into which an [Accumulate] window's draw is rendered.
+#[derive(Component, Clone, Debug)]
+pub struct AccumulationCanvas {
+    /// The image retaining the window's drawings between frames.
+    pub image: Handle<Image>,
+    /// The camera rendering the window's draw into `image`.
+    pub camera: Entity,
+    /// The physical size of the window for which the canvas was created.
+    pub size: UVec2,
+}
+
+// Marks the camera rendering into the canvas of the given window.
+#[derive(Component)]
+pub(crate) struct CanvasWindow(Entity);
+
+impl Accumulate {
+    /// Accumulate drawings indefinitely on a canvas initially filled with black.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fade the canvas towards `color` by the fraction `fade` each frame.
+    pub fn fade<C>(color: C, fade: f32) -> Self
+    where
+        C: Into<Color>,
+    {
+        Accumulate {
+            fade_color: color.into(),
+            fade,
+        }
+    }
+}
+
+impl Default for Accumulate {
+    fn default() -> Self {
+        Accumulate {
+            fade_color: Color::BLACK,
+            fade: 0.0,
+        }
+    }
+}
+
+/// A quad covering a window of the given logical size, centred on the origin.
+///
+/// Texture coordinates map the top-left corner to `[0.0, 0.0]`, matching images rendered by a
+/// [NannouCamera].
+pub(crate) fn window_quad(size: Vec2, color: Color) -> Mesh {
+    let mut mesh = Mesh::init();
+    let half = size * 0.5;
+    let corners = [
+        (Vec2::new(-half.x, half.y), [0.0, 0.0]),
+        (Vec2::new(half.x, half.y), [1.0, 0.0]),
+        (Vec2::new(half.x, -half.y), [1.0, 1.0]),
+        (Vec2::new(-half.x, -half.y), [0.0, 1.0]),
+    ];
+    let color = color.to_linear().to_f32_array();
+    for (point, tex_coords) in corners {
+        mesh.points_mut().push(point.extend(0.0).to_array());
+        mesh.colors_mut().push(color);
+        mesh.tex_coords_mut().push(tex_coords);
+        mesh.normals_mut().push([0.0, 0.0, 1.0]);
+    }
+    for index in [0, 3, 2, 0, 2, 1] {
+        mesh.push_index(index);
+    }
+    mesh
+}
+
+// (Re)create the canvases of accumulating windows whose size has changed, and remove those of
+// windows that no longer accumulate or have been closed.
+pub(crate) fn update_accumulation_canvases(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    windows: Query<(
+        Entity,
+        &Window,
+        &Accumulate,
+        Option<&AccumulationCanvas>,
+        Option<&RenderLayers>,
+    )>,
+    stale: Query<Entity, (With<AccumulationCanvas>, Without<Accumulate>)>,
+    cameras: Query<(Entity, &CanvasWindow)>,
+) {
+    for (entity, window, accumulate, canvas, layers) in windows.iter() {
+        let size = window.physical_size();
+        if canvas.is_some_and(|canvas| canvas.size == size) || size.cmpeq(UVec2::ZERO).any() {
+            continue;
+        }
+        if let Some(canvas) = canvas {
+            commands.entity(canvas.camera).despawn();
+        }
+        let fill = accumulate.fade_color.to_srgba().to_u8_array();
+        let image = images.add(new_target_image_filled(size.x, size.y, fill));
+        // The canvas is rendered at the window's physical resolution, while one unit remains
+        // one logical pixel.
+        let projection = Projection::Orthographic(OrthographicProjection {
+            scale: 1.0 / window.scale_factor(),
+            ..NannouCamera::image_projection()
+        });
+        let camera = commands
+            .spawn(NannouCamera::for_image(image.clone()))
+            .insert((
+                projection,
+                layers.cloned().unwrap_or_default(),
+                CanvasWindow(entity),
+            ))
+            .id();
+        commands.entity(entity).insert(AccumulationCanvas {
+            image,
+            camera,
+            size,
+        });
+    }
+
+    for entity in stale.iter() {
+        commands.entity(entity).remove::<AccumulationCanvas>();
+    }
+    for (camera, CanvasWindow(window)) in cameras.iter() {
+        if !windows.contains(*window) {
+            commands.entity(camera).despawn();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+
+    fn new_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<Assets<Image>>();
+        world
+    }
+
+    fn update(world: &mut World) {
+        world.run_system_once(update_accumulation_canvases).unwrap();
+    }
+
+    fn canvas(world: &World, window: Entity) -> Option<AccumulationCanvas> {
+        world.get::<AccumulationCanvas>(window).cloned()
+    }
+
+    #[test]
+    fn test_canvas_created_for_accumulating_window() {
+        let mut world = new_world();
+        let mut window = Window::default();
+        window.resolution.set_physical_resolution(64, 32);
+        let fade_color = Color::srgb(1.0, 0.0, 0.0);
+        let window = world
+            .spawn((window, Accumulate::fade(fade_color, 0.1)))
+            .id();
+        update(&mut world);
+
+        let created = canvas(&world, window).expect("no canvas created");
+        assert_eq!(created.size, UVec2::new(64, 32));
+        let images = world.resource::<Assets<Image>>();
+        let image = images.get(&created.image).expect("no canvas image");
+        assert_eq!(image.size(), UVec2::new(64, 32));
+        // The canvas is initially filled with the fade color.
+        assert_eq!(image.data.as_deref().unwrap()[..4], [255, 0, 0, 255]);
+        assert!(world.get::<NannouCamera>(created.camera).is_some());
+
+        // The canvas is kept while the window's size is unchanged.
+        update(&mut world);
+        assert_eq!(canvas(&world, window).unwrap().camera, created.camera);
+    }
+
+    #[test]
+    fn test_canvas_recreated_on_resize() {
+        let mut world = new_world();
+        let mut window = Window::default();
+        window.resolution.set_physical_resolution(64, 32);
+        let window = world.spawn((window, Accumulate::new())).id();
+        update(&mut world);
+        let first = canvas(&world, window).unwrap();
+
+        world
+            .get_mut::<Window>(window)
+            .unwrap()
+            .resolution
+            .set_physical_resolution(128, 64);
+        update(&mut world);
+        let second = canvas(&world, window).unwrap();
+        assert_eq!(second.size, UVec2::new(128, 64));
+        assert_ne!(second.camera, first.camera);
+        assert!(world.get_entity(first.camera).is_err());
+    }
+
+    #[test]
+    fn test_canvas_removed_when_no_longer_accumulating() {
+        let mut world = new_world();
+        let mut window = Window::default();
+        window.resolution.set_physical_resolution(64, 32);
+        let window = world.spawn((window, Accumulate::new())).id();
+        update(&mut world);
+        let created = canvas(&world, window).unwrap();
+
+        world.entity_mut(window).remove::<Accumulate>();
+        update(&mut world);
+        assert!(canvas(&world, window).is_none());
+        assert!(world.get_entity(created.camera).is_err());
+    }
+
+    #[test]
+    fn test_window_quad_tex_coords() {
+        let mesh = window_quad(Vec2::new(100.0, 50.0), Color::WHITE);
+        // The top-left corner maps to the origin of the canvas image.
+        assert_eq!(mesh.points()[0], [-50.0, 25.0, 0.0]);
+        assert_eq!(mesh.tex_coords()[0], [0.0, 0.0]);
+        assert_eq!(mesh.count_indices(), 6);
+    }
+}
@@ -8,7 +8,8 @@ use uuid::Uuid;
 
 use crate::draw::primitive::Primitive;
 use crate::draw::properties::{
-    SetColor, SetDimensions, SetFill, SetOrientation, SetPosition, SetStroke, color, fill,
+    SetColor, SetDashes, SetDimensions, SetFill, SetGradient, SetOrientation, SetPosition,
+    SetStroke, color, dash, fill, gradient,
     spatial::{dimension, orientation, position},
     stroke,
 };
@@ -55,6 +56,10 @@ pub struct DrawingContext<'a> {
     pub path_event_buffer: &'a mut Vec<PathEvent>,
     /// A re-usable buffer for collecting polyline points vertex data.
     pub path_points_vertex_buffer: &'a mut Vec<(Vec2, Color, Vec2)>,
+    /// A re-usable buffer for collecting the points and weights of variable-width polylines.
+    pub path_points_weighted_buffer: &'a mut Vec<(Vec2, Color, f32)>,
+    /// A re-usable buffer for collecting the points of point clouds.
+    pub points_buffer: &'a mut Vec<crate::draw::primitive::points::Point>,
     /// A re-usable buffer for collecting text.
     pub text_buffer: &'a mut String,
 }
@@ -90,12 +95,16 @@ impl<'a> DrawingContext<'a> {
             ref mut intermediary_mesh,
             ref mut path_event_buffer,
             ref mut path_points_vertex_buffer,
+            ref mut path_points_weighted_buffer,
+            ref mut points_buffer,
             ref mut text_buffer,
         } = *state;
         DrawingContext {
             mesh: intermediary_mesh,
             path_event_buffer,
             path_points_vertex_buffer,
+            path_points_weighted_buffer,
+            points_buffer,
             text_buffer,
         }
     }
@@ -191,6 +200,8 @@ impl<'a, T> Drawing<'a, T> {
             state: draw.state.clone(),
             context: draw.context.clone(),
             shader_model: new_id.clone(),
+            scissor: draw.scissor,
+            mask: draw.mask.clone(),
             window: draw.window,
             text_cx: draw.text_cx.clone(),
         };
@@ -809,6 +820,87 @@ where
     }
 }
 
+// SetDashes methods
+
+impl<'a, T> Drawing<'a, T>
+where
+    T: SetDashes,
+{
+    /// Split the stroke into dashes with the given alternating lengths of dashes and gaps.
+    ///
+    /// A pattern with an odd number of lengths is repeated, as in SVG's `stroke-dasharray`. A
+    /// length of `0.0` produces a dot when combined with round or square caps.
+    pub fn dashes(self, pattern: &[f32]) -> Self {
+        let update = dash::Update::Pattern(pattern.to_vec());
+        dash::set_dashes(&self.draw, self.index, update);
+        self
+    }
+
+    /// The distance into the dash pattern at which the stroke begins.
+    ///
+    /// Animating this produces the "marching ants" effect.
+    pub fn dash_offset(self, offset: f32) -> Self {
+        dash::set_dashes(&self.draw, self.index, dash::Update::Offset(offset));
+        self
+    }
+}
+
+// SetGradient methods
+
+impl<'a, T> Drawing<'a, T>
+where
+    T: SetGradient,
+{
+    /// Color the primitive with a gradient along the line from `start` to `end`.
+    ///
+    /// Each stop pairs an offset along the line in `0.0..=1.0` with a color. Coordinates are
+    /// relative to the position of the primitive, prior to its orientation.
+    pub fn linear_gradient<I, C>(self, start: Vec2, end: Vec2, stops: I) -> Self
+    where
+        I: IntoIterator<Item = (f32, C)>,
+        C: Into<Color>,
+    {
+        let kind = gradient::GradientKind::Linear { start, end };
+        let update = gradient::Update::kind(kind, stops);
+        gradient::set_gradient(&self.draw, self.index, update);
+        self
+    }
+
+    /// Color the primitive with a gradient radiating from `center` out to `radius`.
+    ///
+    /// Each stop pairs an offset between the center (`0.0`) and the radius (`1.0`) with a color.
+    /// Coordinates are relative to the position of the primitive, prior to its orientation.
+    pub fn radial_gradient<I, C>(self, center: Vec2, radius: f32, stops: I) -> Self
+    where
+        I: IntoIterator<Item = (f32, C)>,
+        C: Into<Color>,
+    {
+        let kind = gradient::GradientKind::Radial { center, radius };
+        let update = gradient::Update::kind(kind, stops);
+        gradient::set_gradient(&self.draw, self.index, update);
+        self
+    }
+
+    /// The color space in which gradient colors are interpolated. Defaults to sRGB.
+    pub fn gradient_interpolation(self, interpolation: gradient::Interpolation) -> Self {
+        let update = gradient::Update::Interpolation(interpolation);
+        gradient::set_gradient(&self.draw, self.index, update);
+        self
+    }
+
+    /// The number of times that each tessellated triangle is subdivided for gradient fidelity.
+    ///
+    /// Colors are sampled at mesh vertices, so shapes with few vertices (e.g. a rect) need
+    /// subdividing to show gradients with more than two stops, radial gradients or non-sRGB
+    /// interpolation accurately. Each level quadruples the number of triangles, so this is
+    /// clamped to [gradient::MAX_SUBDIVISIONS].
+    pub fn gradient_subdivisions(self, subdivisions: u32) -> Self {
+        let update = gradient::Update::Subdivisions(subdivisions);
+        gradient::set_gradient(&self.draw, self.index, update);
+        self
+    }
+}
+
 impl<'a, T> Drawing<'a, T> {
     /// Set the base color of the shader model used to draw this primitive.
     ///
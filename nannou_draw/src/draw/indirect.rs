@@ -1,6 +1,6 @@
 //! A shader that renders a mesh multiple times in one draw call.
 
-use crate::render::{ShaderBufferHandle, ShaderModelAsset};
+use crate::render::{SetNannouScissor, ShaderBufferHandle, ShaderModelAsset};
 use crate::{
     draw::{Draw, DrawCommand, drawing::Drawing, primitive::Primitive},
     render::{PreparedShaderModel, ShaderModel, queue_shader_model},
@@ -110,6 +110,7 @@ where
 
 type DrawIndirectShaderModel<SM> = (
     SetItemPipeline,
+    SetNannouScissor,
     SetMeshViewBindGroup<0>,
     SetMeshViewBindingArrayBindGroup<1>,
     SetMeshBindGroup<2>,
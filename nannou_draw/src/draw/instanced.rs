@@ -1,6 +1,6 @@
 //! A shader that renders a mesh multiple times in one draw call.
 
-use crate::render::ShaderModelAsset;
+use crate::render::{SetNannouScissor, ShaderModelAsset};
 use crate::{
     draw::{Draw, DrawCommand, drawing::Drawing, primitive::Primitive},
     render::{PreparedShaderModel, ShaderModel, queue_shader_model},
@@ -112,6 +112,7 @@ where
 
 type DrawInstancedShaderModel<SM> = (
     SetItemPipeline,
+    SetNannouScissor,
     SetMeshViewBindGroup<0>,
     SetMeshViewBindingArrayBindGroup<1>,
     SetMeshBindGroup<2>,
@@ -0,0 +1,348 @@
+//! Clipping drawings to the area covered by an arbitrary set of shapes.
+//!
+//! A [Mask] is recorded via [Draw::mask] or [Draw::inverse_mask]. The shapes drawn within the
+//! mask closure are tessellated immediately into a set of triangles. When rendering, each
+//! triangle of a masked drawing is clipped against those triangles on the CPU, so masks work
+//! with any shader model and require no stencil buffer.
+
+use std::sync::Arc;
+
+use bevy::mesh::Indices;
+use bevy::prelude::*;
+use lyon::tessellation::{FillTessellator, StrokeTessellator};
+
+use crate::draw::mesh::MeshExt;
+use crate::draw::primitive::Primitive;
+use crate::draw::render::{RenderContext, RenderPrimitive};
+use crate::draw::{Draw, DrawCommand, DrawContext};
+
+/// The area to which drawings are clipped, as produced by [Draw::mask] or [Draw::inverse_mask].
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Mask {
+    /// The triangles covered by the mask shapes, with the draw transform already applied.
+    triangles: Vec<[Vec2; 3]>,
+    /// The bounding box of all triangles, used to skip work for geometry outside of the mask.
+    bounds: Option<(Vec2, Vec2)>,
+    /// Whether drawings appear outside (rather than inside) of the mask shapes.
+    inverse: bool,
+    /// The mask of the [Draw] that this mask was created from, if any.
+    ///
+    /// Nested masks are applied in turn, clipping drawings to their intersection.
+    parent: Option<Arc<Mask>>,
+}
+
+// A vertex of a triangle being clipped, carrying all mesh attributes.
+#[derive(Copy, Clone, Debug)]
+struct Vertex {
+    point: Vec3,
+    color: Vec4,
+    tex_coords: Vec2,
+    normal: Vec3,
+}
+
+type Polygon = Vec<Vertex>;
+
+impl Mask {
+    /// Build a mask by tessellating all primitives drawn to the given `Draw`.
+    ///
+    /// Text, points and instanced or indirect drawings are not supported within masks and are
+    /// ignored.
+    pub(crate) fn from_draw(mask_draw: &Draw, inverse: bool, parent: Option<Arc<Mask>>) -> Self {
+        let mesh = tessellate(mask_draw);
+        let points = mesh.points();
+        let triangles = disjoint(
+            (0..mesh.count_indices() / 3)
+                .map(|tri| {
+                    let ix = |i: usize| mesh.get_index(tri * 3 + i) as usize;
+                    let p = |i: usize| Vec3::from(points[ix(i)]).truncate();
+                    [p(0), p(1), p(2)]
+                })
+                .filter(|tri| signed_area(tri).abs() > f32::EPSILON),
+        );
+        let bounds = triangles
+            .iter()
+            .flatten()
+            .fold(None, |bounds, &p| match bounds {
+                None => Some((p, p)),
+                Some((min, max)) => Some((min.min(p), max.max(p))),
+            });
+        Mask {
+            triangles,
+            bounds,
+            inverse,
+            parent,
+        }
+    }
+
+    /// The triangles covered by the mask shapes.
+    ///
+    /// Where mask shapes overlap, the overlapping area is covered by a single triangle so that
+    /// masked drawings are never output twice. These are described in the same space as the vertices of rendered draw meshes.
+    pub fn triangles(&self) -> &[[Vec2; 3]] {
+        &self.triangles
+    }
+
+    /// Whether drawings appear outside of the mask shapes rather than inside.
+    pub fn is_inverse(&self) -> bool {
+        self.inverse
+    }
+
+    /// The mask that this mask is nested within, if any.
+    pub fn parent(&self) -> Option<&Arc<Mask>> {
+        self.parent.as_ref()
+    }
+
+    /// Clip the triangles of the given mesh that were added since the given vertex and index
+    /// counts, replacing them with the parts that lie within the mask.
+    ///
+    /// Triangles are clipped within the *xy* plane and all vertex attributes are interpolated.
+    pub fn apply(&self, mesh: &mut Mesh, vertex_start: usize, index_start: usize) {
+        if let Some(parent) = &self.parent {
+            parent.apply(mesh, vertex_start, index_start);
+        }
+
+        let mut polygons = vec![];
+        for tri in 0..(mesh.count_indices() - index_start) / 3 {
+            let vertex = |i: usize| {
+                let ix = mesh.get_index(index_start + tri * 3 + i) as usize;
+                Vertex {
+                    point: mesh.points()[ix].into(),
+                    color: mesh.colors()[ix].into(),
+                    tex_coords: mesh.tex_coords()[ix].into(),
+                    normal: mesh.normals()[ix].into(),
+                }
+            };
+            let polygon = vec![vertex(0), vertex(1), vertex(2)];
+            if self.inverse {
+                polygons.extend(self.subtract(polygon));
+            } else {
+                polygons.extend(self.intersect(polygon));
+            }
+        }
+
+        // Replace the clipped geometry.
+        truncate(mesh, vertex_start, index_start);
+        for polygon in polygons {
+            let first = mesh.count_vertices() as u32;
+            for v in &polygon {
+                mesh.points_mut().push(v.point.to_array());
+                mesh.colors_mut().push(v.color.to_array());
+                mesh.tex_coords_mut().push(v.tex_coords.to_array());
+                mesh.normals_mut().push(v.normal.to_array());
+            }
+            for i in 1..polygon.len() as u32 - 1 {
+                mesh.push_index(first);
+                mesh.push_index(first + i);
+                mesh.push_index(first + i + 1);
+            }
+        }
+    }
+
+    // The parts of the polygon that lie within the mask triangles.
+    fn intersect(&self, polygon: Polygon) -> Vec<Polygon> {
+        if !self.overlaps_bounds(&polygon) {
+            return vec![];
+        }
+        self.triangles
+            .iter()
+            .filter_map(|tri| clip_to_triangle(&polygon, tri))
+            .collect()
+    }
+
+    // The parts of the polygon that lie outside of all mask triangles.
+    fn subtract(&self, polygon: Polygon) -> Vec<Polygon> {
+        if !self.overlaps_bounds(&polygon) {
+            return vec![polygon];
+        }
+        let mut pieces = vec![polygon];
+        for tri in &self.triangles {
+            pieces = pieces
+                .into_iter()
+                .flat_map(|piece| subtract_triangle(piece, tri))
+                .collect();
+        }
+        pieces
+    }
+
+    fn overlaps_bounds(&self, polygon: &Polygon) -> bool {
+        let Some((min, max)) = self.bounds else {
+            return false;
+        };
+        let (pmin, pmax) = polygon.iter().fold(
+            (Vec2::splat(f32::MAX), Vec2::splat(f32::MIN)),
+            |(lo, hi), v| (lo.min(v.point.truncate()), hi.max(v.point.truncate())),
+        );
+        pmin.x <= max.x && pmax.x >= min.x && pmin.y <= max.y && pmax.y >= min.y
+    }
+}
+
+/// Whether the two optional masks refer to the same mask.
+pub(crate) fn same(a: &Option<Arc<Mask>>, b: &Option<Arc<Mask>>) -> bool {
+    match (a, b) {
+        (None, None) => true,
+        (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+        _ => false,
+    }
+}
+
+// Tessellate all primitives recorded by the given draw into a single mesh.
+fn tessellate(draw: &Draw) -> Mesh {
+    let mut mesh = Mesh::init();
+    let mut fill_tessellator = FillTessellator::new();
+    let mut stroke_tessellator = StrokeTessellator::new();
+    let mut curr_ctx = DrawContext::default();
+    let cmds: Vec<_> = draw.drain_commands().collect();
+    let state = draw.state.read().unwrap();
+    let intermediary_state = state.intermediary_state.read().unwrap();
+    for cmd in cmds {
+        match cmd {
+            // Points and instances are positioned on the GPU and text is rendered separately,
+            // so none of them contribute to the mask.
+            DrawCommand::Primitive(Primitive::Points(_) | Primitive::Text(_)) => (),
+            DrawCommand::Primitive(prim) => {
+                let ctxt = RenderContext {
+                    intermediary_mesh: &intermediary_state.intermediary_mesh,
+                    path_event_buffer: &intermediary_state.path_event_buffer,
+                    path_points_vertex_buffer: &intermediary_state.path_points_vertex_buffer,
+                    path_points_weighted_buffer: &intermediary_state.path_points_weighted_buffer,
+                    points_buffer: &intermediary_state.points_buffer,
+                    text_buffer: &intermediary_state.text_buffer,
+                    theme: &state.theme,
+                    transform: &curr_ctx.transform,
+                    fill_tessellator: &mut fill_tessellator,
+                    stroke_tessellator: &mut stroke_tessellator,
+                    output_attachment_size: Vec2::ZERO,
+                    output_attachment_scale_factor: 1.0,
+                    vector_paths: None,
+                };
+                prim.render_primitive(ctxt, &mut mesh);
+            }
+            DrawCommand::Context(ctx) => curr_ctx = ctx,
+            _ => (),
+        }
+    }
+    mesh
+}
+
+// Remove all vertices and indices beyond the given counts.
+fn truncate(mesh: &mut Mesh, vertex_count: usize, index_count: usize) {
+    mesh.points_mut().truncate(vertex_count);
+    mesh.colors_mut().truncate(vertex_count);
+    mesh.tex_coords_mut().truncate(vertex_count);
+    mesh.normals_mut().truncate(vertex_count);
+    if let Some(Indices::U32(indices)) = mesh.indices_mut() {
+        indices.truncate(index_count);
+    }
+}
+
+// Split the given triangles so that none of them overlap, preserving the area they cover.
+//
+// Each triangle is reduced to the parts not already covered by those before it.
+fn disjoint(triangles: impl IntoIterator<Item = [Vec2; 3]>) -> Vec<[Vec2; 3]> {
+    let mut out: Vec<[Vec2; 3]> = vec![];
+    for tri in triangles {
+        let mut pieces = vec![tri.map(point_vertex).to_vec()];
+        for covered in &out {
+            if pieces.is_empty() {
+                break;
+            }
+            pieces = pieces
+                .into_iter()
+                .flat_map(|piece| subtract_triangle(piece, covered))
+                .collect();
+        }
+        // Each piece is convex, so it may be split into a fan of triangles.
+        for piece in pieces {
+            for i in 1..piece.len() - 1 {
+                let tri = [piece[0], piece[i], piece[i + 1]].map(|v| v.point.truncate());
+                if signed_area(&tri).abs() > f32::EPSILON {
+                    out.push(tri);
+                }
+            }
+        }
+    }
+    out
+}
+
+fn point_vertex(point: Vec2) -> Vertex {
+    Vertex {
+        point: point.extend(0.0),
+        color: Vec4::ZERO,
+        tex_coords: Vec2::ZERO,
+        normal: Vec3::ZERO,
+    }
+}
+
+fn signed_area([a, b, c]: &[Vec2; 3]) -> f32 {
+    (b - a).perp_dot(c - a) * 0.5
+}
+
+// The edges of the triangle as `(start, end)` pairs, wound counter-clockwise.
+fn ccw_edges(tri: &[Vec2; 3]) -> [(Vec2, Vec2); 3] {
+    let [a, b, c] = *tri;
+    if signed_area(tri) >= 0.0 {
+        [(a, b), (b, c), (c, a)]
+    } else {
+        [(a, c), (c, b), (b, a)]
+    }
+}
+
+// Clip the polygon to the half-plane on the left of the given edge (or the right if `!left`).
+fn clip_to_edge(polygon: &Polygon, (start, end): (Vec2, Vec2), left: bool) -> Polygon {
+    let dir = end - start;
+    let side = |v: &Vertex| {
+        let d = dir.perp_dot(v.point.truncate() - start);
+        if left { d } else { -d }
+    };
+    let mut out = Vec::with_capacity(polygon.len() + 1);
+    for (i, a) in polygon.iter().enumerate() {
+        let b = &polygon[(i + 1) % polygon.len()];
+        let (da, db) = (side(a), side(b));
+        if da >= 0.0 {
+            out.push(*a);
+        }
+        if (da >= 0.0) != (db >= 0.0) {
+            out.push(lerp(a, b, da / (da - db)));
+        }
+    }
+    out
+}
+
+// The part of the polygon within the triangle, if any.
+fn clip_to_triangle(polygon: &Polygon, tri: &[Vec2; 3]) -> Option<Polygon> {
+    let mut out = polygon.clone();
+    for edge in ccw_edges(tri) {
+        out = clip_to_edge(&out, edge, true);
+        if out.len() < 3 {
+            return None;
+        }
+    }
+    Some(out)
+}
+
+// The convex pieces of the polygon that lie outside of the triangle.
+fn subtract_triangle(polygon: Polygon, tri: &[Vec2; 3]) -> Vec<Polygon> {
+    let mut pieces = vec![];
+    let mut inside = polygon;
+    for edge in ccw_edges(tri) {
+        let outside = clip_to_edge(&inside, edge, false);
+        if outside.len() >= 3 {
+            pieces.push(outside);
+        }
+        inside = clip_to_edge(&inside, edge, true);
+        if inside.len() < 3 {
+            break;
+        }
+    }
+    pieces
+}
+
+fn lerp(a: &Vertex, b: &Vertex, t: f32) -> Vertex {
+    Vertex {
+        point: a.point.lerp(b.point, t),
+        color: a.color.lerp(b.color, t),
+        tex_coords: a.tex_coords.lerp(b.tex_coords, t),
+        normal: a.normal.lerp(b.normal, t),
+    }
+}
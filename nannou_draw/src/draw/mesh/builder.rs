@@ -5,6 +5,9 @@
 //!
 //! Lyon tessellators assume `f32` data, so we do the same in the following implementations.
 
+use std::collections::HashMap;
+
+use bevy::mesh::Indices;
 use bevy::prelude::*;
 use lyon::tessellation::{FillVertex, GeometryBuilderError, StrokeVertex, VertexId};
 // use lyon::tessellation::{FillVertex, GeometryBuilderError, StrokeVertex, VertexId};
@@ -13,6 +16,7 @@ use lyon::tessellation::geometry_builder::{
 };
 
 use crate::draw::mesh::MeshExt;
+use crate::draw::properties::gradient;
 
 pub struct MeshBuilder<'a, A> {
     /// The mesh that is to be extended.
@@ -29,6 +33,11 @@ pub struct MeshBuilder<'a, A> {
 
 pub struct SingleColor(Color);
 pub struct Vertex;
+pub struct Gradient<'g> {
+    gradient: &'g gradient::Gradient,
+    /// The untransformed position of each vertex added since `begin_geometry`.
+    local_points: Vec<Vec2>,
+}
 
 impl<'a, A> MeshBuilder<'a, A> {
     /// Begin extending the mesh.
@@ -57,6 +66,87 @@ impl<'a> MeshBuilder<'a, Vertex> {
     }
 }
 
+impl<'a, 'g> MeshBuilder<'a, Gradient<'g>> {
+    /// Begin extending a mesh where the color of each vertex is sampled from a gradient.
+    pub fn gradient(mesh: &'a mut Mesh, transform: Mat4, gradient: &'g gradient::Gradient) -> Self {
+        let local_points = vec![];
+        Self::new(
+            mesh,
+            transform,
+            Gradient {
+                gradient,
+                local_points,
+            },
+        )
+    }
+
+    /// Split each triangle added since `begin_geometry` into four, `levels` times over.
+    ///
+    /// New vertices are added at the midpoint of each edge, with their colors sampled from the
+    /// gradient. Edges shared between triangles share their midpoint. `levels` is clamped to
+    /// [gradient::MAX_SUBDIVISIONS].
+    pub fn subdivide(&mut self, levels: u32) {
+        // Subdivided meshes quickly outgrow U16 indices.
+        if let Some(Indices::U16(indices)) = self.mesh.indices() {
+            let indices = indices.iter().copied().map(u32::from).collect();
+            self.mesh.insert_indices(Indices::U32(indices));
+        }
+        for _ in 0..levels.min(gradient::MAX_SUBDIVISIONS) {
+            let start = self.begin_index_count as usize;
+            let indices: Vec<u32> = match self.mesh.indices_mut() {
+                Some(Indices::U32(indices)) => indices.drain(start..).collect(),
+                _ => return,
+            };
+            let mut midpoints = HashMap::new();
+            for tri in indices.chunks_exact(3) {
+                let [a, b, c] = [tri[0], tri[1], tri[2]];
+                let ab = self.midpoint(&mut midpoints, a, b);
+                let bc = self.midpoint(&mut midpoints, b, c);
+                let ca = self.midpoint(&mut midpoints, c, a);
+                for ix in [a, ab, ca, ab, b, bc, ca, bc, c, ab, bc, ca] {
+                    self.mesh.push_index(ix);
+                }
+            }
+        }
+    }
+
+    // The index of the vertex at the midpoint of the edge between `a` and `b`, adding it if
+    // necessary.
+    fn midpoint(&mut self, midpoints: &mut HashMap<(u32, u32), u32>, a: u32, b: u32) -> u32 {
+        let key = (a.min(b), a.max(b));
+        if let Some(&ix) = midpoints.get(&key) {
+            return ix;
+        }
+        let local = |ix: u32| self.attributes.local_points[(ix - self.begin_vertex_count) as usize];
+        let position = local(a).lerp(local(b), 0.5);
+        let tex_coords = self.mesh.tex_coords();
+        let tex_coords =
+            Vec2::from(tex_coords[a as usize]).lerp(tex_coords[b as usize].into(), 0.5);
+        let ix = self.push_gradient_vertex(position, tex_coords.to_array());
+        midpoints.insert(key, ix);
+        ix
+    }
+
+    // Push a vertex with the given untransformed position, returning its index.
+    fn push_gradient_vertex(&mut self, position: Vec2, tex_coords: [f32; 2]) -> u32 {
+        let ix = self.mesh.count_vertices() as u32;
+        let point = self.transform.transform_point3(position.extend(0.0));
+        let color = self
+            .attributes
+            .gradient
+            .color_at(position)
+            .unwrap_or_default();
+        self.attributes.local_points.push(position);
+        self.mesh.points_mut().push(point.to_array());
+        self.mesh
+            .colors_mut()
+            .push(color.to_linear().to_f32_array());
+        self.mesh.tex_coords_mut().push(tex_coords);
+        self.mesh.normals_mut().push([0.0, 0.0, 1.0]);
+        ix
+    }
+}
+
 impl<'a, A> GeometryBuilder for MeshBuilder<'a, A> {
     fn begin_geometry(&mut self) {
         self.begin_vertex_count = self.mesh.count_vertices() as u32;
@@ -196,3 +286,37 @@ impl<'a> StrokeGeometryBuilder for MeshBuilder<'a, Vertex> {
         Ok(id)
     }
 }
+
+impl<'a, 'g> FillGeometryBuilder for MeshBuilder<'a, Gradient<'g>> {
+    fn add_fill_vertex(
+        &mut self,
+        mut vertex: FillVertex,
+    ) -> Result<VertexId, GeometryBuilderError> {
+        let position = vertex.position();
+        let attr = vertex.interpolated_attributes();
+        let tex_coords = if attr.is_empty() {
+            [0.0, 0.0]
+        } else {
+            [attr[0], attr[1]]
+        };
+        let ix = self.push_gradient_vertex(Vec2::new(position.x, position.y), tex_coords);
+        Ok(VertexId(ix))
+    }
+}
+
+impl<'a, 'g> StrokeGeometryBuilder for MeshBuilder<'a, Gradient<'g>> {
+    fn add_stroke_vertex(
+        &mut self,
+        mut vertex: StrokeVertex,
+    ) -> Result<VertexId, GeometryBuilderError> {
+        let position = vertex.position();
+        let attr = vertex.interpolated_attributes();
+        let tex_coords = if attr.is_empty() {
+            [0.0, 0.0]
+        } else {
+            [attr[0], attr[1]]
+        };
+        let ix = self.push_gradient_vertex(Vec2::new(position.x, position.y), tex_coords);
+        Ok(VertexId(ix))
+    }
+}
@@ -12,11 +12,14 @@ use self::primitive::Primitive;
 pub use self::{
     background::Background,
     drawing::{Drawing, DrawingContext},
+    mask::Mask,
+    picture::Picture,
+    sprite::{SpriteAnimation, SpriteSheet},
     theme::Theme,
 };
 use crate::{
     draw::{indirect::Indirect, instanced::Instanced, mesh::MeshExt},
-    render::{DefaultNannouShaderModel, ErasedShaderModel, ShaderModel},
+    render::{DefaultNannouShaderModel, ErasedShaderModel, ShaderModel, blend::BlendMode},
     text::font::SharedTextCx,
 };
 use bevy::{
@@ -30,16 +33,21 @@ use bevy::{
     },
 };
 use lyon::path::PathEvent;
+use nannou_core::geom;
 use uuid::Uuid;
 
 pub mod background;
 mod drawing;
 pub mod indirect;
 pub mod instanced;
+pub mod mask;
 pub mod mesh;
+pub mod picture;
 pub mod primitive;
 pub mod properties;
 pub(crate) mod render;
+pub mod sprite;
+mod svg;
 pub mod theme;
 
 /// A simple API for drawing 2D and 3D graphics.
@@ -61,7 +69,7 @@ pub struct Draw {
     /// The state of the [Draw].
     ///
     /// State is shared between this [Draw] instance and all other [Draw] instances that were
-    /// produced by cloning or changing transform, scissor or blend mode.
+    /// produced by cloning or changing transform, scissor, mask or blend mode.
     ///
     /// We use a `RefCell` in order to avoid requiring a `mut` handle to a `draw`. The primary
     /// purpose of a [Draw] is to be an easy-as-possible, high-level API for drawing stuff. In
@@ -72,7 +80,12 @@ pub struct Draw {
     context: DrawContext,
     /// The current type erased shader model of this [Draw] instance.
     pub(crate) shader_model: UntypedAssetId,
-    /// The window to which this [Draw] instance is associated.
+    /// The area to which drawings made via this [Draw] instance are clipped.
+    pub(crate) scissor: Scissor,
+    /// The mask to which drawings made via this [Draw] instance are clipped, if any.
+    pub(crate) mask: Option<Arc<Mask>>,
+    /// The window to which this [Draw] instance is associated, or the camera entity for draws
+    /// rendering to an image.
     pub(crate) window: Entity,
     /// Shared text context.
     pub(crate) text_cx: SharedTextCx,
@@ -98,6 +111,7 @@ impl<'a> Deref for DrawRef<'a> {
 
 /// The current **Transform** of a [Draw] instance.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DrawContext {
     // TODO: figure out how to fixup camera via transform
     pub transform: Mat4,
@@ -111,20 +125,46 @@ impl Default for DrawContext {
     }
 }
 
+/// The area of the output to which drawing is clipped.
+///
+/// See [Draw::scissor] for details.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Scissor {
+    /// Drawings are not clipped.
+    #[default]
+    Full,
+    /// Drawings are clipped to the given rectangle, specified in the window's coordinate space
+    /// where the origin is the centre of the window.
+    #[cfg_attr(feature = "serde", serde(with = "crate::record::geom_rect"))]
+    Rect(geom::Rect),
+    /// Nested scissors had no overlap, so nothing is drawn.
+    NoOverlap,
+}
+
 /// Commands generated by drawing that instruct how to create the meshes and shader model that will be
 /// rendered.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DrawCommand {
     /// Draw a primitive.
     Primitive(Primitive),
     /// Draw an instanced primitive
     Instanced(Primitive, Range<u32>),
     /// Draw a primitive using an indirect buffer.
+    ///
+    /// Indirect buffers live on the GPU, so these commands are not serialized.
+    #[cfg_attr(feature = "serde", serde(skip))]
     Indirect(Primitive, Handle<ShaderBuffer>),
     /// A change in the rendering context occurred.
     Context(DrawContext),
     /// A change in the shader model occurred.
+    #[cfg_attr(feature = "serde", serde(with = "crate::record::asset_id"))]
     ShaderModel(UntypedAssetId),
+    /// A change in the scissor occurred.
+    Scissor(Scissor),
+    /// A change in the mask occurred.
+    Mask(Option<Arc<Mask>>),
     /// A change in the background color occurred.
     BackgroundColor(Color),
 }
@@ -141,6 +181,15 @@ pub struct State {
     last_shader_model: Option<UntypedAssetId>,
     /// The last context used to draw an image, used to detect changes and emit commands for them.
     last_draw_context: Option<DrawContext>,
+    /// The last scissor used to draw an image, used to detect changes and emit commands for them.
+    ///
+    /// Starts as [`Scissor::Full`], the renderer's initial state, so it is only recorded once it
+    /// changes.
+    last_scissor: Scissor,
+    /// The last mask used to draw an image, used to detect changes and emit commands for them.
+    ///
+    /// Starts as `None`, the renderer's initial state, so it is only recorded once it changes.
+    last_mask: Option<Arc<Mask>>,
     /// If `Some`, the [Draw] should first clear the frame's texture with the given color.
     background_color: Option<Color>,
     /// Primitives that are in the process of being drawn.
@@ -164,13 +213,19 @@ pub struct State {
 
 /// State made accessible via the `DrawingContext`.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IntermediaryState {
     /// Buffers of vertex data that may be re-used for paths, meshes, etc between view calls.
+    #[cfg_attr(feature = "serde", serde(with = "crate::record::mesh"))]
     pub intermediary_mesh: Mesh,
     /// A re-usable buffer for collecting path events.
     pub path_event_buffer: Vec<PathEvent>,
     /// A re-usable buffer for collecting polyline points vertex data.
     pub path_points_vertex_buffer: Vec<(Vec2, Color, Vec2)>,
+    /// A re-usable buffer for collecting the points and weights of variable-width polylines.
+    pub path_points_weighted_buffer: Vec<(Vec2, Color, f32)>,
+    /// A re-usable buffer for collecting the points of point clouds.
+    pub points_buffer: Vec<primitive::points::Point>,
     /// A buffer containing all text.
     pub text_buffer: String,
 }
@@ -180,6 +235,8 @@ impl IntermediaryState {
         self.intermediary_mesh.clear();
         self.path_event_buffer.clear();
         self.path_points_vertex_buffer.clear();
+        self.path_points_weighted_buffer.clear();
+        self.points_buffer.clear();
         self.text_buffer.clear();
     }
 }
@@ -189,6 +246,8 @@ impl State {
     fn reset(&mut self) {
         self.last_shader_model = None;
         self.last_draw_context = None;
+        self.last_scissor = Scissor::Full;
+        self.last_mask = None;
         self.background_color = None;
         self.drawing.clear();
         self.shader_models.clear();
@@ -223,6 +282,35 @@ impl State {
             *elem = Some(DrawCommand::Primitive(prim));
         }
     }
+
+    // Push a command that was recorded elsewhere, e.g. by a replayed frame.
+    //
+    // The last context and shader model are forgotten so that they are emitted again for the
+    // next drawing, while replayed scissors and masks become the last ones recorded.
+    #[cfg(feature = "serde")]
+    pub(crate) fn push_command(&mut self, cmd: DrawCommand) {
+        match cmd {
+            DrawCommand::BackgroundColor(color) => self.background_color = Some(color),
+            DrawCommand::Scissor(scissor) => self.last_scissor = scissor,
+            DrawCommand::Mask(ref mask) => self.last_mask = mask.clone(),
+            _ => (),
+        }
+        self.last_shader_model = None;
+        self.last_draw_context = None;
+        self.draw_commands.push(Some(cmd));
+    }
+
+    // Return to the default scissor and mask, e.g. before replaying commands that were recorded
+    // from a fresh stream.
+    #[cfg(feature = "serde")]
+    pub(crate) fn reset_clip(&mut self) {
+        if self.last_scissor != Scissor::Full {
+            self.push_command(DrawCommand::Scissor(Scissor::Full));
+        }
+        if self.last_mask.is_some() {
+            self.push_command(DrawCommand::Mask(None));
+        }
+    }
 }
 
 impl Draw {
@@ -240,6 +328,8 @@ impl Draw {
             state: Arc::new(RwLock::new(state)),
             context,
             shader_model: model_id,
+            scissor: Scissor::Full,
+            mask: None,
             window,
             text_cx,
         }
@@ -451,16 +541,85 @@ impl Draw {
         self.z_radians(radians)
     }
 
+    /// Produce a new [Draw] instance where the contents are clipped to the given rectangle.
+    ///
+    /// The rectangle is specified in the window's coordinate space, where the origin is the
+    /// centre of the window. It is *not* affected by the transform of the [Draw] instance.
+    ///
+    /// If the [Draw] instance already has a scissor, the new scissor is the overlap of the
+    /// existing scissor and the given rectangle. If the two do not overlap, nothing is drawn.
+    pub fn scissor(&self, scissor: geom::Rect) -> Self {
+        let scissor = match self.scissor {
+            Scissor::Full => Scissor::Rect(scissor),
+            Scissor::Rect(rect) => rect
+                .overlap(scissor)
+                .map(Scissor::Rect)
+                .unwrap_or(Scissor::NoOverlap),
+            Scissor::NoOverlap => Scissor::NoOverlap,
+        };
+        let mut draw = self.clone();
+        draw.scissor = scissor;
+        draw
+    }
+
+    /// Produce a new [Draw] instance where the contents are clipped to the shapes drawn by the
+    /// given function.
+    ///
+    /// The function is provided a [Draw] instance with the same transform as this one. Only the
+    /// geometry of the shapes drawn to it is used - their colors are ignored and they are not
+    /// drawn to the output themselves. E.g. `draw.mask(|m| { m.ellipse().w_h(300.0, 300.0); })`
+    /// produces a [Draw] instance whose drawings only appear within a circle of diameter 300.
+    ///
+    /// If the [Draw] instance already has a mask, drawings are clipped to the overlap of both.
+    ///
+    /// Masks are applied to geometry on the CPU. Points and instanced or indirect drawings are
+    /// positioned on the GPU, so they are neither clipped by a mask nor used as mask shapes.
+    ///
+    /// Text drawn within the mask function is silently ignored: it is rendered separately from
+    /// other geometry, so it adds nothing to the mask and produces no error. Text drawn with the
+    /// returned [Draw] instance is still clipped by the mask.
+    pub fn mask<F>(&self, mask: F) -> Self
+    where
+        F: FnOnce(&Draw),
+    {
+        self.with_mask(mask, false)
+    }
+
+    /// The same as [Draw::mask], but drawings only appear *outside* of the drawn shapes.
+    pub fn inverse_mask<F>(&self, mask: F) -> Self
+    where
+        F: FnOnce(&Draw),
+    {
+        self.with_mask(mask, true)
+    }
+
+    fn with_mask<F>(&self, mask: F, inverse: bool) -> Self
+    where
+        F: FnOnce(&Draw),
+    {
+        let mut mask_draw = Draw::new(self.window, self.text_cx.clone());
+        mask_draw.context = self.context.clone();
+        mask(&mask_draw);
+        let mask = Mask::from_draw(&mask_draw, inverse, self.mask.clone());
+        let mut draw = self.clone();
+        draw.mask = Some(Arc::new(mask));
+        draw
+    }
+
     /// Produce a new [Draw] instance with the given context.
     fn context(&self, context: DrawContext) -> Draw {
         let state = self.state.clone();
         let shader_model = self.shader_model.clone();
+        let scissor = self.scissor;
+        let mask = self.mask.clone();
         let window = self.window;
         let text_cx = self.text_cx.clone();
         Draw {
             state,
             context,
             shader_model,
+            scissor,
+            mask,
             window,
             text_cx,
         }
@@ -501,6 +660,8 @@ impl Draw {
             state,
             context,
             shader_model: model_id,
+            scissor: self.scissor,
+            mask: self.mask.clone(),
             window,
             text_cx,
         }
@@ -551,6 +712,20 @@ impl Draw {
             state.last_shader_model = Some(id.clone());
         }
 
+        if state.last_scissor != self.scissor {
+            state
+                .draw_commands
+                .push(Some(DrawCommand::Scissor(self.scissor)));
+            state.last_scissor = self.scissor;
+        }
+
+        if !mask::same(&state.last_mask, &self.mask) {
+            state
+                .draw_commands
+                .push(Some(DrawCommand::Mask(self.mask.clone())));
+            state.last_mask = self.mask.clone();
+        }
+
         // Insert a model slot to be used if the drawing switches models.
         let shader_model_index = state.draw_commands.len();
         state.draw_commands.push(None);
@@ -572,6 +747,21 @@ impl Draw {
         self.a(Default::default())
     }
 
+    /// Begin drawing an **Arc**, a stroked section of an ellipse's circumference.
+    pub fn arc(&self) -> Drawing<'_, primitive::Arc> {
+        self.a(Default::default())
+    }
+
+    /// Begin drawing a **Pie**, a filled wedge of an ellipse.
+    pub fn pie(&self) -> Drawing<'_, primitive::Pie> {
+        self.a(Default::default())
+    }
+
+    /// Begin drawing a **Ring**, the region between two concentric ellipses.
+    pub fn ring(&self) -> Drawing<'_, primitive::Ring> {
+        self.a(Default::default())
+    }
+
     /// Begin drawing a **Line**.
     pub fn line(&self) -> Drawing<'_, primitive::Line> {
         self.a(Default::default())
@@ -592,6 +782,44 @@ impl Draw {
         self.a(Default::default())
     }
 
+    /// Begin drawing the frame at `index` of the given sprite sheet as a textured **Rect**.
+    ///
+    /// The rect defaults to the frame's size in pixels where the size of the sheet's texture is
+    /// known. Indices out of range draw the whole texture.
+    pub fn sprite(&self, sheet: &SpriteSheet, index: usize) -> Drawing<'_, primitive::Rect> {
+        let drawing = self.rect().texture(sheet.texture());
+        let drawing = match sheet.frame_size(index) {
+            Some(size) => drawing.wh(size),
+            None => drawing,
+        };
+        match sheet.area(index) {
+            Some(area) => drawing.area(area),
+            None => {
+                bevy::log::warn_once!("sprite frame index out of range");
+                drawing
+            }
+        }
+    }
+
+    /// Begin drawing a **NineSlice** of the given texture.
+    ///
+    /// Specify the borders of the texture via `.borders(left, right, top, bottom)`. These are kept
+    /// at their original size while the edges and centre stretch to fill the rectangle, making
+    /// this suitable for resizable UI panels and frames.
+    pub fn nine_slice(&self, texture: &Handle<Image>) -> Drawing<'_, primitive::NineSlice> {
+        self.a(primitive::NineSlice::default()).texture(texture)
+    }
+
+    /// Begin drawing an **Ngon**, a regular polygon with any number of sides.
+    pub fn ngon(&self) -> Drawing<'_, primitive::Ngon> {
+        self.a(Default::default())
+    }
+
+    /// Begin drawing a **Star**.
+    pub fn star(&self) -> Drawing<'_, primitive::Star> {
+        self.a(Default::default())
+    }
+
     /// Begin drawing a **Triangle**.
     pub fn tri(&self) -> Drawing<'_, primitive::Tri> {
         self.a(Default::default())
@@ -602,6 +830,70 @@ impl Draw {
         self.a(Default::default())
     }
 
+    /// Begin drawing a **Bezier** curve from its control points.
+    pub fn bezier(&self) -> Drawing<'_, primitive::Bezier> {
+        self.a(Default::default())
+    }
+
+    /// Begin drawing a **Curve** that passes smoothly through a sequence of points.
+    pub fn curve(&self) -> Drawing<'_, primitive::Curve> {
+        self.a(Default::default())
+    }
+
+    /// Begin drawing a **Spline**, a smooth curve shaped by a sequence of control points.
+    pub fn spline(&self) -> Drawing<'_, primitive::Spline> {
+        self.a(Default::default())
+    }
+
+    /// Begin drawing a solid **Cuboid**.
+    pub fn cuboid(&self) -> Drawing<'_, primitive::Cuboid> {
+        self.a(Default::default())
+    }
+
+    /// Begin drawing a solid **Sphere**.
+    pub fn sphere(&self) -> Drawing<'_, primitive::Sphere> {
+        self.a(Default::default())
+    }
+
+    /// Begin drawing a solid **Cylinder**.
+    pub fn cylinder(&self) -> Drawing<'_, primitive::Cylinder> {
+        self.a(Default::default())
+    }
+
+    /// Begin drawing a solid **Cone**.
+    pub fn cone(&self) -> Drawing<'_, primitive::Cone> {
+        self.a(Default::default())
+    }
+
+    /// Begin drawing a solid **Torus**.
+    pub fn torus(&self) -> Drawing<'_, primitive::Torus> {
+        self.a(Default::default())
+    }
+
+    /// Begin drawing a flat **Plane**, optionally subdivided into a grid of quads.
+    pub fn plane(&self) -> Drawing<'_, primitive::Plane> {
+        self.a(Default::default())
+    }
+
+    /// Begin drawing a cloud of **Points**.
+    ///
+    /// Each point may be given as a position, optionally paired with a color and size, e.g.
+    /// `(pt2(x, y), color, size)`. Points are rendered as sprites by a dedicated shader, making
+    /// this far cheaper than drawing an `ellipse` per point.
+    pub fn points<I>(&self, points: I) -> Drawing<'_, primitive::Points>
+    where
+        I: IntoIterator,
+        I::Item: Into<primitive::points::Point>,
+    {
+        let points = {
+            let state = self.state.read().expect("lock poisoned");
+            let mut intermediary_state = state.intermediary_state.write().expect("lock poisoned");
+            let ctxt = DrawingContext::from_intermediary_state(&mut *intermediary_state);
+            primitive::Points::new(ctxt, points)
+        };
+        self.a(points)
+    }
+
     /// Begin drawing a **Mesh**.
     pub fn mesh(&self) -> Drawing<'_, primitive::mesh::Vertexless> {
         self.a(Default::default())
@@ -614,6 +906,54 @@ impl Draw {
         self.path().stroke()
     }
 
+    /// Begin drawing an **Svg** document.
+    ///
+    /// Accepts either a `&Handle<Svg>` to an asset loaded via the `AssetServer` or a reference to
+    /// an already loaded `Svg`. The paths of the document are drawn in its own colors unless a
+    /// color is specified. Documents referenced by handle are only drawn once loaded, and are not
+    /// available to masks or SVG export.
+    pub fn svg<T>(&self, svg: T) -> Drawing<'_, primitive::PrimitiveSvg>
+    where
+        T: Into<primitive::PrimitiveSvg>,
+    {
+        self.a(svg.into())
+    }
+
+    /// Record the drawings made by the given function into a [Picture] for replaying later.
+    ///
+    /// The function is provided a new [Draw] instance whose drawings are tessellated immediately
+    /// rather than drawn to the output. Replay the result any number of times via
+    /// [Draw::picture], avoiding the cost of tessellating the drawings again each frame.
+    ///
+    /// Masks are applied when recording. Text, instanced drawings, scissors and SVG documents
+    /// drawn via an asset handle are not supported within pictures.
+    pub fn record_picture<F>(&self, record: F) -> Picture
+    where
+        F: FnOnce(&Draw),
+    {
+        let picture_draw = Draw::new(self.window, self.text_cx.clone());
+        record(&picture_draw);
+        Picture::from_draw(&picture_draw)
+    }
+
+    /// Begin drawing a recorded [Picture].
+    ///
+    /// The picture is drawn with this [Draw] instance's transform, along with any position,
+    /// orientation or color tint specified via the builder.
+    pub fn picture(&self, picture: &Picture) -> Drawing<'_, primitive::PrimitivePicture> {
+        // Register the shader model of each layer once per frame.
+        {
+            let mut state = self.state.write().unwrap();
+            for layer in picture.layers() {
+                state
+                    .shader_models
+                    .entry(layer.model_id)
+                    .or_insert_with(|| layer.model.clone_erased());
+            }
+        }
+        self.a(primitive::PrimitivePicture::new(picture))
+    }
+
     /// Begin drawing a **Text**.
     pub fn text(&self, s: &str) -> Drawing<'_, primitive::Text> {
         let text = {
@@ -625,6 +965,14 @@ impl Draw {
         self.a(text)
     }
 
+    /// Begin drawing **Text** made of styled spans, laid out together as a single paragraph.
+    ///
+    /// Spans are appended via [DrawingText::span](primitive::text::DrawingText::span), e.g.
+    /// `draw.rich_text().span("bold", |s| s.bold()).span(" and regular", |s| s)`.
+    pub fn rich_text(&self) -> Drawing<'_, primitive::Text> {
+        self.text("")
+    }
+
     /// Build a text layout immediately for measurement or glyph extraction.
     ///
     /// Unlike `draw.text()` which defers layout to the render pass, this
@@ -633,6 +981,30 @@ impl Draw {
         crate::text::Builder::new(s, self.text_cx.clone())
     }
 
+    /// Write all drawings recorded so far to an SVG file at the given path.
+    ///
+    /// The given `rect` describes the area of the drawing to include, in the same coordinate
+    /// space as drawings (e.g. `app.window_rect()`). The recorded commands are left in place, so
+    /// the drawing is still rendered to the window as usual.
+    ///
+    /// Paths, polylines, shapes and text are written as vector paths with their fill or stroke
+    /// style, with text converted to glyph outlines. Meshes are written as filled triangles.
+    /// Scissors and masks are written as clip paths. Textures, shader models and instanced
+    /// drawings are not supported. No GPU is required.
+    pub fn to_svg<P>(&self, path: P, rect: geom::Rect) -> std::io::Result<()>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        std::fs::write(path, self.to_svg_string(rect))
+    }
+
+    /// Produce an SVG document containing all drawings recorded so far.
+    ///
+    /// See [Draw::to_svg] for details.
+    pub fn to_svg_string(&self, rect: geom::Rect) -> String {
+        svg::document(self, rect)
+    }
+
     /// Finish any drawings-in-progress and produce an iterator draining the inner draw commands
     /// and yielding them by value.
     pub fn drain_commands(&self) -> impl Iterator<Item = DrawCommand> {
@@ -682,6 +1054,7 @@ impl Draw {
                 color: BlendComponent::REPLACE,
                 alpha: blend_descriptor,
             });
+            model.premultiply_alpha = false;
         })
     }
 
@@ -693,6 +1066,7 @@ impl Draw {
                 color: blend_descriptor,
                 alpha: BlendComponent::REPLACE,
             });
+            model.premultiply_alpha = false;
         })
     }
 
@@ -701,6 +1075,17 @@ impl Draw {
         self.color_blend(blend_descriptor)
     }
 
+    /// Produce a new [Draw] instance that will draw with the given blend mode preset.
+    ///
+    /// Unlike `color_blend` and `alpha_blend`, this sets both the color and alpha blend
+    /// components, as well as whether colors are premultiplied by their alpha in the shader.
+    pub fn blend_mode(&self, mode: BlendMode) -> Self {
+        self.map_default_shader_model(|model| {
+            model.blend = Some(mode.blend_state());
+            model.premultiply_alpha = mode.premultiplies_alpha();
+        })
+    }
+
     /// Produce a new [Draw] instance that will use the given polygon mode.
     pub fn polygon_mode(&self, polygon_mode: wgpu::PolygonMode) -> Self {
         self.map_default_shader_model(|model| model.polygon_mode = polygon_mode)
@@ -712,11 +1097,15 @@ impl Default for IntermediaryState {
         let intermediary_mesh = Mesh::init();
         let path_event_buffer = Default::default();
         let path_points_vertex_buffer = Default::default();
+        let path_points_weighted_buffer = Default::default();
+        let points_buffer = Default::default();
         let text_buffer = Default::default();
         IntermediaryState {
             intermediary_mesh,
             path_event_buffer,
             path_points_vertex_buffer,
+            path_points_weighted_buffer,
+            points_buffer,
             text_buffer,
         }
     }
@@ -726,6 +1115,8 @@ impl Default for State {
     fn default() -> Self {
         let last_shader_model = None;
         let last_draw_context = None;
+        let last_scissor = Scissor::Full;
+        let last_mask = None;
         let background_color = Default::default();
         let draw_commands = Default::default();
         let drawing = Default::default();
@@ -734,6 +1125,8 @@ impl Default for State {
         State {
             last_shader_model,
             last_draw_context,
+            last_scissor,
+            last_mask,
             draw_commands,
             drawing,
             intermediary_state,
@@ -0,0 +1,243 @@
+//! Recorded drawings that may be replayed many times at little cost.
+//!
+//! A [Picture] is recorded via [Draw::record_picture]. The shapes drawn within the recording
+//! closure are tessellated once into a set of meshes, one per shader model. Replaying the picture
+//! via [Draw::picture] reuses those meshes rather than tessellating the shapes again, making
+//! pictures well suited to complex, static content like backgrounds.
+
+use std::fmt;
+use std::sync::{Arc, OnceLock};
+
+use bevy::asset::UntypedAssetId;
+use bevy::prelude::*;
+use lyon::tessellation::{FillTessellator, StrokeTessellator};
+use uuid::Uuid;
+
+use crate::draw::mesh::MeshExt;
+use crate::draw::primitive::Primitive;
+use crate::draw::render::{RenderContext, RenderPrimitive};
+use crate::draw::{Draw, DrawCommand, DrawContext, Scissor};
+#[cfg(feature = "serde")]
+use crate::record::RecordedModel;
+#[cfg(feature = "serde")]
+use crate::render::DefaultNannouShaderModel;
+use crate::render::ErasedShaderModel;
+
+/// A drawing recorded via [Draw::record_picture] that may be replayed via [Draw::picture].
+///
+/// Cloning a picture is cheap, as the tessellated meshes are shared between clones.
+#[derive(Clone)]
+pub struct Picture {
+    layers: Arc<[Layer]>,
+}
+
+// The geometry drawn with a single shader model.
+pub(crate) struct Layer {
+    /// The ID under which the shader model is registered with draws replaying the picture.
+    ///
+    /// This is fixed so that replaying the picture many times registers the model only once.
+    pub(crate) model_id: UntypedAssetId,
+    /// The shader model with which the geometry was drawn.
+    pub(crate) model: Box<dyn ErasedShaderModel>,
+    /// The tessellated geometry, in the picture's local space.
+    pub(crate) mesh: Mesh,
+    /// The mesh asset uploaded the first time the layer is rendered.
+    handle: OnceLock<Handle<Mesh>>,
+}
+
+impl Picture {
+    /// Tessellate all primitives drawn to the given `Draw`.
+    ///
+    /// Text, instanced drawings and scissors are not supported within pictures and are ignored.
+    pub(crate) fn from_draw(draw: &Draw) -> Self {
+        let mut fill_tessellator = FillTessellator::new();
+        let mut stroke_tessellator = StrokeTessellator::new();
+        let mut curr_ctx = DrawContext::default();
+        let mut curr_model = None;
+        let mut curr_mask = None;
+        let mut layers: Vec<Layer> = vec![];
+        let mut new_layer = true;
+
+        let cmds: Vec<_> = draw.drain_commands().collect();
+        let state = draw.state.read().unwrap();
+        let intermediary_state = state.intermediary_state.read().unwrap();
+        for cmd in cmds {
+            match cmd {
+                DrawCommand::Primitive(Primitive::Text(_)) => {
+                    bevy::log::warn_once!("text is not supported within pictures");
+                }
+                DrawCommand::Primitive(prim) => {
+                    let Some(model) = curr_model.and_then(|id| state.shader_models.get(&id)) else {
+                        continue;
+                    };
+                    // Start a new layer for the first primitive drawn with each shader model.
+                    if new_layer {
+                        layers.push(Layer::new(model.clone_erased(), Mesh::init()));
+                        new_layer = false;
+                    }
+                    let layer = layers.last_mut().unwrap();
+                    let ctxt = RenderContext {
+                        intermediary_mesh: &intermediary_state.intermediary_mesh,
+                        path_event_buffer: &intermediary_state.path_event_buffer,
+                        path_points_vertex_buffer: &intermediary_state.path_points_vertex_buffer,
+                        path_points_weighted_buffer: &intermediary_state
+                            .path_points_weighted_buffer,
+                        points_buffer: &intermediary_state.points_buffer,
+                        text_buffer: &intermediary_state.text_buffer,
+                        theme: &state.theme,
+                        transform: &curr_ctx.transform,
+                        fill_tessellator: &mut fill_tessellator,
+                        stroke_tessellator: &mut stroke_tessellator,
+                        output_attachment_size: Vec2::ZERO,
+                        output_attachment_scale_factor: 1.0,
+                        vector_paths: None,
+                    };
+                    let vertex_start = layer.mesh.count_vertices();
+                    let index_start = layer.mesh.count_indices();
+                    prim.render_primitive(ctxt, &mut layer.mesh);
+                    if let Some(mask) = &curr_mask {
+                        mask.apply(&mut layer.mesh, vertex_start, index_start);
+                    }
+                }
+                DrawCommand::Instanced(..) | DrawCommand::Indirect(..) => {
+                    bevy::log::warn_once!(
+                        "instanced and indirect drawings are not supported within pictures"
+                    );
+                }
+                DrawCommand::Context(ctx) => curr_ctx = ctx,
+                DrawCommand::ShaderModel(id) => {
+                    new_layer |= curr_model != Some(id);
+                    curr_model = Some(id);
+                }
+                DrawCommand::Scissor(scissor) => {
+                    if scissor != Scissor::Full {
+                        bevy::log::warn_once!("scissors are not supported within pictures");
+                    }
+                }
+                DrawCommand::Mask(mask) => curr_mask = mask,
+                DrawCommand::BackgroundColor(_) => {
+                    bevy::log::warn_once!("backgrounds are not supported within pictures");
+                }
+            }
+        }
+
+        Picture {
+            layers: layers.into(),
+        }
+    }
+
+    /// Whether or not the picture contains no geometry.
+    pub fn is_empty(&self) -> bool {
+        self.layers
+            .iter()
+            .all(|layer| layer.mesh.count_indices() == 0)
+    }
+
+    /// The tessellated meshes of the picture, one for each change in shader model.
+    ///
+    /// Vertices are described in the picture's local space.
+    pub fn meshes(&self) -> impl Iterator<Item = &Mesh> {
+        self.layers.iter().map(|layer| &layer.mesh)
+    }
+
+    pub(crate) fn layers(&self) -> &[Layer] {
+        &self.layers
+    }
+}
+
+impl Layer {
+    fn new(model: Box<dyn ErasedShaderModel>, mesh: Mesh) -> Self {
+        let model_id = UntypedAssetId::Uuid {
+            type_id: model.as_any().type_id(),
+            uuid: Uuid::new_v4(),
+        };
+        Layer {
+            model_id,
+            model,
+            mesh,
+            handle: OnceLock::new(),
+        }
+    }
+
+    /// The mesh asset for the layer, adding it to the given assets on first use.
+    pub(crate) fn mesh_handle(&self, meshes: &mut Assets<Mesh>) -> Handle<Mesh> {
+        self.handle
+            .get_or_init(|| meshes.add(self.mesh.clone()))
+            .clone()
+    }
+}
+
+impl fmt::Debug for Picture {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let vertices: usize = self.meshes().map(|mesh| mesh.count_vertices()).sum();
+        f.debug_struct("Picture")
+            .field("layers", &self.layers.len())
+            .field("vertices", &vertices)
+            .finish()
+    }
+}
+
+// Pictures are serialized as the meshes of their layers. Custom shader models cannot be recorded,
+// so layers drawn with them are deserialized with the default shader model, as are textures.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Picture {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        #[derive(serde::Serialize)]
+        struct LayerRef<'a> {
+            model: Option<RecordedModel>,
+            #[serde(serialize_with = "serialize_mesh")]
+            mesh: &'a Mesh,
+        }
+
+        fn serialize_mesh<S>(mesh: &&Mesh, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            crate::record::mesh::serialize(mesh, serializer)
+        }
+
+        serializer.collect_seq(self.layers.iter().map(|layer| {
+            let model = layer
+                .model
+                .as_any()
+                .downcast_ref::<DefaultNannouShaderModel>();
+            if model.is_none() {
+                bevy::log::warn_once!("custom shader models cannot be recorded");
+            }
+            LayerRef {
+                model: model.map(RecordedModel::new),
+                mesh: &layer.mesh,
+            }
+        }))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Picture {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        struct LayerData {
+            model: Option<RecordedModel>,
+            #[serde(with = "crate::record::mesh")]
+            mesh: Mesh,
+        }
+
+        let layers = Vec::<LayerData>::deserialize(deserializer)?
+            .into_iter()
+            .map(|layer| {
+                let model = layer
+                    .model
+                    .map(|model| model.model(None))
+                    .unwrap_or_default();
+                Layer::new(Box::new(model), layer.mesh)
+            })
+            .collect();
+        Ok(Picture { layers })
+    }
+}
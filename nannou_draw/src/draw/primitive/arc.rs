@@ -0,0 +1,260 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+use lyon::tessellation::StrokeOptions;
+
+use nannou_core::geom;
+
+use crate::draw::primitive::path;
+use crate::draw::primitive::{PathStroke, Primitive};
+use crate::draw::properties::dash::Dashes;
+use crate::draw::properties::spatial::{dimension, orientation, position};
+use crate::draw::properties::{
+    SetColor, SetDashes, SetDimensions, SetOrientation, SetPosition, SetStroke,
+};
+use crate::draw::{self, Drawing};
+
+/// A stroked section of the circumference of an ellipse.
+///
+/// Angles are in radians, counter-clockwise from the positive *x* axis. By default the arc spans
+/// the full circumference.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Arc {
+    pub path: PathStroke,
+    dimensions: dimension::Properties,
+    start_angle: f32,
+    end_angle: f32,
+    resolution: Option<f32>,
+}
+
+/// The drawing context for an arc.
+pub type DrawingArc<'a> = Drawing<'a, Arc>;
+
+impl Arc {
+    /// Short-hand for the `stroke_weight` method.
+    pub fn weight(self, weight: f32) -> Self {
+        self.stroke_weight(weight)
+    }
+
+    /// Specify the width and height of the ellipse along which the arc is drawn via a given
+    /// **radius**.
+    pub fn radius(self, radius: f32) -> Self {
+        let side = radius * 2.0;
+        self.w_h(side, side)
+    }
+
+    /// The angle at which the arc begins.
+    pub fn start_angle(mut self, radians: f32) -> Self {
+        self.start_angle = radians;
+        self
+    }
+
+    /// The angle at which the arc ends.
+    pub fn end_angle(mut self, radians: f32) -> Self {
+        self.end_angle = radians;
+        self
+    }
+
+    /// The angles at which the arc begins and ends.
+    pub fn angles(self, start: f32, end: f32) -> Self {
+        self.start_angle(start).end_angle(end)
+    }
+
+    /// The number of segments used to draw the arc.
+    ///
+    /// By default, the number of segments is determined by the stroke tolerance.
+    pub fn resolution(mut self, resolution: f32) -> Self {
+        self.resolution = Some(resolution);
+        self
+    }
+}
+
+impl<'a> DrawingArc<'a> {
+    /// Short-hand for the `stroke_weight` method.
+    pub fn weight(self, weight: f32) -> Self {
+        self.stroke_weight(weight)
+    }
+
+    /// Specify the width and height of the ellipse along which the arc is drawn via a given
+    /// **radius**.
+    pub fn radius(self, radius: f32) -> Self {
+        let side = radius * 2.0;
+        self.w_h(side, side)
+    }
+
+    /// The angle at which the arc begins.
+    pub fn start_angle(self, radians: f32) -> Self {
+        update_arc(&self.draw, self.index, |arc| arc.start_angle = radians);
+        self
+    }
+
+    /// The angle at which the arc ends.
+    pub fn end_angle(self, radians: f32) -> Self {
+        update_arc(&self.draw, self.index, |arc| arc.end_angle = radians);
+        self
+    }
+
+    /// The angles at which the arc begins and ends.
+    pub fn angles(self, start: f32, end: f32) -> Self {
+        self.start_angle(start).end_angle(end)
+    }
+
+    /// The number of segments used to draw the arc.
+    pub fn resolution(self, resolution: f32) -> Self {
+        update_arc(&self.draw, self.index, |arc| {
+            arc.resolution = Some(resolution)
+        });
+        self
+    }
+}
+
+// Update the inner `Arc` of the primitive being drawn at `index`.
+fn update_arc(draw: &crate::draw::Draw, index: usize, f: impl FnOnce(&mut Arc)) {
+    crate::draw::drawing::with_primitive(draw, index, |prim| match prim {
+        Primitive::Arc(arc) => f(arc),
+        _ => bevy::log::warn_once!("expected an `Arc` primitive"),
+    })
+}
+
+/// The width and height described by the given dimensions, defaulting to `100` on each axis.
+pub(crate) fn ellipse_wh(dimensions: &dimension::Properties, name: &str) -> Vec2 {
+    assert!(
+        dimensions.z.is_none(),
+        "z dimension support for {name} is unimplemented"
+    );
+    let w = dimensions.x.map(f32::abs).unwrap_or(100.0);
+    let h = dimensions.y.map(f32::abs).unwrap_or(100.0);
+    Vec2::new(w, h)
+}
+
+/// The points along the section of the ellipse with the given width and height that begins at
+/// `start` and ends at `end` radians, including both ends.
+///
+/// Without a `resolution`, the number of segments is chosen so that the section is within the
+/// default tessellation tolerance of the true ellipse.
+pub(crate) fn section_points(
+    wh: Vec2,
+    start: f32,
+    end: f32,
+    resolution: Option<f32>,
+) -> impl Clone + Iterator<Item = Vec2> {
+    let sweep = (end - start).clamp(-TAU, TAU);
+    let resolution = resolution.unwrap_or_else(|| {
+        let radius = wh.max_element() * 0.5;
+        let tolerance = lyon::tessellation::StrokeOptions::DEFAULT_TOLERANCE;
+        let step = 2.0 * (1.0 - tolerance / radius).clamp(-1.0, 1.0).acos();
+        (sweep.abs() / step).ceil()
+    });
+    let rect = geom::Rect::from_wh(wh);
+    geom::Ellipse::new(rect, resolution.max(1.0))
+        .section(start, sweep)
+        .circumference()
+        .map(Vec2::from)
+}
+
+/// Whether the section between the given angles covers the full circumference.
+pub(crate) fn is_full(start: f32, end: f32) -> bool {
+    (end - start).abs() >= TAU
+}
+
+/// Texture coordinates for a point within the bounds of an ellipse of the given size.
+pub(crate) fn tex_coords(p: Vec2, wh: Vec2) -> Vec2 {
+    p / wh + 0.5
+}
+
+impl draw::render::RenderPrimitive for Arc {
+    fn render_primitive(self, mut ctxt: draw::render::RenderContext, mesh: &mut Mesh) {
+        let Arc {
+            path,
+            dimensions,
+            start_angle,
+            end_angle,
+            resolution,
+        } = self;
+        let wh = ellipse_wh(&dimensions, "arc");
+        if wh.cmple(Vec2::ZERO).any() || start_angle == end_angle {
+            return;
+        }
+        let close = is_full(start_angle, end_angle);
+        let points =
+            section_points(wh, start_angle, end_angle, resolution).map(|p| (p, tex_coords(p, wh)));
+
+        // Determine the transform to apply to all points.
+        let global_transform = *ctxt.transform;
+        let local_transform = path.position.transform() * path.orientation.transform();
+        let transform = global_transform * local_transform;
+
+        path::render_path_points_themed(
+            points,
+            close,
+            path.color,
+            None,
+            transform,
+            path::Options::Stroke(path.opts),
+            Some(&path.dashes),
+            &ctxt.theme,
+            &draw::theme::Primitive::Arc,
+            &mut ctxt.fill_tessellator,
+            &mut ctxt.stroke_tessellator,
+            ctxt.vector_paths.as_deref_mut(),
+            mesh,
+        );
+    }
+}
+
+impl Default for Arc {
+    fn default() -> Self {
+        Arc {
+            path: Default::default(),
+            dimensions: Default::default(),
+            start_angle: 0.0,
+            end_angle: TAU,
+            resolution: None,
+        }
+    }
+}
+
+impl SetStroke for Arc {
+    fn stroke_options_mut(&mut self) -> &mut StrokeOptions {
+        SetStroke::stroke_options_mut(&mut self.path)
+    }
+}
+
+impl SetDashes for Arc {
+    fn dashes_mut(&mut self) -> &mut Dashes {
+        SetDashes::dashes_mut(&mut self.path)
+    }
+}
+
+impl SetOrientation for Arc {
+    fn properties(&mut self) -> &mut orientation::Properties {
+        SetOrientation::properties(&mut self.path)
+    }
+}
+
+impl SetPosition for Arc {
+    fn properties(&mut self) -> &mut position::Properties {
+        SetPosition::properties(&mut self.path)
+    }
+}
+
+impl SetDimensions for Arc {
+    fn properties(&mut self) -> &mut dimension::Properties {
+        SetDimensions::properties(&mut self.dimensions)
+    }
+}
+
+impl SetColor for Arc {
+    fn color_mut(&mut self) -> &mut Option<Color> {
+        SetColor::color_mut(&mut self.path)
+    }
+}
+
+// Primitive conversion.
+
+impl From<Arc> for Primitive {
+    fn from(prim: Arc) -> Self {
+        Primitive::Arc(prim)
+    }
+}
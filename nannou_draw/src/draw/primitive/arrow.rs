@@ -4,14 +4,16 @@ use lyon::tessellation::StrokeOptions;
 use crate::draw::primitive::Line;
 use crate::draw::primitive::Primitive;
 use crate::draw::primitive::path;
+use crate::draw::properties::dash::Dashes;
 use crate::draw::properties::spatial::{orientation, position};
-use crate::draw::properties::{SetColor, SetOrientation, SetPosition, SetStroke};
+use crate::draw::properties::{SetColor, SetDashes, SetOrientation, SetPosition, SetStroke};
 use crate::draw::{self, Drawing};
 
 /// A path containing only two points - a start and end.
 ///
 /// A triangle is drawn on the end to indicate direction.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Arrow {
     line: Line,
     head_length: Option<f32>,
@@ -143,6 +145,12 @@ impl SetStroke for Arrow {
     }
 }
 
+impl SetDashes for Arrow {
+    fn dashes_mut(&mut self) -> &mut Dashes {
+        SetDashes::dashes_mut(&mut self.line)
+    }
+}
+
 impl SetOrientation for Arrow {
     fn properties(&mut self) -> &mut orientation::Properties {
         SetOrientation::properties(&mut self.line)
@@ -221,12 +229,15 @@ impl draw::render::RenderPrimitive for Arrow {
             tri_points,
             close_tri,
             line.path.color,
+            None,
             transform,
             path::Options::Fill(Default::default()),
+            None,
             &ctxt.theme,
             &draw::theme::Primitive::Arrow,
             &mut ctxt.fill_tessellator,
             &mut ctxt.stroke_tessellator,
+            ctxt.vector_paths.as_deref_mut(),
             mesh,
         );
 
@@ -243,12 +254,15 @@ impl draw::render::RenderPrimitive for Arrow {
                 line_points,
                 close_line,
                 line.path.color,
+                None,
                 transform,
                 path::Options::Stroke(line.path.opts),
+                Some(&line.path.dashes),
                 &ctxt.theme,
                 &draw::theme::Primitive::Arrow,
                 &mut ctxt.fill_tessellator,
                 &mut ctxt.stroke_tessellator,
+                ctxt.vector_paths.as_deref_mut(),
                 mesh,
             );
         }
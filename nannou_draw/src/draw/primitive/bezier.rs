@@ -0,0 +1,185 @@
+use bevy::prelude::*;
+use lyon::path::PathEvent;
+use lyon::tessellation::StrokeOptions;
+
+use crate::draw::drawing::{self, DrawingContext};
+use crate::draw::primitive::Primitive;
+use crate::draw::primitive::curve::cubic_events;
+use crate::draw::primitive::polygon::{
+    DrawingPolygon, Polygon, PolygonInit, PolygonOptions, SetPolygon,
+};
+use crate::draw::properties::dash::Dashes;
+use crate::draw::properties::gradient::Gradient;
+use crate::draw::properties::spatial::{orientation, position};
+use crate::draw::properties::{
+    SetColor, SetDashes, SetGradient, SetOrientation, SetPosition, SetStroke,
+};
+use crate::draw::{self, Drawing};
+
+/// One or more bezier curve segments, joined end to end.
+///
+/// Like a polygon, the curve is filled by default. Use `no_fill` and `stroke` to draw its outline
+/// instead.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bezier {
+    polygon: PolygonInit,
+    closed: bool,
+}
+
+/// The drawing context for a bezier curve, prior to submitting its control points.
+pub type DrawingBezier<'a> = Drawing<'a, Bezier>;
+
+impl Bezier {
+    /// Join the end of the curve back to its start with a straight line.
+    pub fn closed(mut self) -> Self {
+        self.closed = true;
+        self
+    }
+
+    /// A quadratic bezier curve from `start` to `end`, pulled towards `ctrl`.
+    pub fn quadratic(self, ctxt: DrawingContext, start: Vec2, ctrl: Vec2, end: Vec2) -> Polygon {
+        let point = |p: Vec2| lyon::math::point(p.x, p.y);
+        let (first, last) = (point(start), point(end));
+        let events = [
+            PathEvent::Begin { at: first },
+            PathEvent::Quadratic {
+                from: first,
+                ctrl: point(ctrl),
+                to: last,
+            },
+            PathEvent::End {
+                last,
+                first,
+                close: self.closed,
+            },
+        ];
+        self.polygon.events(ctxt, events)
+    }
+
+    /// A cubic bezier curve from `start` to `end`, shaped by `ctrl1` and `ctrl2`.
+    pub fn cubic(
+        self,
+        ctxt: DrawingContext,
+        start: Vec2,
+        ctrl1: Vec2,
+        ctrl2: Vec2,
+        end: Vec2,
+    ) -> Polygon {
+        self.segments(ctxt, start, [[ctrl1, ctrl2, end]])
+    }
+
+    /// A sequence of cubic bezier segments beginning at `start`.
+    ///
+    /// Each segment is given as its two control points followed by its end point, which is also
+    /// the start of the next segment. This matches Processing's `bezierVertex`.
+    pub fn segments<I>(self, ctxt: DrawingContext, start: Vec2, segments: I) -> Polygon
+    where
+        I: IntoIterator<Item = [Vec2; 3]>,
+    {
+        let events = cubic_events(start, segments, self.closed);
+        self.polygon.events(ctxt, events)
+    }
+}
+
+impl<'a> DrawingBezier<'a> {
+    /// Join the end of the curve back to its start with a straight line.
+    pub fn closed(self) -> Self {
+        drawing::with_primitive(&self.draw, self.index, |prim| match prim {
+            Primitive::Bezier(bezier) => bezier.closed = true,
+            _ => bevy::log::warn_once!("expected a `Bezier` primitive"),
+        });
+        self
+    }
+
+    /// A quadratic bezier curve from `start` to `end`, pulled towards `ctrl`.
+    pub fn quadratic(self, start: Vec2, ctrl: Vec2, end: Vec2) -> DrawingPolygon<'a> {
+        submit(&self.draw, self.index, |bezier, ctxt| {
+            bezier.quadratic(ctxt, start, ctrl, end)
+        });
+        self.transition()
+    }
+
+    /// A cubic bezier curve from `start` to `end`, shaped by `ctrl1` and `ctrl2`.
+    pub fn cubic(self, start: Vec2, ctrl1: Vec2, ctrl2: Vec2, end: Vec2) -> DrawingPolygon<'a> {
+        submit(&self.draw, self.index, |bezier, ctxt| {
+            bezier.cubic(ctxt, start, ctrl1, ctrl2, end)
+        });
+        self.transition()
+    }
+
+    /// A sequence of cubic bezier segments beginning at `start`.
+    ///
+    /// Each segment is given as its two control points followed by its end point, which is also
+    /// the start of the next segment. This matches Processing's `bezierVertex`.
+    pub fn segments<I>(self, start: Vec2, segments: I) -> DrawingPolygon<'a>
+    where
+        I: IntoIterator<Item = [Vec2; 3]>,
+    {
+        submit(&self.draw, self.index, |bezier, ctxt| {
+            bezier.segments(ctxt, start, segments)
+        });
+        self.transition()
+    }
+}
+
+// Submit the curve, transitioning the primitive from `Bezier` to `Polygon`.
+fn submit(draw: &draw::Draw, index: usize, f: impl FnOnce(Bezier, DrawingContext) -> Polygon) {
+    drawing::with_primitive_ctxt(draw, index, |prim, ctxt| match prim {
+        Primitive::Bezier(bezier) => Primitive::Polygon(f(bezier, ctxt)),
+        other => {
+            bevy::log::warn_once!("expected a `Bezier` primitive");
+            other
+        }
+    })
+}
+
+impl SetOrientation for Bezier {
+    fn properties(&mut self) -> &mut orientation::Properties {
+        SetOrientation::properties(&mut self.polygon)
+    }
+}
+
+impl SetPosition for Bezier {
+    fn properties(&mut self) -> &mut position::Properties {
+        SetPosition::properties(&mut self.polygon)
+    }
+}
+
+impl SetColor for Bezier {
+    fn color_mut(&mut self) -> &mut Option<Color> {
+        SetColor::color_mut(&mut self.polygon)
+    }
+}
+
+impl SetStroke for Bezier {
+    fn stroke_options_mut(&mut self) -> &mut StrokeOptions {
+        SetStroke::stroke_options_mut(&mut self.polygon)
+    }
+}
+
+impl SetDashes for Bezier {
+    fn dashes_mut(&mut self) -> &mut Dashes {
+        SetDashes::dashes_mut(&mut self.polygon)
+    }
+}
+
+impl SetGradient for Bezier {
+    fn gradient_mut(&mut self) -> &mut Gradient {
+        SetGradient::gradient_mut(&mut self.polygon)
+    }
+}
+
+impl SetPolygon for Bezier {
+    fn polygon_options_mut(&mut self) -> &mut PolygonOptions {
+        SetPolygon::polygon_options_mut(&mut self.polygon)
+    }
+}
+
+// Primitive conversion.
+
+impl From<Bezier> for Primitive {
+    fn from(prim: Bezier) -> Self {
+        Primitive::Bezier(prim)
+    }
+}
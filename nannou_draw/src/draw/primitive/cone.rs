@@ -0,0 +1,144 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+use crate::draw::primitive::Primitive;
+use crate::draw::primitive::solid::{self, Geometry};
+use crate::draw::properties::spatial::{self, dimension, orientation, position};
+use crate::draw::properties::{SetColor, SetDimensions, SetOrientation, SetPosition};
+use crate::draw::{self, Drawing};
+
+/// Properties related to drawing a solid **Cone** with a capped base.
+///
+/// The axis of the cone lies along *y* with its apex at the top, so that its height is given by
+/// the *y* dimension and the diameter of its base by the *x* and *z* dimensions.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cone {
+    spatial: spatial::Properties,
+    color: Option<Color>,
+    resolution: u32,
+}
+
+/// The drawing context for a cone.
+pub type DrawingCone<'a> = Drawing<'a, Cone>;
+
+impl Cone {
+    /// Specify the width and depth of the base of the **Cone** via a given **radius**.
+    pub fn radius(self, radius: f32) -> Self {
+        let side = radius * 2.0;
+        self.w(side).d(side)
+    }
+
+    /// The number of flat faces running from the base of the **Cone** to its apex, also used for
+    /// the outline of the base.
+    ///
+    /// Clamped to a minimum of `3`, which draws a triangular pyramid. Defaults to `32`.
+    pub fn resolution(mut self, resolution: u32) -> Self {
+        self.resolution = resolution;
+        self
+    }
+}
+
+impl<'a> DrawingCone<'a> {
+    /// Specify the width and depth of the base of the **Cone** via a given **radius**.
+    pub fn radius(self, radius: f32) -> Self {
+        let side = radius * 2.0;
+        self.w(side).d(side)
+    }
+
+    /// The number of flat faces running from the base of the cone to its apex.
+    pub fn resolution(self, resolution: u32) -> Self {
+        update_cone(&self.draw, self.index, |cone| cone.resolution = resolution);
+        self
+    }
+}
+
+// Update the inner `Cone` of the primitive being drawn at `index`.
+fn update_cone(draw: &crate::draw::Draw, index: usize, f: impl FnOnce(&mut Cone)) {
+    crate::draw::drawing::with_primitive(draw, index, |prim| match prim {
+        Primitive::Cone(cone) => f(cone),
+        _ => bevy::log::warn_once!("expected a `Cone` primitive"),
+    })
+}
+
+impl draw::render::RenderPrimitive for Cone {
+    fn render_primitive(self, ctxt: draw::render::RenderContext, mesh: &mut Mesh) {
+        let Cone {
+            spatial,
+            color,
+            resolution,
+        } = self;
+        let whd = solid::whd(&spatial.dimensions);
+        if whd.cmple(Vec3::ZERO).any() {
+            return;
+        }
+        let resolution = resolution.max(3);
+        let radii = Vec2::new(whd.x, whd.z) * 0.5;
+        let half_h = whd.y * 0.5;
+        let mut geometry = Geometry::default();
+        // The apex is repeated for each segment so that every side keeps its own normal.
+        geometry.grid(resolution, 1, |u, v| {
+            let (sin, cos) = (u * TAU).sin_cos();
+            let scale = 1.0 - v;
+            let point = Vec3::new(
+                cos * radii.x * scale,
+                (v - 0.5) * whd.y,
+                -sin * radii.y * scale,
+            );
+            let normal = Vec3::new(cos / radii.x, 1.0 / whd.y, -sin / radii.y).normalize();
+            (point, normal)
+        });
+        geometry.disc(-half_h, radii, false, resolution);
+        geometry.render(
+            spatial.position,
+            spatial.orientation,
+            color,
+            ctxt,
+            &draw::theme::Primitive::Cone,
+            mesh,
+        );
+    }
+}
+
+impl Default for Cone {
+    fn default() -> Self {
+        Cone {
+            spatial: Default::default(),
+            color: None,
+            resolution: 32,
+        }
+    }
+}
+
+impl SetOrientation for Cone {
+    fn properties(&mut self) -> &mut orientation::Properties {
+        SetOrientation::properties(&mut self.spatial)
+    }
+}
+
+impl SetPosition for Cone {
+    fn properties(&mut self) -> &mut position::Properties {
+        SetPosition::properties(&mut self.spatial)
+    }
+}
+
+impl SetDimensions for Cone {
+    fn properties(&mut self) -> &mut dimension::Properties {
+        SetDimensions::properties(&mut self.spatial)
+    }
+}
+
+impl SetColor for Cone {
+    fn color_mut(&mut self) -> &mut Option<Color> {
+        SetColor::color_mut(&mut self.color)
+    }
+}
+
+// Primitive conversion.
+
+impl From<Cone> for Primitive {
+    fn from(prim: Cone) -> Self {
+        Primitive::Cone(prim)
+    }
+}
@@ -0,0 +1,82 @@
+use bevy::prelude::*;
+
+use crate::draw::primitive::Primitive;
+use crate::draw::primitive::solid::{self, Geometry};
+use crate::draw::properties::spatial::{self, dimension, orientation, position};
+use crate::draw::properties::{SetColor, SetDimensions, SetOrientation, SetPosition};
+use crate::draw::{self, Drawing};
+
+/// Properties related to drawing a solid **Cuboid**.
+///
+/// Each face is given its own normal, and is textured with the full texture.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cuboid {
+    spatial: spatial::Properties,
+    color: Option<Color>,
+}
+
+/// The drawing context for a cuboid.
+pub type DrawingCuboid<'a> = Drawing<'a, Cuboid>;
+
+impl draw::render::RenderPrimitive for Cuboid {
+    fn render_primitive(self, ctxt: draw::render::RenderContext, mesh: &mut Mesh) {
+        let Cuboid { spatial, color } = self;
+        let whd = solid::whd(&spatial.dimensions);
+        if whd.cmple(Vec3::ZERO).any() {
+            return;
+        }
+        let (x, y, z) = (Vec3::X * whd.x, Vec3::Y * whd.y, Vec3::Z * whd.z);
+        // Each face as its *u* and *v* axes, whose cross product is the outward normal.
+        let faces = [(x, y), (-x, y), (-z, y), (z, y), (x, -z), (x, z)];
+        let mut geometry = Geometry::default();
+        for (u_axis, v_axis) in faces {
+            let normal = u_axis.cross(v_axis).normalize();
+            let centre = normal * whd * 0.5;
+            geometry.grid(1, 1, |u, v| {
+                let point = centre + u_axis * (u - 0.5) + v_axis * (v - 0.5);
+                (point, normal)
+            });
+        }
+        geometry.render(
+            spatial.position,
+            spatial.orientation,
+            color,
+            ctxt,
+            &draw::theme::Primitive::Cuboid,
+            mesh,
+        );
+    }
+}
+
+impl SetOrientation for Cuboid {
+    fn properties(&mut self) -> &mut orientation::Properties {
+        SetOrientation::properties(&mut self.spatial)
+    }
+}
+
+impl SetPosition for Cuboid {
+    fn properties(&mut self) -> &mut position::Properties {
+        SetPosition::properties(&mut self.spatial)
+    }
+}
+
+impl SetDimensions for Cuboid {
+    fn properties(&mut self) -> &mut dimension::Properties {
+        SetDimensions::properties(&mut self.spatial)
+    }
+}
+
+impl SetColor for Cuboid {
+    fn color_mut(&mut self) -> &mut Option<Color> {
+        SetColor::color_mut(&mut self.color)
+    }
+}
+
+// Primitive conversion.
+
+impl From<Cuboid> for Primitive {
+    fn from(prim: Cuboid) -> Self {
+        Primitive::Cuboid(prim)
+    }
+}
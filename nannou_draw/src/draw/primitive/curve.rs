@@ -0,0 +1,205 @@
+use bevy::prelude::*;
+use lyon::path::PathEvent;
+use lyon::tessellation::StrokeOptions;
+
+use crate::draw::drawing::{self, DrawingContext};
+use crate::draw::primitive::Primitive;
+use crate::draw::primitive::polygon::{
+    DrawingPolygon, Polygon, PolygonInit, PolygonOptions, SetPolygon,
+};
+use crate::draw::properties::dash::Dashes;
+use crate::draw::properties::gradient::Gradient;
+use crate::draw::properties::spatial::{orientation, position};
+use crate::draw::properties::{
+    SetColor, SetDashes, SetGradient, SetOrientation, SetPosition, SetStroke,
+};
+use crate::draw::{self, Drawing};
+
+/// A smooth Catmull-Rom curve passing through each of a sequence of points.
+///
+/// Like a polygon, the curve is filled by default. Use `no_fill` and `stroke` to draw its outline
+/// instead.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Curve {
+    polygon: PolygonInit,
+    tension: f32,
+    closed: bool,
+}
+
+/// The drawing context for a curve, prior to submitting its points.
+pub type DrawingCurve<'a> = Drawing<'a, Curve>;
+
+impl Curve {
+    /// The tension of the curve.
+    ///
+    /// `0.0` (the default) produces a Catmull-Rom curve, while `1.0` connects the points with
+    /// straight lines. Values in between tighten the curve around the points.
+    pub fn tension(mut self, tension: f32) -> Self {
+        self.tension = tension;
+        self
+    }
+
+    /// Join the last point back to the first so that the curve forms a smooth loop.
+    pub fn closed(mut self) -> Self {
+        self.closed = true;
+        self
+    }
+
+    /// Submit the points through which the curve passes.
+    pub fn points<I>(self, ctxt: DrawingContext, points: I) -> Polygon
+    where
+        I: IntoIterator,
+        I::Item: Into<Vec2>,
+    {
+        let points: Vec<Vec2> = points.into_iter().map(Into::into).collect();
+        let events = catmull_rom(&points, self.tension, self.closed);
+        self.polygon.events(ctxt, events)
+    }
+}
+
+impl<'a> DrawingCurve<'a> {
+    /// The tension of the curve.
+    ///
+    /// `0.0` (the default) produces a Catmull-Rom curve, while `1.0` connects the points with
+    /// straight lines. Values in between tighten the curve around the points.
+    pub fn tension(self, tension: f32) -> Self {
+        update_curve(&self.draw, self.index, |curve| curve.tension = tension);
+        self
+    }
+
+    /// Join the last point back to the first so that the curve forms a smooth loop.
+    pub fn closed(self) -> Self {
+        update_curve(&self.draw, self.index, |curve| curve.closed = true);
+        self
+    }
+
+    /// Submit the points through which the curve passes.
+    pub fn points<I>(self, points: I) -> DrawingPolygon<'a>
+    where
+        I: IntoIterator,
+        I::Item: Into<Vec2>,
+    {
+        let points: Vec<Vec2> = points.into_iter().map(Into::into).collect();
+        drawing::with_primitive_ctxt(&self.draw, self.index, |prim, ctxt| match prim {
+            Primitive::Curve(curve) => Primitive::Polygon(curve.points(ctxt, points)),
+            other => {
+                bevy::log::warn_once!("expected a `Curve` primitive");
+                other
+            }
+        });
+        self.transition()
+    }
+}
+
+// Update the inner `Curve` of the primitive being drawn at `index`.
+fn update_curve(draw: &draw::Draw, index: usize, f: impl FnOnce(&mut Curve)) {
+    drawing::with_primitive(draw, index, |prim| match prim {
+        Primitive::Curve(curve) => f(curve),
+        _ => bevy::log::warn_once!("expected a `Curve` primitive"),
+    })
+}
+
+/// The path events for a cardinal spline passing through each of the given points.
+///
+/// A `tension` of `0.0` produces a Catmull-Rom spline, while `1.0` produces straight lines. Open
+/// curves leave their end points along the line towards their neighbour.
+pub fn catmull_rom(points: &[Vec2], tension: f32, closed: bool) -> Vec<PathEvent> {
+    let n = points.len();
+    if n < 2 {
+        return vec![];
+    }
+    let point = |i: isize| {
+        let i = if closed {
+            i.rem_euclid(n as isize)
+        } else {
+            i.clamp(0, n as isize - 1)
+        };
+        points[i as usize]
+    };
+    let tangent = |i: isize| (point(i + 1) - point(i - 1)) * (1.0 - tension) * 0.5;
+    let segments = if closed { n } else { n - 1 };
+    let cubics = (0..segments as isize).map(|i| {
+        let (from, to) = (point(i), point(i + 1));
+        let ctrl1 = from + tangent(i) / 3.0;
+        let ctrl2 = to - tangent(i + 1) / 3.0;
+        [ctrl1, ctrl2, to]
+    });
+    cubic_events(points[0], cubics, closed)
+}
+
+/// The path events for a single sub-path of cubic bezier segments beginning at `from`.
+///
+/// Each segment is given as its two control points followed by its end point.
+pub(crate) fn cubic_events(
+    from: Vec2,
+    segments: impl IntoIterator<Item = [Vec2; 3]>,
+    close: bool,
+) -> Vec<PathEvent> {
+    let point = |p: Vec2| lyon::math::point(p.x, p.y);
+    let first = point(from);
+    let mut events = vec![PathEvent::Begin { at: first }];
+    let mut last = first;
+    for [ctrl1, ctrl2, to] in segments {
+        let to = point(to);
+        events.push(PathEvent::Cubic {
+            from: last,
+            ctrl1: point(ctrl1),
+            ctrl2: point(ctrl2),
+            to,
+        });
+        last = to;
+    }
+    events.push(PathEvent::End { last, first, close });
+    events
+}
+
+impl SetOrientation for Curve {
+    fn properties(&mut self) -> &mut orientation::Properties {
+        SetOrientation::properties(&mut self.polygon)
+    }
+}
+
+impl SetPosition for Curve {
+    fn properties(&mut self) -> &mut position::Properties {
+        SetPosition::properties(&mut self.polygon)
+    }
+}
+
+impl SetColor for Curve {
+    fn color_mut(&mut self) -> &mut Option<Color> {
+        SetColor::color_mut(&mut self.polygon)
+    }
+}
+
+impl SetStroke for Curve {
+    fn stroke_options_mut(&mut self) -> &mut StrokeOptions {
+        SetStroke::stroke_options_mut(&mut self.polygon)
+    }
+}
+
+impl SetDashes for Curve {
+    fn dashes_mut(&mut self) -> &mut Dashes {
+        SetDashes::dashes_mut(&mut self.polygon)
+    }
+}
+
+impl SetGradient for Curve {
+    fn gradient_mut(&mut self) -> &mut Gradient {
+        SetGradient::gradient_mut(&mut self.polygon)
+    }
+}
+
+impl SetPolygon for Curve {
+    fn polygon_options_mut(&mut self) -> &mut PolygonOptions {
+        SetPolygon::polygon_options_mut(&mut self.polygon)
+    }
+}
+
+// Primitive conversion.
+
+impl From<Curve> for Primitive {
+    fn from(prim: Curve) -> Self {
+        Primitive::Curve(prim)
+    }
+}
@@ -0,0 +1,141 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+use crate::draw::primitive::Primitive;
+use crate::draw::primitive::solid::{self, Geometry};
+use crate::draw::properties::spatial::{self, dimension, orientation, position};
+use crate::draw::properties::{SetColor, SetDimensions, SetOrientation, SetPosition};
+use crate::draw::{self, Drawing};
+
+/// Properties related to drawing a solid **Cylinder** with capped ends.
+///
+/// The axis of the cylinder lies along *y*, so that its height is given by the *y* dimension
+/// and its diameter by the *x* and *z* dimensions.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cylinder {
+    spatial: spatial::Properties,
+    color: Option<Color>,
+    resolution: u32,
+}
+
+/// The drawing context for a cylinder.
+pub type DrawingCylinder<'a> = Drawing<'a, Cylinder>;
+
+impl Cylinder {
+    /// Specify the width and depth of the **Cylinder** via a given **radius**.
+    pub fn radius(self, radius: f32) -> Self {
+        let side = radius * 2.0;
+        self.w(side).d(side)
+    }
+
+    /// The number of flat sides approximating the curved wall of the **Cylinder**, also used for
+    /// the outline of both caps.
+    ///
+    /// Clamped to a minimum of `3`, which draws a triangular prism. Defaults to `32`.
+    pub fn resolution(mut self, resolution: u32) -> Self {
+        self.resolution = resolution;
+        self
+    }
+}
+
+impl<'a> DrawingCylinder<'a> {
+    /// Specify the width and depth of the **Cylinder** via a given **radius**.
+    pub fn radius(self, radius: f32) -> Self {
+        let side = radius * 2.0;
+        self.w(side).d(side)
+    }
+
+    /// The number of flat sides approximating the curved wall of the cylinder.
+    pub fn resolution(self, resolution: u32) -> Self {
+        update_cylinder(&self.draw, self.index, |cylinder| {
+            cylinder.resolution = resolution
+        });
+        self
+    }
+}
+
+// Update the inner `Cylinder` of the primitive being drawn at `index`.
+fn update_cylinder(draw: &crate::draw::Draw, index: usize, f: impl FnOnce(&mut Cylinder)) {
+    crate::draw::drawing::with_primitive(draw, index, |prim| match prim {
+        Primitive::Cylinder(cylinder) => f(cylinder),
+        _ => bevy::log::warn_once!("expected a `Cylinder` primitive"),
+    })
+}
+
+impl draw::render::RenderPrimitive for Cylinder {
+    fn render_primitive(self, ctxt: draw::render::RenderContext, mesh: &mut Mesh) {
+        let Cylinder {
+            spatial,
+            color,
+            resolution,
+        } = self;
+        let whd = solid::whd(&spatial.dimensions);
+        if whd.cmple(Vec3::ZERO).any() {
+            return;
+        }
+        let resolution = resolution.max(3);
+        let radii = Vec2::new(whd.x, whd.z) * 0.5;
+        let half_h = whd.y * 0.5;
+        let mut geometry = Geometry::default();
+        geometry.grid(resolution, 1, |u, v| {
+            let (sin, cos) = (u * TAU).sin_cos();
+            let point = Vec3::new(cos * radii.x, (v - 0.5) * whd.y, -sin * radii.y);
+            let normal = Vec3::new(cos / radii.x, 0.0, -sin / radii.y).normalize();
+            (point, normal)
+        });
+        geometry.disc(half_h, radii, true, resolution);
+        geometry.disc(-half_h, radii, false, resolution);
+        geometry.render(
+            spatial.position,
+            spatial.orientation,
+            color,
+            ctxt,
+            &draw::theme::Primitive::Cylinder,
+            mesh,
+        );
+    }
+}
+
+impl Default for Cylinder {
+    fn default() -> Self {
+        Cylinder {
+            spatial: Default::default(),
+            color: None,
+            resolution: 32,
+        }
+    }
+}
+
+impl SetOrientation for Cylinder {
+    fn properties(&mut self) -> &mut orientation::Properties {
+        SetOrientation::properties(&mut self.spatial)
+    }
+}
+
+impl SetPosition for Cylinder {
+    fn properties(&mut self) -> &mut position::Properties {
+        SetPosition::properties(&mut self.spatial)
+    }
+}
+
+impl SetDimensions for Cylinder {
+    fn properties(&mut self) -> &mut dimension::Properties {
+        SetDimensions::properties(&mut self.spatial)
+    }
+}
+
+impl SetColor for Cylinder {
+    fn color_mut(&mut self) -> &mut Option<Color> {
+        SetColor::color_mut(&mut self.color)
+    }
+}
+
+// Primitive conversion.
+
+impl From<Cylinder> for Primitive {
+    fn from(prim: Cylinder) -> Self {
+        Primitive::Cylinder(prim)
+    }
+}
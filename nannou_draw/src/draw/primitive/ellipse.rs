@@ -7,13 +7,17 @@ use crate::draw;
 use crate::draw::Drawing;
 use crate::draw::primitive::Primitive;
 use crate::draw::primitive::polygon::{self, PolygonInit, PolygonOptions, SetPolygon};
+use crate::draw::properties::dash::Dashes;
+use crate::draw::properties::gradient::Gradient;
 use crate::draw::properties::spatial::{dimension, orientation, position};
 use crate::draw::properties::{
-    SetColor, SetDimensions, SetOrientation, SetPosition, SetStroke, spatial,
+    SetColor, SetDashes, SetDimensions, SetGradient, SetOrientation, SetPosition, SetStroke,
+    spatial,
 };
 
 /// Properties related to drawing an **Ellipse**.
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Ellipse {
     dimensions: spatial::dimension::Properties,
     resolution: Option<f32>,
@@ -26,14 +30,6 @@ pub type DrawingEllipse<'a> = Drawing<'a, Ellipse>;
 // Ellipse-specific methods.
 
 impl Ellipse {
-    /// Stroke the outline with the given color.
-    pub fn stroke<C>(self, color: C) -> Self
-    where
-        C: Into<Color>,
-    {
-        self.stroke_color(color)
-    }
-
     /// Specify the width and height of the **Ellipse** via a given **radius**.
     pub fn radius(self, radius: f32) -> Self {
         let side = radius * 2.0;
@@ -163,6 +159,18 @@ impl SetStroke for Ellipse {
     }
 }
 
+impl SetDashes for Ellipse {
+    fn dashes_mut(&mut self) -> &mut Dashes {
+        SetDashes::dashes_mut(&mut self.polygon)
+    }
+}
+
+impl SetGradient for Ellipse {
+    fn gradient_mut(&mut self) -> &mut Gradient {
+        SetGradient::gradient_mut(&mut self.polygon)
+    }
+}
+
 impl SetPolygon for Ellipse {
     fn polygon_options_mut(&mut self) -> &mut PolygonOptions {
         SetPolygon::polygon_options_mut(&mut self.polygon)
@@ -180,14 +188,6 @@ impl From<Ellipse> for Primitive {
 // Drawing methods.
 
 impl<'a> DrawingEllipse<'a> {
-    /// Stroke the outline with the given color.
-    pub fn stroke<C>(self, color: C) -> Self
-    where
-        C: Into<Color>,
-    {
-        self.stroke_color(color)
-    }
-
     /// Specify the width and height of the **Ellipse** via a given **radius**.
     pub fn radius(self, radius: f32) -> Self {
         let side = radius * 2.0;
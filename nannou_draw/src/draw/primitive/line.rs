@@ -3,8 +3,9 @@ use lyon::tessellation::StrokeOptions;
 
 use crate::draw::primitive::path;
 use crate::draw::primitive::{PathStroke, Primitive};
+use crate::draw::properties::dash::Dashes;
 use crate::draw::properties::spatial::{orientation, position};
-use crate::draw::properties::{SetColor, SetOrientation, SetPosition, SetStroke};
+use crate::draw::properties::{SetColor, SetDashes, SetOrientation, SetPosition, SetStroke};
 use crate::draw::{self, Drawing};
 
 /// A path containing only two points - a start and end.
@@ -12,6 +13,7 @@ use crate::draw::{self, Drawing};
 /// The usage of this type is almost identical to `PathStroke` but provides `start`, `end` and
 /// `points(a, b)` methods.
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Line {
     pub path: PathStroke,
     pub start: Option<Vec2>,
@@ -103,6 +105,12 @@ impl SetStroke for Line {
     }
 }
 
+impl SetDashes for Line {
+    fn dashes_mut(&mut self) -> &mut Dashes {
+        SetDashes::dashes_mut(&mut self.path)
+    }
+}
+
 impl SetOrientation for Line {
     fn properties(&mut self) -> &mut orientation::Properties {
         SetOrientation::properties(&mut self.path)
@@ -149,12 +157,15 @@ impl draw::render::RenderPrimitive for Line {
             points,
             close,
             path.color,
+            None,
             transform,
             path::Options::Stroke(path.opts),
+            Some(&path.dashes),
             &ctxt.theme,
             &draw::theme::Primitive::Line,
             &mut ctxt.fill_tessellator,
             &mut ctxt.stroke_tessellator,
+            ctxt.vector_paths.as_deref_mut(),
             mesh,
         );
     }
@@ -12,10 +12,12 @@ use crate::draw::{self, Drawing};
 
 /// The mesh type prior to being initialised with vertices or indices.
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vertexless;
 
 /// Properties related to drawing an arbitrary mesh of colours, geometry and texture.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PrimitiveMesh {
     position: position::Properties,
     orientation: orientation::Properties,
@@ -25,6 +27,7 @@ pub struct PrimitiveMesh {
 }
 
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct FillColor(Option<Color>);
 
 pub type DrawingMesh<'a> = Drawing<'a, PrimitiveMesh>;
@@ -290,6 +293,13 @@ impl PrimitiveMesh {
             fill_color,
         }
     }
+
+    #[cfg(feature = "serde")]
+    pub(crate) fn offset_buffers(&mut self, offsets: &crate::record::BufferOffsets) {
+        let (v, i) = (offsets.vertices, offsets.indices);
+        self.vertex_range = self.vertex_range.start + v..self.vertex_range.end + v;
+        self.index_range = self.index_range.start + i..self.index_range.end + i;
+    }
 }
 
 impl<'a> Drawing<'a, Vertexless> {
@@ -4,32 +4,72 @@ use lyon::tessellation::{FillOptions, StrokeOptions};
 use nannou_core::geom::{Vec2, Vec3};
 
 use crate::draw::primitive::polygon::{PolygonOptions, SetPolygon};
+use crate::draw::properties::dash::Dashes;
+use crate::draw::properties::gradient::Gradient;
 use crate::draw::properties::spatial::{dimension, orientation, position};
 use crate::draw::properties::tex_coords::SetTexCoords;
 use crate::draw::properties::{
-    SetColor, SetDimensions, SetFill, SetOrientation, SetPosition, SetStroke,
+    SetColor, SetDashes, SetDimensions, SetFill, SetGradient, SetOrientation, SetPosition,
+    SetStroke,
 };
 
+pub use self::arc::Arc;
 pub use self::arrow::Arrow;
+pub use self::bezier::Bezier;
+pub use self::cone::Cone;
+pub use self::cuboid::Cuboid;
+pub use self::curve::Curve;
+pub use self::cylinder::Cylinder;
 pub use self::ellipse::Ellipse;
 pub use self::line::Line;
 pub use self::mesh::PrimitiveMesh;
+pub use self::ngon::Ngon;
+pub use self::nine_slice::NineSlice;
 pub use self::path::{Path, PathFill, PathInit, PathStroke};
+pub use self::picture::PrimitivePicture;
+pub use self::pie::Pie;
+pub use self::plane::Plane;
+pub use self::points::Points;
 pub use self::polygon::{Polygon, PolygonInit};
 pub use self::quad::Quad;
 pub use self::rect::Rect;
-pub use self::text::Text;
+pub use self::ring::Ring;
+pub use self::sphere::Sphere;
+pub use self::spline::Spline;
+pub use self::star::Star;
+pub use self::svg::PrimitiveSvg;
+pub use self::text::{Text, TextMode};
+pub use self::torus::Torus;
 pub use self::tri::Tri;
 
+pub mod arc;
 pub mod arrow;
+pub mod bezier;
+pub mod cone;
+pub mod cuboid;
+pub mod curve;
+pub mod cylinder;
 pub mod ellipse;
 pub mod line;
 pub mod mesh;
+pub mod ngon;
+pub mod nine_slice;
 pub mod path;
+pub mod picture;
+pub mod pie;
+pub mod plane;
+pub mod points;
 pub mod polygon;
 pub mod quad;
 pub mod rect;
+pub mod ring;
+mod solid;
+pub mod sphere;
+pub mod spline;
+pub mod star;
+pub mod svg;
 pub mod text;
+pub mod torus;
 pub mod tri;
 
 type Vertex = (Vec3, Color, Vec2);
@@ -40,21 +80,40 @@ type Vertex = (Vec3, Color, Vec2);
 /// This also allows us to flush all pending drawings to the mesh if `Draw::to_frame` is called
 /// before their respective **Drawing** types are dropped.
 #[derive(Component, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Primitive {
+    Arc(Arc),
     Arrow(Arrow),
+    Bezier(Bezier),
+    Cone(Cone),
+    Cuboid(Cuboid),
+    Curve(Curve),
+    Cylinder(Cylinder),
     Ellipse(Ellipse),
     Line(Line),
     MeshVertexless(mesh::Vertexless),
     Mesh(PrimitiveMesh),
+    Ngon(Ngon),
+    NineSlice(NineSlice),
     PathInit(PathInit),
     PathFill(PathFill),
     PathStroke(PathStroke),
     Path(Path),
+    Picture(PrimitivePicture),
+    Pie(Pie),
+    Plane(Plane),
+    Points(Points),
     PolygonInit(PolygonInit),
     Polygon(Polygon),
     Quad(Quad),
     Rect(Rect),
+    Ring(Ring),
+    Sphere(Sphere),
+    Spline(Spline),
+    Star(Star),
+    Svg(PrimitiveSvg),
     Text(Text),
+    Torus(Torus),
     Tri(Tri),
 }
 
@@ -75,18 +134,36 @@ impl Default for Primitive {
 impl Primitive {
     pub(crate) fn color_mut(&mut self) -> Option<&mut Option<Color>> {
         match self {
+            Primitive::Arc(p) => Some(SetColor::color_mut(p)),
             Primitive::Arrow(p) => Some(SetColor::color_mut(p)),
+            Primitive::Bezier(p) => Some(SetColor::color_mut(p)),
+            Primitive::Cone(p) => Some(SetColor::color_mut(p)),
+            Primitive::Cuboid(p) => Some(SetColor::color_mut(p)),
+            Primitive::Curve(p) => Some(SetColor::color_mut(p)),
+            Primitive::Cylinder(p) => Some(SetColor::color_mut(p)),
             Primitive::Ellipse(p) => Some(SetColor::color_mut(p)),
             Primitive::Line(p) => Some(SetColor::color_mut(p)),
             Primitive::Mesh(p) => Some(SetColor::color_mut(p)),
+            Primitive::Ngon(p) => Some(SetColor::color_mut(p)),
+            Primitive::NineSlice(p) => Some(SetColor::color_mut(p)),
             Primitive::PathFill(p) => Some(SetColor::color_mut(p)),
             Primitive::PathStroke(p) => Some(SetColor::color_mut(p)),
             Primitive::Path(p) => Some(SetColor::color_mut(p)),
+            Primitive::Picture(p) => Some(SetColor::color_mut(p)),
+            Primitive::Pie(p) => Some(SetColor::color_mut(p)),
+            Primitive::Plane(p) => Some(SetColor::color_mut(p)),
+            Primitive::Points(p) => Some(SetColor::color_mut(p)),
             Primitive::PolygonInit(p) => Some(SetColor::color_mut(p)),
             Primitive::Polygon(p) => Some(SetColor::color_mut(p)),
             Primitive::Quad(p) => Some(SetColor::color_mut(p)),
             Primitive::Rect(p) => Some(SetColor::color_mut(p)),
+            Primitive::Ring(p) => Some(SetColor::color_mut(p)),
+            Primitive::Sphere(p) => Some(SetColor::color_mut(p)),
+            Primitive::Spline(p) => Some(SetColor::color_mut(p)),
+            Primitive::Star(p) => Some(SetColor::color_mut(p)),
+            Primitive::Svg(p) => Some(SetColor::color_mut(p)),
             Primitive::Text(p) => Some(SetColor::color_mut(p)),
+            Primitive::Torus(p) => Some(SetColor::color_mut(p)),
             Primitive::Tri(p) => Some(SetColor::color_mut(p)),
             Primitive::MeshVertexless(_) | Primitive::PathInit(_) => None,
         }
@@ -94,18 +171,36 @@ impl Primitive {
 
     pub(crate) fn position_mut(&mut self) -> Option<&mut position::Properties> {
         match self {
+            Primitive::Arc(p) => Some(SetPosition::properties(p)),
             Primitive::Arrow(p) => Some(SetPosition::properties(p)),
+            Primitive::Bezier(p) => Some(SetPosition::properties(p)),
+            Primitive::Cone(p) => Some(SetPosition::properties(p)),
+            Primitive::Cuboid(p) => Some(SetPosition::properties(p)),
+            Primitive::Curve(p) => Some(SetPosition::properties(p)),
+            Primitive::Cylinder(p) => Some(SetPosition::properties(p)),
             Primitive::Ellipse(p) => Some(SetPosition::properties(p)),
             Primitive::Line(p) => Some(SetPosition::properties(p)),
             Primitive::Mesh(p) => Some(SetPosition::properties(p)),
+            Primitive::Ngon(p) => Some(SetPosition::properties(p)),
+            Primitive::NineSlice(p) => Some(SetPosition::properties(p)),
             Primitive::PathFill(p) => Some(SetPosition::properties(p)),
             Primitive::PathStroke(p) => Some(SetPosition::properties(p)),
             Primitive::Path(p) => Some(SetPosition::properties(p)),
+            Primitive::Picture(p) => Some(SetPosition::properties(p)),
+            Primitive::Pie(p) => Some(SetPosition::properties(p)),
+            Primitive::Plane(p) => Some(SetPosition::properties(p)),
+            Primitive::Points(p) => Some(SetPosition::properties(p)),
             Primitive::PolygonInit(p) => Some(SetPosition::properties(p)),
             Primitive::Polygon(p) => Some(SetPosition::properties(p)),
             Primitive::Quad(p) => Some(SetPosition::properties(p)),
             Primitive::Rect(p) => Some(SetPosition::properties(p)),
+            Primitive::Ring(p) => Some(SetPosition::properties(p)),
+            Primitive::Sphere(p) => Some(SetPosition::properties(p)),
+            Primitive::Spline(p) => Some(SetPosition::properties(p)),
+            Primitive::Star(p) => Some(SetPosition::properties(p)),
+            Primitive::Svg(p) => Some(SetPosition::properties(p)),
             Primitive::Text(p) => Some(SetPosition::properties(p)),
+            Primitive::Torus(p) => Some(SetPosition::properties(p)),
             Primitive::Tri(p) => Some(SetPosition::properties(p)),
             Primitive::MeshVertexless(_) | Primitive::PathInit(_) => None,
         }
@@ -113,18 +208,36 @@ impl Primitive {
 
     pub(crate) fn orientation_mut(&mut self) -> Option<&mut orientation::Properties> {
         match self {
+            Primitive::Arc(p) => Some(SetOrientation::properties(p)),
             Primitive::Arrow(p) => Some(SetOrientation::properties(p)),
+            Primitive::Bezier(p) => Some(SetOrientation::properties(p)),
+            Primitive::Cone(p) => Some(SetOrientation::properties(p)),
+            Primitive::Cuboid(p) => Some(SetOrientation::properties(p)),
+            Primitive::Curve(p) => Some(SetOrientation::properties(p)),
+            Primitive::Cylinder(p) => Some(SetOrientation::properties(p)),
             Primitive::Ellipse(p) => Some(SetOrientation::properties(p)),
             Primitive::Line(p) => Some(SetOrientation::properties(p)),
             Primitive::Mesh(p) => Some(SetOrientation::properties(p)),
+            Primitive::Ngon(p) => Some(SetOrientation::properties(p)),
+            Primitive::NineSlice(p) => Some(SetOrientation::properties(p)),
             Primitive::PathFill(p) => Some(SetOrientation::properties(p)),
             Primitive::PathStroke(p) => Some(SetOrientation::properties(p)),
             Primitive::Path(p) => Some(SetOrientation::properties(p)),
+            Primitive::Picture(p) => Some(SetOrientation::properties(p)),
+            Primitive::Pie(p) => Some(SetOrientation::properties(p)),
+            Primitive::Plane(p) => Some(SetOrientation::properties(p)),
+            Primitive::Points(p) => Some(SetOrientation::properties(p)),
             Primitive::PolygonInit(p) => Some(SetOrientation::properties(p)),
             Primitive::Polygon(p) => Some(SetOrientation::properties(p)),
             Primitive::Quad(p) => Some(SetOrientation::properties(p)),
             Primitive::Rect(p) => Some(SetOrientation::properties(p)),
+            Primitive::Ring(p) => Some(SetOrientation::properties(p)),
+            Primitive::Sphere(p) => Some(SetOrientation::properties(p)),
+            Primitive::Spline(p) => Some(SetOrientation::properties(p)),
+            Primitive::Star(p) => Some(SetOrientation::properties(p)),
+            Primitive::Svg(p) => Some(SetOrientation::properties(p)),
             Primitive::Text(p) => Some(SetOrientation::properties(p)),
+            Primitive::Torus(p) => Some(SetOrientation::properties(p)),
             Primitive::Tri(p) => Some(SetOrientation::properties(p)),
             Primitive::MeshVertexless(_) | Primitive::PathInit(_) => None,
         }
@@ -132,12 +245,26 @@ impl Primitive {
 
     pub(crate) fn dimensions_mut(&mut self) -> Option<&mut dimension::Properties> {
         match self {
+            Primitive::Arc(p) => Some(SetDimensions::properties(p)),
+            Primitive::Cone(p) => Some(SetDimensions::properties(p)),
+            Primitive::Cuboid(p) => Some(SetDimensions::properties(p)),
+            Primitive::Cylinder(p) => Some(SetDimensions::properties(p)),
             Primitive::Ellipse(p) => Some(SetDimensions::properties(p)),
+            Primitive::Ngon(p) => Some(SetDimensions::properties(p)),
+            Primitive::NineSlice(p) => Some(SetDimensions::properties(p)),
+            Primitive::Pie(p) => Some(SetDimensions::properties(p)),
+            Primitive::Plane(p) => Some(SetDimensions::properties(p)),
             Primitive::Quad(p) => Some(SetDimensions::properties(p)),
             Primitive::Rect(p) => Some(SetDimensions::properties(p)),
+            Primitive::Ring(p) => Some(SetDimensions::properties(p)),
+            Primitive::Sphere(p) => Some(SetDimensions::properties(p)),
+            Primitive::Star(p) => Some(SetDimensions::properties(p)),
+            Primitive::Svg(p) => Some(SetDimensions::properties(p)),
             Primitive::Text(p) => Some(SetDimensions::properties(p)),
             Primitive::Tri(p) => Some(SetDimensions::properties(p)),
             Primitive::Arrow(_)
+            | Primitive::Bezier(_)
+            | Primitive::Curve(_)
             | Primitive::Line(_)
             | Primitive::Mesh(_)
             | Primitive::MeshVertexless(_)
@@ -145,28 +272,126 @@ impl Primitive {
             | Primitive::PathFill(_)
             | Primitive::PathStroke(_)
             | Primitive::Path(_)
+            | Primitive::Picture(_)
+            | Primitive::Points(_)
             | Primitive::PolygonInit(_)
-            | Primitive::Polygon(_) => None,
+            | Primitive::Polygon(_)
+            | Primitive::Spline(_)
+            | Primitive::Torus(_) => None,
         }
     }
 
     pub(crate) fn stroke_options_mut(&mut self) -> Option<&mut StrokeOptions> {
         match self {
+            Primitive::Arc(p) => Some(SetStroke::stroke_options_mut(p)),
             Primitive::Arrow(p) => Some(SetStroke::stroke_options_mut(p)),
+            Primitive::Bezier(p) => Some(SetStroke::stroke_options_mut(p)),
+            Primitive::Curve(p) => Some(SetStroke::stroke_options_mut(p)),
             Primitive::Ellipse(p) => Some(SetStroke::stroke_options_mut(p)),
             Primitive::Line(p) => Some(SetStroke::stroke_options_mut(p)),
+            Primitive::Ngon(p) => Some(SetStroke::stroke_options_mut(p)),
             Primitive::PathStroke(p) => Some(SetStroke::stroke_options_mut(p)),
+            Primitive::Pie(p) => Some(SetStroke::stroke_options_mut(p)),
             Primitive::PolygonInit(p) => Some(SetStroke::stroke_options_mut(p)),
             Primitive::Quad(p) => Some(SetStroke::stroke_options_mut(p)),
             Primitive::Rect(p) => Some(SetStroke::stroke_options_mut(p)),
+            Primitive::Ring(p) => Some(SetStroke::stroke_options_mut(p)),
+            Primitive::Spline(p) => Some(SetStroke::stroke_options_mut(p)),
+            Primitive::Star(p) => Some(SetStroke::stroke_options_mut(p)),
+            Primitive::Text(p) => Some(SetStroke::stroke_options_mut(p)),
             Primitive::Tri(p) => Some(SetStroke::stroke_options_mut(p)),
-            Primitive::Mesh(_)
+            Primitive::Cone(_)
+            | Primitive::Cuboid(_)
+            | Primitive::Cylinder(_)
+            | Primitive::Mesh(_)
             | Primitive::MeshVertexless(_)
+            | Primitive::NineSlice(_)
             | Primitive::PathInit(_)
             | Primitive::PathFill(_)
             | Primitive::Path(_)
+            | Primitive::Picture(_)
+            | Primitive::Plane(_)
+            | Primitive::Points(_)
             | Primitive::Polygon(_)
-            | Primitive::Text(_) => None,
+            | Primitive::Sphere(_)
+            | Primitive::Svg(_)
+            | Primitive::Torus(_) => None,
+        }
+    }
+
+    pub(crate) fn dashes_mut(&mut self) -> Option<&mut Dashes> {
+        match self {
+            Primitive::Arc(p) => Some(SetDashes::dashes_mut(p)),
+            Primitive::Arrow(p) => Some(SetDashes::dashes_mut(p)),
+            Primitive::Bezier(p) => Some(SetDashes::dashes_mut(p)),
+            Primitive::Curve(p) => Some(SetDashes::dashes_mut(p)),
+            Primitive::Ellipse(p) => Some(SetDashes::dashes_mut(p)),
+            Primitive::Line(p) => Some(SetDashes::dashes_mut(p)),
+            Primitive::Ngon(p) => Some(SetDashes::dashes_mut(p)),
+            Primitive::PathStroke(p) => Some(SetDashes::dashes_mut(p)),
+            Primitive::Pie(p) => Some(SetDashes::dashes_mut(p)),
+            Primitive::PolygonInit(p) => Some(SetDashes::dashes_mut(p)),
+            Primitive::Quad(p) => Some(SetDashes::dashes_mut(p)),
+            Primitive::Rect(p) => Some(SetDashes::dashes_mut(p)),
+            Primitive::Ring(p) => Some(SetDashes::dashes_mut(p)),
+            Primitive::Spline(p) => Some(SetDashes::dashes_mut(p)),
+            Primitive::Star(p) => Some(SetDashes::dashes_mut(p)),
+            Primitive::Tri(p) => Some(SetDashes::dashes_mut(p)),
+            Primitive::Cone(_)
+            | Primitive::Cuboid(_)
+            | Primitive::Cylinder(_)
+            | Primitive::Mesh(_)
+            | Primitive::MeshVertexless(_)
+            | Primitive::NineSlice(_)
+            | Primitive::PathInit(_)
+            | Primitive::PathFill(_)
+            | Primitive::Path(_)
+            | Primitive::Picture(_)
+            | Primitive::Plane(_)
+            | Primitive::Points(_)
+            | Primitive::Polygon(_)
+            | Primitive::Sphere(_)
+            | Primitive::Svg(_)
+            | Primitive::Text(_)
+            | Primitive::Torus(_) => None,
+        }
+    }
+
+    pub(crate) fn gradient_mut(&mut self) -> Option<&mut Gradient> {
+        match self {
+            Primitive::Bezier(p) => Some(SetGradient::gradient_mut(p)),
+            Primitive::Curve(p) => Some(SetGradient::gradient_mut(p)),
+            Primitive::Ellipse(p) => Some(SetGradient::gradient_mut(p)),
+            Primitive::Ngon(p) => Some(SetGradient::gradient_mut(p)),
+            Primitive::PathFill(p) => Some(SetGradient::gradient_mut(p)),
+            Primitive::PathStroke(p) => Some(SetGradient::gradient_mut(p)),
+            Primitive::Path(p) => Some(SetGradient::gradient_mut(p)),
+            Primitive::Pie(p) => Some(SetGradient::gradient_mut(p)),
+            Primitive::PolygonInit(p) => Some(SetGradient::gradient_mut(p)),
+            Primitive::Polygon(p) => Some(SetGradient::gradient_mut(p)),
+            Primitive::Quad(p) => Some(SetGradient::gradient_mut(p)),
+            Primitive::Rect(p) => Some(SetGradient::gradient_mut(p)),
+            Primitive::Ring(p) => Some(SetGradient::gradient_mut(p)),
+            Primitive::Spline(p) => Some(SetGradient::gradient_mut(p)),
+            Primitive::Star(p) => Some(SetGradient::gradient_mut(p)),
+            Primitive::Tri(p) => Some(SetGradient::gradient_mut(p)),
+            Primitive::Arc(_)
+            | Primitive::Arrow(_)
+            | Primitive::Cone(_)
+            | Primitive::Cuboid(_)
+            | Primitive::Cylinder(_)
+            | Primitive::Line(_)
+            | Primitive::Mesh(_)
+            | Primitive::MeshVertexless(_)
+            | Primitive::NineSlice(_)
+            | Primitive::PathInit(_)
+            | Primitive::Picture(_)
+            | Primitive::Plane(_)
+            | Primitive::Points(_)
+            | Primitive::Sphere(_)
+            | Primitive::Svg(_)
+            | Primitive::Text(_)
+            | Primitive::Torus(_) => None,
         }
     }
 
@@ -179,28 +404,93 @@ impl Primitive {
 
     pub(crate) fn polygon_options_mut(&mut self) -> Option<&mut PolygonOptions> {
         match self {
+            Primitive::Bezier(p) => Some(SetPolygon::polygon_options_mut(p)),
+            Primitive::Curve(p) => Some(SetPolygon::polygon_options_mut(p)),
             Primitive::Ellipse(p) => Some(SetPolygon::polygon_options_mut(p)),
+            Primitive::Ngon(p) => Some(SetPolygon::polygon_options_mut(p)),
+            Primitive::Pie(p) => Some(SetPolygon::polygon_options_mut(p)),
             Primitive::PolygonInit(p) => Some(SetPolygon::polygon_options_mut(p)),
             Primitive::Quad(p) => Some(SetPolygon::polygon_options_mut(p)),
             Primitive::Rect(p) => Some(SetPolygon::polygon_options_mut(p)),
+            Primitive::Ring(p) => Some(SetPolygon::polygon_options_mut(p)),
+            Primitive::Spline(p) => Some(SetPolygon::polygon_options_mut(p)),
+            Primitive::Star(p) => Some(SetPolygon::polygon_options_mut(p)),
             Primitive::Tri(p) => Some(SetPolygon::polygon_options_mut(p)),
-            Primitive::Arrow(_)
+            Primitive::Arc(_)
+            | Primitive::Arrow(_)
+            | Primitive::Cone(_)
+            | Primitive::Cuboid(_)
+            | Primitive::Cylinder(_)
             | Primitive::Line(_)
             | Primitive::Mesh(_)
             | Primitive::MeshVertexless(_)
+            | Primitive::NineSlice(_)
             | Primitive::PathInit(_)
             | Primitive::PathFill(_)
             | Primitive::PathStroke(_)
             | Primitive::Path(_)
+            | Primitive::Picture(_)
+            | Primitive::Plane(_)
+            | Primitive::Points(_)
             | Primitive::Polygon(_)
-            | Primitive::Text(_) => None,
+            | Primitive::Sphere(_)
+            | Primitive::Svg(_)
+            | Primitive::Text(_)
+            | Primitive::Torus(_) => None,
         }
     }
 
     pub(crate) fn tex_coords_mut(&mut self) -> Option<&mut Option<nannou_core::geom::Rect>> {
         match self {
+            Primitive::Ngon(p) => Some(SetTexCoords::tex_coords_mut(p)),
+            Primitive::NineSlice(p) => Some(SetTexCoords::tex_coords_mut(p)),
+            Primitive::Quad(p) => Some(SetTexCoords::tex_coords_mut(p)),
             Primitive::Rect(p) => Some(SetTexCoords::tex_coords_mut(p)),
+            Primitive::Star(p) => Some(SetTexCoords::tex_coords_mut(p)),
             _ => None,
         }
     }
 }
+
+#[cfg(feature = "serde")]
+impl Primitive {
+    // Offset the ranges into the draw's intermediary buffers, e.g. after the buffers of a
+    // recorded frame have been appended to those of another draw.
+    pub(crate) fn offset_buffers(&mut self, offsets: &crate::record::BufferOffsets) {
+        match self {
+            Primitive::Mesh(p) => p.offset_buffers(offsets),
+            Primitive::Path(p) => p.offset_buffers(offsets),
+            Primitive::Points(p) => p.offset_buffers(offsets),
+            Primitive::Polygon(p) => p.offset_buffers(offsets),
+            Primitive::Text(p) => p.offset_buffers(offsets),
+            Primitive::Arc(_)
+            | Primitive::Arrow(_)
+            | Primitive::Bezier(_)
+            | Primitive::Cone(_)
+            | Primitive::Cuboid(_)
+            | Primitive::Curve(_)
+            | Primitive::Cylinder(_)
+            | Primitive::Ellipse(_)
+            | Primitive::Line(_)
+            | Primitive::MeshVertexless(_)
+            | Primitive::Ngon(_)
+            | Primitive::NineSlice(_)
+            | Primitive::PathInit(_)
+            | Primitive::PathFill(_)
+            | Primitive::PathStroke(_)
+            | Primitive::Picture(_)
+            | Primitive::Pie(_)
+            | Primitive::Plane(_)
+            | Primitive::PolygonInit(_)
+            | Primitive::Quad(_)
+            | Primitive::Rect(_)
+            | Primitive::Ring(_)
+            | Primitive::Sphere(_)
+            | Primitive::Spline(_)
+            | Primitive::Star(_)
+            | Primitive::Svg(_)
+            | Primitive::Torus(_)
+            | Primitive::Tri(_) => (),
+        }
+    }
+}
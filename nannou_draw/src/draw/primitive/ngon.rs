@@ -0,0 +1,207 @@
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+use bevy::prelude::*;
+use lyon::tessellation::StrokeOptions;
+
+use nannou_core::geom;
+
+use crate::draw::primitive::Primitive;
+use crate::draw::primitive::arc::ellipse_wh;
+use crate::draw::primitive::polygon::{self, PolygonInit, PolygonOptions, SetPolygon};
+use crate::draw::properties::dash::Dashes;
+use crate::draw::properties::gradient::Gradient;
+use crate::draw::properties::spatial::{dimension, orientation, position};
+use crate::draw::properties::tex_coords::{self, SetTexCoords};
+use crate::draw::properties::{
+    SetColor, SetDashes, SetDimensions, SetGradient, SetOrientation, SetPosition, SetStroke,
+};
+use crate::draw::{self, Drawing};
+
+/// Properties related to drawing a regular polygon (an **Ngon**).
+///
+/// The vertices lie on the ellipse described by the dimensions, with the first pointing straight
+/// up.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ngon {
+    dimensions: dimension::Properties,
+    #[cfg_attr(feature = "serde", serde(with = "crate::record::option_geom_rect"))]
+    tex_coords: Option<geom::Rect>,
+    sides: usize,
+    polygon: PolygonInit,
+}
+
+/// The drawing context for an ngon.
+pub type DrawingNgon<'a> = Drawing<'a, Ngon>;
+
+impl Ngon {
+    /// Specify the width and height of the **Ngon** via the radius of its circumcircle.
+    pub fn radius(self, radius: f32) -> Self {
+        let side = radius * 2.0;
+        self.w_h(side, side)
+    }
+
+    /// The number of sides, clamped to a minimum of `3`. Defaults to `6`.
+    pub fn sides(mut self, sides: usize) -> Self {
+        self.sides = sides;
+        self
+    }
+}
+
+impl<'a> DrawingNgon<'a> {
+    /// Specify the width and height of the **Ngon** via the radius of its circumcircle.
+    pub fn radius(self, radius: f32) -> Self {
+        let side = radius * 2.0;
+        self.w_h(side, side)
+    }
+
+    /// The number of sides, clamped to a minimum of `3`. Defaults to `6`.
+    pub fn sides(self, sides: usize) -> Self {
+        update_ngon(&self.draw, self.index, |ngon| ngon.sides = sides);
+        self
+    }
+
+    /// The area of the texture mapped onto the bounds of the **Ngon**.
+    pub fn area(self, area: geom::Rect) -> Self {
+        tex_coords::set_tex_coords_area(&self.draw, self.index, area);
+        self
+    }
+}
+
+// Update the inner `Ngon` of the primitive being drawn at `index`.
+fn update_ngon(draw: &crate::draw::Draw, index: usize, f: impl FnOnce(&mut Ngon)) {
+    crate::draw::drawing::with_primitive(draw, index, |prim| match prim {
+        Primitive::Ngon(ngon) => f(ngon),
+        _ => bevy::log::warn_once!("expected an `Ngon` primitive"),
+    })
+}
+
+/// The points of a regular polygon inscribed within an ellipse of the given size, starting from
+/// the top and winding counter-clockwise.
+pub(crate) fn vertices(wh: Vec2, sides: usize) -> impl Clone + Iterator<Item = Vec2> {
+    let radii = wh * 0.5;
+    (0..sides).map(move |i| {
+        let angle = FRAC_PI_2 + TAU * i as f32 / sides as f32;
+        Vec2::from_angle(angle) * radii
+    })
+}
+
+/// Render the given points of a shape with the given size, mapping them to texture coordinates
+/// by their position within its bounds.
+pub(crate) fn render_points<I>(
+    points: I,
+    wh: Vec2,
+    tex_coords: Option<geom::Rect>,
+    polygon: PolygonInit,
+    ctxt: draw::render::RenderContext,
+    theme_primitive: &draw::theme::Primitive,
+    mesh: &mut Mesh,
+) where
+    I: Clone + Iterator<Item = Vec2>,
+{
+    let area = tex_coords.unwrap_or(geom::Rect::from_corners(Vec2::ZERO, Vec2::ONE));
+    let (bottom_left, top_right) = (area.bottom_left(), area.top_right());
+    let points = points.map(move |p| {
+        let uv = p / wh + 0.5;
+        (p, bottom_left + uv * (top_right - bottom_left))
+    });
+    polygon::render_points_themed(polygon.opts, true, points, ctxt, theme_primitive, mesh);
+}
+
+impl draw::render::RenderPrimitive for Ngon {
+    fn render_primitive(self, ctxt: draw::render::RenderContext, mesh: &mut Mesh) {
+        let Ngon {
+            dimensions,
+            tex_coords,
+            sides,
+            polygon,
+        } = self;
+        let wh = ellipse_wh(&dimensions, "ngon");
+        if wh.cmple(Vec2::ZERO).any() {
+            return;
+        }
+        let points = vertices(wh, sides.max(3));
+        render_points(
+            points,
+            wh,
+            tex_coords,
+            polygon,
+            ctxt,
+            &draw::theme::Primitive::Ngon,
+            mesh,
+        );
+    }
+}
+
+impl Default for Ngon {
+    fn default() -> Self {
+        Ngon {
+            dimensions: Default::default(),
+            tex_coords: None,
+            sides: 6,
+            polygon: Default::default(),
+        }
+    }
+}
+
+impl SetOrientation for Ngon {
+    fn properties(&mut self) -> &mut orientation::Properties {
+        SetOrientation::properties(&mut self.polygon)
+    }
+}
+
+impl SetPosition for Ngon {
+    fn properties(&mut self) -> &mut position::Properties {
+        SetPosition::properties(&mut self.polygon)
+    }
+}
+
+impl SetDimensions for Ngon {
+    fn properties(&mut self) -> &mut dimension::Properties {
+        SetDimensions::properties(&mut self.dimensions)
+    }
+}
+
+impl SetColor for Ngon {
+    fn color_mut(&mut self) -> &mut Option<Color> {
+        SetColor::color_mut(&mut self.polygon)
+    }
+}
+
+impl SetStroke for Ngon {
+    fn stroke_options_mut(&mut self) -> &mut StrokeOptions {
+        SetStroke::stroke_options_mut(&mut self.polygon)
+    }
+}
+
+impl SetDashes for Ngon {
+    fn dashes_mut(&mut self) -> &mut Dashes {
+        SetDashes::dashes_mut(&mut self.polygon)
+    }
+}
+
+impl SetGradient for Ngon {
+    fn gradient_mut(&mut self) -> &mut Gradient {
+        SetGradient::gradient_mut(&mut self.polygon)
+    }
+}
+
+impl SetPolygon for Ngon {
+    fn polygon_options_mut(&mut self) -> &mut PolygonOptions {
+        SetPolygon::polygon_options_mut(&mut self.polygon)
+    }
+}
+
+impl SetTexCoords for Ngon {
+    fn tex_coords_mut(&mut self) -> &mut Option<geom::Rect> {
+        SetTexCoords::tex_coords_mut(&mut self.tex_coords)
+    }
+}
+
+// Primitive conversion.
+
+impl From<Ngon> for Primitive {
+    fn from(prim: Ngon) -> Self {
+        Primitive::Ngon(prim)
+    }
+}
@@ -0,0 +1,232 @@
+use bevy::prelude::*;
+
+use nannou_core::geom;
+
+use crate::draw::mesh::MeshExt;
+use crate::draw::primitive::Primitive;
+use crate::draw::properties::spatial::{self, dimension, orientation, position};
+use crate::draw::properties::tex_coords::{self, SetTexCoords};
+use crate::draw::properties::{SetColor, SetDimensions, SetOrientation, SetPosition};
+use crate::draw::{self, Drawing};
+
+/// Properties related to drawing a **NineSlice**, a textured rectangle that may be resized
+/// without distorting the borders of its texture.
+///
+/// The texture is divided into a three by three grid by the borders. The corners are drawn at
+/// their original size, the edges are stretched along their length and the centre is stretched
+/// to fill the remaining space.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NineSlice {
+    spatial: spatial::Properties,
+    color: Option<Color>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::record::option_geom_rect"))]
+    tex_coords: Option<geom::Rect>,
+    /// The left, right, top and bottom borders in pixels.
+    borders: [f32; 4],
+    /// The size of the whole texture in pixels, if known.
+    texture_size: Option<Vec2>,
+}
+
+/// The drawing context for a nine-slice rectangle.
+pub type DrawingNineSlice<'a> = Drawing<'a, NineSlice>;
+
+impl NineSlice {
+    /// The width of the left, right, top and bottom borders in pixels.
+    ///
+    /// Each border is drawn at the same size as the region of the texture it covers. Borders are
+    /// scaled down where the sum of two opposite borders would exceed the size of the rectangle.
+    pub fn borders(mut self, left: f32, right: f32, top: f32, bottom: f32) -> Self {
+        self.borders = [left, right, top, bottom];
+        self
+    }
+
+    /// The size of the whole texture in pixels.
+    ///
+    /// This is used to locate the borders within the texture. Where unknown, the texture's area is
+    /// assumed to be the same size as the rectangle.
+    pub fn texture_size(mut self, w: f32, h: f32) -> Self {
+        self.texture_size = Some(Vec2::new(w, h));
+        self
+    }
+}
+
+impl<'a> DrawingNineSlice<'a> {
+    /// The width of the left, right, top and bottom borders in pixels.
+    ///
+    /// Each border is drawn at the same size as the region of the texture it covers. Borders are
+    /// scaled down where the sum of two opposite borders would exceed the size of the rectangle.
+    pub fn borders(self, left: f32, right: f32, top: f32, bottom: f32) -> Self {
+        update_nine_slice(&self.draw, self.index, |n| {
+            n.borders = [left, right, top, bottom]
+        });
+        self
+    }
+
+    /// The size of the whole texture in pixels.
+    ///
+    /// This is used to locate the borders within the texture. Where unknown, the texture's area is
+    /// assumed to be the same size as the rectangle.
+    pub fn texture_size(self, w: f32, h: f32) -> Self {
+        update_nine_slice(&self.draw, self.index, |n| {
+            n.texture_size = Some(Vec2::new(w, h))
+        });
+        self
+    }
+
+    /// The area of the texture to slice, e.g. a frame of a sprite sheet.
+    pub fn area(self, area: geom::Rect) -> Self {
+        tex_coords::set_tex_coords_area(&self.draw, self.index, area);
+        self
+    }
+}
+
+// Update the inner `NineSlice` of the primitive being drawn at `index`.
+fn update_nine_slice(draw: &crate::draw::Draw, index: usize, f: impl FnOnce(&mut NineSlice)) {
+    crate::draw::drawing::with_primitive(draw, index, |prim| match prim {
+        Primitive::NineSlice(n) => f(n),
+        _ => bevy::log::warn_once!("expected a `NineSlice` primitive"),
+    })
+}
+
+impl draw::render::RenderPrimitive for NineSlice {
+    fn render_primitive(self, ctxt: draw::render::RenderContext, mesh: &mut Mesh) {
+        let NineSlice {
+            spatial,
+            color,
+            tex_coords,
+            borders,
+            texture_size,
+        } = self;
+        let area = tex_coords.unwrap_or(geom::Rect::from_corners(Vec2::ZERO, Vec2::ONE));
+        let area_wh = Vec2::new(area.w(), area.h());
+
+        // Default to the size of the texture's area in pixels where known.
+        let default_wh = texture_size.map(|size| size * area_wh);
+        let w = spatial
+            .dimensions
+            .x
+            .or(default_wh.map(|wh| wh.x))
+            .unwrap_or(100.0)
+            .abs();
+        let h = spatial
+            .dimensions
+            .y
+            .or(default_wh.map(|wh| wh.y))
+            .unwrap_or(100.0)
+            .abs();
+        if w <= 0.0 || h <= 0.0 {
+            return;
+        }
+
+        // The size of a pixel in normalised texture coordinates.
+        let texel = match texture_size {
+            Some(size) => 1.0 / size.max(Vec2::ONE),
+            None => area_wh / Vec2::new(w, h),
+        };
+
+        // Fit opposite borders within the rectangle and the texture's area.
+        let [left, right, top, bottom] = borders.map(|b| b.max(0.0));
+        let fit = |a: f32, b: f32, len: f32| {
+            let scale = if a + b > len { len / (a + b) } else { 1.0 };
+            (a * scale, b * scale)
+        };
+        let (left, right) = fit(left, right, w);
+        let (top, bottom) = fit(top, bottom, h);
+        let (tex_left, tex_right) = fit(left * texel.x, right * texel.x, area_wh.x);
+        let (tex_top, tex_bottom) = fit(top * texel.y, bottom * texel.y, area_wh.y);
+
+        // The grid lines from left to right and top to bottom. Texture coordinates are y-down.
+        let (hw, hh) = (w * 0.5, h * 0.5);
+        let xs = [-hw, -hw + left, hw - right, hw];
+        let ys = [hh, hh - top, -hh + bottom, -hh];
+        let (min, max) = (area.bottom_left(), area.top_right());
+        let us = [min.x, min.x + tex_left, max.x - tex_right, max.x];
+        let vs = [min.y, min.y + tex_top, max.y - tex_bottom, max.y];
+
+        let transform =
+            *ctxt.transform * spatial.position.transform() * spatial.orientation.transform();
+        let color = color
+            .unwrap_or_else(|| ctxt.theme.fill(&draw::theme::Primitive::NineSlice))
+            .to_linear()
+            .to_f32_array();
+        let start = mesh.count_vertices() as u32;
+        for (y, v) in ys.into_iter().zip(vs) {
+            for (x, u) in xs.into_iter().zip(us) {
+                let point = transform.transform_point3(Vec3::new(x, y, 0.0));
+                mesh.points_mut().push(point.to_array());
+                mesh.colors_mut().push(color);
+                mesh.tex_coords_mut().push([u, v]);
+                mesh.normals_mut().push([0.0, 0.0, 1.0]);
+            }
+        }
+        // Two counter-clockwise triangles for each of the nine quads.
+        for row in 0..3 {
+            for col in 0..3 {
+                let top_left = start + row * 4 + col;
+                let (top_right, bottom_left) = (top_left + 1, top_left + 4);
+                let bottom_right = bottom_left + 1;
+                for index in [
+                    top_left,
+                    bottom_left,
+                    bottom_right,
+                    top_left,
+                    bottom_right,
+                    top_right,
+                ] {
+                    mesh.push_index(index);
+                }
+            }
+        }
+    }
+}
+
+impl Default for NineSlice {
+    fn default() -> Self {
+        NineSlice {
+            spatial: Default::default(),
+            color: None,
+            tex_coords: None,
+            borders: [0.0; 4],
+            texture_size: None,
+        }
+    }
+}
+
+impl SetOrientation for NineSlice {
+    fn properties(&mut self) -> &mut orientation::Properties {
+        SetOrientation::properties(&mut self.spatial)
+    }
+}
+
+impl SetPosition for NineSlice {
+    fn properties(&mut self) -> &mut position::Properties {
+        SetPosition::properties(&mut self.spatial)
+    }
+}
+
+impl SetDimensions for NineSlice {
+    fn properties(&mut self) -> &mut dimension::Properties {
+        SetDimensions::properties(&mut self.spatial)
+    }
+}
+
+impl SetColor for NineSlice {
+    fn color_mut(&mut self) -> &mut Option<Color> {
+        SetColor::color_mut(&mut self.color)
+    }
+}
+
+impl SetTexCoords for NineSlice {
+    fn tex_coords_mut(&mut self) -> &mut Option<geom::Rect> {
+        SetTexCoords::tex_coords_mut(&mut self.tex_coords)
+    }
+}
+
+// Primitive conversion.
+
+impl From<NineSlice> for Primitive {
+    fn from(prim: NineSlice) -> Self {
+        Primitive::NineSlice(prim)
+    }
+}
@@ -3,8 +3,13 @@ use lyon::path::PathEvent;
 use lyon::tessellation::{FillOptions, FillTessellator, StrokeOptions, StrokeTessellator};
 
 use crate::draw::primitive::Primitive;
+use crate::draw::properties::dash::Dashes;
+use crate::draw::properties::gradient::Gradient;
 use crate::draw::properties::spatial::{orientation, position};
-use crate::draw::properties::{SetColor, SetFill, SetOrientation, SetPosition, SetStroke};
+use crate::draw::properties::{
+    SetColor, SetDashes, SetFill, SetGradient, SetOrientation, SetPosition, SetStroke,
+};
+use crate::draw::render::VectorPath;
 use crate::draw::{self, Drawing, DrawingContext, drawing};
 
 /// A set of path tessellation options (FillOptions or StrokeOptions).
@@ -16,6 +21,7 @@ pub trait TessellationOptions {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) enum PathEventSource {
     /// Fetch events from `path_events_buffer`.
     Buffered(std::ops::Range<usize>),
@@ -24,6 +30,26 @@ pub(crate) enum PathEventSource {
         range: std::ops::Range<usize>,
         close: bool,
     },
+    /// Generate a variable-width stroke from the `path_points_weighted_buffer`.
+    ///
+    /// Unless `colored`, the colors within the buffer are ignored in favour of the path's color.
+    Weighted {
+        range: std::ops::Range<usize>,
+        close: bool,
+        colored: bool,
+    },
+}
+
+#[cfg(feature = "serde")]
+impl PathEventSource {
+    pub(crate) fn offset_buffers(&mut self, offsets: &crate::record::BufferOffsets) {
+        let (range, offset) = match self {
+            PathEventSource::Buffered(range) => (range, offsets.path_events),
+            PathEventSource::Vertex { range, .. } => (range, offsets.path_points),
+            PathEventSource::Weighted { range, .. } => (range, offsets.path_points_weighted),
+        };
+        *range = range.start + offset..range.end + offset;
+    }
 }
 
 pub(crate) enum PathEventSourceIter<'a> {
@@ -32,20 +58,28 @@ pub(crate) enum PathEventSourceIter<'a> {
         points: &'a mut dyn Iterator<Item = (Vec2, Color, Vec2)>,
         close: bool,
     },
+    Weighted {
+        points: &'a mut dyn Iterator<Item = (Vec2, Color, f32)>,
+        close: bool,
+    },
 }
 
 /// The beginning of the path building process, prior to choosing the tessellation mode (fill or
 /// stroke).
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PathInit;
 
 /// A path drawing context ready to specify tessellation options.
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PathOptions<T> {
     pub(crate) opts: T,
     pub(crate) color: Option<Color>,
     pub(crate) position: position::Properties,
     pub(crate) orientation: orientation::Properties,
+    pub(crate) dashes: Dashes,
+    pub(crate) gradient: Gradient,
 }
 
 /// Mutable access to stroke and fill tessellators.
@@ -62,12 +96,15 @@ pub type PathStroke = PathOptions<StrokeOptions>;
 
 /// Properties related to drawing a **Path**.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Path {
     color: Option<Color>,
     position: position::Properties,
     orientation: orientation::Properties,
     path_event_src: PathEventSource,
     options: Options,
+    dashes: Dashes,
+    gradient: Gradient,
 }
 
 /// The initial drawing context for a path.
@@ -87,6 +124,7 @@ pub type DrawingPath<'a> = Drawing<'a, Path>;
 
 /// Dynamically distinguish between fill and stroke tessellation options.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Options {
     Fill(FillOptions),
     Stroke(StrokeOptions),
@@ -116,11 +154,15 @@ impl<T> PathOptions<T> {
         let orientation = Default::default();
         let position = Default::default();
         let color = Default::default();
+        let dashes = Default::default();
+        let gradient = Default::default();
         PathOptions {
             opts,
             orientation,
             position,
             color,
+            dashes,
+            gradient,
         }
     }
 }
@@ -153,6 +195,94 @@ impl PathStroke {
     pub fn tolerance(self, tolerance: f32) -> Self {
         self.stroke_tolerance(tolerance)
     }
+
+    /// Submit a polyline whose width varies along its length, as points each paired with the
+    /// stroke weight at that point.
+    ///
+    /// The weights replace the stroke weight of the path, and are interpolated along each
+    /// segment. The polyline is left open and is drawn with the color of the path.
+    pub fn weighted_points<I, P>(self, ctxt: DrawingContext, points: I) -> Path
+    where
+        I: IntoIterator<Item = (P, f32)>,
+        P: Into<Vec2>,
+    {
+        let points = points
+            .into_iter()
+            .map(|(p, w)| (p.into(), Color::default(), w));
+        self.weighted_points_inner(ctxt, false, false, points)
+    }
+
+    /// Like [`weighted_points`](Self::weighted_points), with a final segment from the last point
+    /// back to the first.
+    pub fn weighted_points_closed<I, P>(self, ctxt: DrawingContext, points: I) -> Path
+    where
+        I: IntoIterator<Item = (P, f32)>,
+        P: Into<Vec2>,
+    {
+        let points = points
+            .into_iter()
+            .map(|(p, w)| (p.into(), Color::default(), w));
+        self.weighted_points_inner(ctxt, true, false, points)
+    }
+
+    /// Like [`weighted_points`](Self::weighted_points), with each point also given a color.
+    ///
+    /// The colors replace the color of the path, and are blended along each segment.
+    pub fn weighted_points_colored<I, P, C>(self, ctxt: DrawingContext, points: I) -> Path
+    where
+        I: IntoIterator<Item = (P, f32, C)>,
+        P: Into<Vec2>,
+        C: Into<Color>,
+    {
+        let points = points.into_iter().map(|(p, w, c)| (p.into(), c.into(), w));
+        self.weighted_points_inner(ctxt, false, true, points)
+    }
+
+    /// Like [`weighted_points_colored`](Self::weighted_points_colored), with a final segment
+    /// from the last point back to the first.
+    pub fn weighted_points_colored_closed<I, P, C>(self, ctxt: DrawingContext, points: I) -> Path
+    where
+        I: IntoIterator<Item = (P, f32, C)>,
+        P: Into<Vec2>,
+        C: Into<Color>,
+    {
+        let points = points.into_iter().map(|(p, w, c)| (p.into(), c.into(), w));
+        self.weighted_points_inner(ctxt, true, true, points)
+    }
+
+    // Buffer the weighted points, producing a path that strokes them with a variable width.
+    fn weighted_points_inner<I>(
+        self,
+        ctxt: DrawingContext,
+        close: bool,
+        colored: bool,
+        points: I,
+    ) -> Path
+    where
+        I: IntoIterator<Item = (Vec2, Color, f32)>,
+    {
+        let DrawingContext {
+            path_points_weighted_buffer,
+            ..
+        } = ctxt;
+        let start = path_points_weighted_buffer.len();
+        path_points_weighted_buffer.extend(points);
+        let end = path_points_weighted_buffer.len();
+        let path_event_src = PathEventSource::Weighted {
+            range: start..end,
+            close,
+            colored,
+        };
+        Path::new(
+            self.position,
+            self.orientation,
+            self.color,
+            path_event_src,
+            self.opts.into_options(),
+            self.dashes,
+            self.gradient,
+        )
+    }
 }
 
 impl<T> PathOptions<T>
@@ -176,6 +306,8 @@ where
             self.color,
             PathEventSource::Buffered(start..end),
             self.opts.into_options(),
+            self.dashes,
+            self.gradient,
         )
     }
 
@@ -265,6 +397,8 @@ where
             self.color,
             path_event_src,
             self.opts.into_options(),
+            self.dashes,
+            self.gradient,
         )
     }
 }
@@ -272,23 +406,67 @@ where
 pub(crate) fn render_path_events<I>(
     events: I,
     color: Option<Color>,
+    gradient: Option<&Gradient>,
     transform: Mat4,
     options: Options,
+    dashes: Option<&Dashes>,
     theme: &draw::Theme,
     theme_prim: &draw::theme::Primitive,
     fill_tessellator: &mut lyon::tessellation::FillTessellator,
     stroke_tessellator: &mut lyon::tessellation::StrokeTessellator,
+    vector_paths: Option<&mut Vec<VectorPath>>,
     mesh: &mut Mesh,
 ) where
     I: IntoIterator<Item = lyon::path::PathEvent>,
 {
-    let res = match options {
-        Options::Fill(options) => {
+    if let Some((dashes, tolerance)) = stroke_dashes(&options, dashes) {
+        let events = dashes.split_events(events, tolerance);
+        return render_path_events(
+            events,
+            color,
+            gradient,
+            transform,
+            options,
+            None,
+            theme,
+            theme_prim,
+            fill_tessellator,
+            stroke_tessellator,
+            vector_paths,
+            mesh,
+        );
+    }
+
+    let gradient = gradient.filter(|g| g.is_active());
+    if let Some(vector_paths) = vector_paths {
+        let color = color.unwrap_or_else(|| match options {
+            Options::Fill(_) => theme.fill(theme_prim),
+            Options::Stroke(_) => theme.stroke(theme_prim),
+        });
+        let path = VectorPath::new(events, color, transform, options);
+        vector_paths.push(path.with_gradient(gradient, transform));
+        return;
+    }
+
+    let res = match (options, gradient) {
+        (Options::Fill(options), Some(gradient)) => {
+            let mut mesh_builder = draw::mesh::MeshBuilder::gradient(mesh, transform, gradient);
+            fill_tessellator
+                .tessellate(events, &options, &mut mesh_builder)
+                .map(|()| mesh_builder.subdivide(gradient.subdivisions))
+        }
+        (Options::Stroke(options), Some(gradient)) => {
+            let mut mesh_builder = draw::mesh::MeshBuilder::gradient(mesh, transform, gradient);
+            stroke_tessellator
+                .tessellate(events, &options, &mut mesh_builder)
+                .map(|()| mesh_builder.subdivide(gradient.subdivisions))
+        }
+        (Options::Fill(options), None) => {
             let color = color.unwrap_or_else(|| theme.fill(theme_prim));
             let mut mesh_builder = draw::mesh::MeshBuilder::single_color(mesh, transform, color);
             fill_tessellator.tessellate(events, &options, &mut mesh_builder)
         }
-        Options::Stroke(options) => {
+        (Options::Stroke(options), None) => {
             let color = color.unwrap_or_else(|| theme.stroke(theme_prim));
             let mut mesh_builder = draw::mesh::MeshBuilder::single_color(mesh, transform, color);
             stroke_tessellator.tessellate(events, &options, &mut mesh_builder)
@@ -303,24 +481,81 @@ pub(crate) fn render_path_points_themed<I>(
     points_themed: I,
     close: bool,
     color: Option<Color>,
+    gradient: Option<&Gradient>,
     transform: Mat4,
     options: Options,
+    dashes: Option<&Dashes>,
     theme: &draw::Theme,
     theme_prim: &draw::theme::Primitive,
     fill_tessellator: &mut lyon::tessellation::FillTessellator,
     stroke_tessellator: &mut lyon::tessellation::StrokeTessellator,
+    mut vector_paths: Option<&mut Vec<VectorPath>>,
     mesh: &mut Mesh,
 ) where
     I: IntoIterator<Item = (Vec2, Vec2)>,
 {
+    if let Some((dashes, _)) = stroke_dashes(&options, dashes) {
+        let lerp = |a: &Vec2, b: &Vec2, t: f32| a.lerp(*b, t);
+        for dash in dashes.split_points(points_themed, close, lerp) {
+            render_path_points_themed(
+                dash,
+                false,
+                color,
+                gradient,
+                transform,
+                options.clone(),
+                None,
+                theme,
+                theme_prim,
+                fill_tessellator,
+                stroke_tessellator,
+                vector_paths.as_deref_mut(),
+                mesh,
+            );
+        }
+        return;
+    }
+
     let path = match points_themed_to_lyon_path(points_themed, close) {
         None => return,
         Some(p) => p,
     };
 
+    let gradient = gradient.filter(|g| g.is_active());
+    if let Some(vector_paths) = vector_paths {
+        let color = color.unwrap_or_else(|| theme.fill(theme_prim));
+        let path = VectorPath::new(&path, color, transform, options);
+        vector_paths.push(path.with_gradient(gradient, transform));
+        return;
+    }
+
     // Extend the mesh with the built path.
-    let res = match options {
-        Options::Fill(options) => {
+    let res = match (options, gradient) {
+        (Options::Fill(options), Some(gradient)) => {
+            let mut mesh_builder = draw::mesh::MeshBuilder::gradient(mesh, transform, gradient);
+            fill_tessellator
+                .tessellate_with_ids(
+                    path.id_iter(),
+                    &path,
+                    Some(&path),
+                    &options,
+                    &mut mesh_builder,
+                )
+                .map(|()| mesh_builder.subdivide(gradient.subdivisions))
+        }
+        (Options::Stroke(options), Some(gradient)) => {
+            let mut mesh_builder = draw::mesh::MeshBuilder::gradient(mesh, transform, gradient);
+            stroke_tessellator
+                .tessellate_with_ids(
+                    path.id_iter(),
+                    &path,
+                    Some(&path),
+                    &options,
+                    &mut mesh_builder,
+                )
+                .map(|()| mesh_builder.subdivide(gradient.subdivisions))
+        }
+        (Options::Fill(options), None) => {
             let color = color.unwrap_or_else(|| theme.fill(theme_prim));
             let mut mesh_builder = draw::mesh::MeshBuilder::single_color(mesh, transform, color);
             fill_tessellator.tessellate_with_ids(
@@ -331,7 +566,7 @@ pub(crate) fn render_path_points_themed<I>(
                 &mut mesh_builder,
             )
         }
-        Options::Stroke(options) => {
+        (Options::Stroke(options), None) => {
             let color = color.unwrap_or_else(|| theme.fill(theme_prim));
             let mut mesh_builder = draw::mesh::MeshBuilder::single_color(mesh, transform, color);
             stroke_tessellator.tessellate_with_ids(
@@ -353,17 +588,49 @@ pub(crate) fn render_path_vertex<I>(
     close: bool,
     transform: Mat4,
     options: Options,
+    dashes: Option<&Dashes>,
     fill_tessellator: &mut FillTessellator,
     stroke_tessellator: &mut StrokeTessellator,
+    mut vector_paths: Option<&mut Vec<VectorPath>>,
     mesh: &mut Mesh,
 ) where
     I: IntoIterator<Item = (Vec2, Color, Vec2)>,
 {
+    if let Some((dashes, _)) = stroke_dashes(&options, dashes) {
+        let points = points_vertex.into_iter().map(|(p, c, u)| (p, (c, u)));
+        let lerp = |(ca, ua): &(Color, Vec2), (cb, ub): &(Color, Vec2), t: f32| {
+            let color = ca.to_linear().mix(&cb.to_linear(), t);
+            (Color::from(color), ua.lerp(*ub, t))
+        };
+        for dash in dashes.split_points(points, close, lerp) {
+            render_path_vertex(
+                dash.into_iter().map(|(p, (c, u))| (p, c, u)),
+                false,
+                transform,
+                options.clone(),
+                None,
+                fill_tessellator,
+                stroke_tessellator,
+                vector_paths.as_deref_mut(),
+                mesh,
+            );
+        }
+        return;
+    }
+
+    let mut points_vertex = points_vertex.into_iter().peekable();
+    // Vector paths have a single color, so use that of the first point.
+    let first_color = points_vertex.peek().map(|&(_, color, _)| color);
     let path = match points_vertex_to_lyon_path(points_vertex, close) {
         None => return,
         Some(p) => p,
     };
 
+    if let (Some(vector_paths), Some(color)) = (vector_paths, first_color) {
+        vector_paths.push(VectorPath::new(&path, color, transform, options));
+        return;
+    }
+
     // Extend the mesh with the built path.
     let mut mesh_builder = draw::mesh::MeshBuilder::vertex_per_point(mesh, transform);
     let res = match options {
@@ -387,27 +654,99 @@ pub(crate) fn render_path_vertex<I>(
     }
 }
 
+pub(crate) fn render_path_weighted<I>(
+    points_weighted: I,
+    close: bool,
+    transform: Mat4,
+    options: StrokeOptions,
+    dashes: Option<&Dashes>,
+    stroke_tessellator: &mut StrokeTessellator,
+    mut vector_paths: Option<&mut Vec<VectorPath>>,
+    mesh: &mut Mesh,
+) where
+    I: IntoIterator<Item = (Vec2, Color, f32)>,
+{
+    if let Some((dashes, _)) = stroke_dashes(&Options::Stroke(options), dashes) {
+        let points = points_weighted.into_iter().map(|(p, c, w)| (p, (c, w)));
+        let lerp = |(ca, wa): &(Color, f32), (cb, wb): &(Color, f32), t: f32| {
+            let color = ca.to_linear().mix(&cb.to_linear(), t);
+            (Color::from(color), wa + (wb - wa) * t)
+        };
+        for dash in dashes.split_points(points, close, lerp) {
+            render_path_weighted(
+                dash.into_iter().map(|(p, (c, w))| (p, c, w)),
+                false,
+                transform,
+                options,
+                None,
+                stroke_tessellator,
+                vector_paths.as_deref_mut(),
+                mesh,
+            );
+        }
+        return;
+    }
+
+    let points_weighted: Vec<_> = points_weighted.into_iter().collect();
+    let path = match points_weighted_to_lyon_path(points_weighted.iter().copied(), close) {
+        None => return,
+        Some(p) => p,
+    };
+
+    // Vector paths have a single color and width, so use the first color and the mean weight.
+    if let Some(vector_paths) = vector_paths {
+        let (_, color, _) = points_weighted[0];
+        let weight =
+            points_weighted.iter().map(|&(_, _, w)| w).sum::<f32>() / points_weighted.len() as f32;
+        let options = Options::Stroke(options.with_line_width(weight));
+        vector_paths.push(VectorPath::new(&path, color, transform, options));
+        return;
+    }
+
+    // The weight attribute scales the line width, so a width of one makes it absolute.
+    let options = options
+        .with_line_width(1.0)
+        .with_variable_line_width(WEIGHTED_ATTRIBUTE_WEIGHT);
+    let mut mesh_builder = draw::mesh::MeshBuilder::vertex_per_point(mesh, transform);
+    let res = stroke_tessellator.tessellate_with_ids(
+        path.id_iter(),
+        &path,
+        Some(&path),
+        &options,
+        &mut mesh_builder,
+    );
+    if let Err(err) = res {
+        eprintln!("failed to tessellate path: {:?}", err);
+    }
+}
+
 pub(crate) fn render_path_source(
     path_src: PathEventSourceIter,
     color: Option<Color>,
+    gradient: Option<&Gradient>,
     transform: Mat4,
     options: Options,
+    dashes: Option<&Dashes>,
     theme: &draw::Theme,
     theme_prim: &draw::theme::Primitive,
     fill_tessellator: &mut FillTessellator,
     stroke_tessellator: &mut StrokeTessellator,
+    vector_paths: Option<&mut Vec<VectorPath>>,
     mesh: &mut Mesh,
 ) {
     match path_src {
         PathEventSourceIter::Events(events) => render_path_events(
             events,
             color,
+            gradient,
             transform,
             options,
+            dashes,
             theme,
             theme_prim,
             fill_tessellator,
             stroke_tessellator,
+            vector_paths,
             mesh,
         ),
         PathEventSourceIter::Vertex { points, close } => render_path_vertex(
@@ -415,10 +754,39 @@ pub(crate) fn render_path_source(
             close,
             transform,
             options,
+            dashes,
             fill_tessellator,
             stroke_tessellator,
+            vector_paths,
             mesh,
         ),
+        PathEventSourceIter::Weighted { points, close } => match options {
+            Options::Stroke(options) => render_path_weighted(
+                points,
+                close,
+                transform,
+                options,
+                dashes,
+                stroke_tessellator,
+                vector_paths,
+                mesh,
+            ),
+            Options::Fill(_) => {
+                bevy::log::warn_once!("weighted points may only be stroked");
+            }
+        },
+    }
+}
+
+// The dash pattern and flattening tolerance to use for the given options, if any.
+//
+// Dashes only apply to strokes with a non-solid dash pattern.
+fn stroke_dashes<'a>(options: &Options, dashes: Option<&'a Dashes>) -> Option<(&'a Dashes, f32)> {
+    match (options, dashes) {
+        (Options::Stroke(opts), Some(dashes)) if !dashes.is_solid() => {
+            Some((dashes, opts.tolerance))
+        }
+        _ => None,
     }
 }
 
@@ -430,7 +798,8 @@ impl draw::render::RenderPrimitive for Path {
             orientation,
             path_event_src,
             options,
-            ..
+            dashes,
+            gradient,
         } = self;
 
         // Determine the transform to apply to all points.
@@ -439,23 +808,26 @@ impl draw::render::RenderPrimitive for Path {
         let transform = global_transform * local_transform;
 
         // A function for rendering the path.
-        let render =
-            |src: PathEventSourceIter,
-             theme: &draw::Theme,
-             fill_tessellator: &mut lyon::tessellation::FillTessellator,
-             stroke_tessellator: &mut lyon::tessellation::StrokeTessellator| {
-                render_path_source(
-                    src,
-                    color,
-                    transform,
-                    options,
-                    theme,
-                    &draw::theme::Primitive::Path,
-                    fill_tessellator,
-                    stroke_tessellator,
-                    mesh,
-                )
-            };
+        let render = |src: PathEventSourceIter,
+                      theme: &draw::Theme,
+                      fill_tessellator: &mut lyon::tessellation::FillTessellator,
+                      stroke_tessellator: &mut lyon::tessellation::StrokeTessellator,
+                      vector_paths: Option<&mut Vec<VectorPath>>| {
+            render_path_source(
+                src,
+                color,
+                Some(&gradient),
+                transform,
+                options,
+                Some(&dashes),
+                theme,
+                &draw::theme::Primitive::Path,
+                fill_tessellator,
+                stroke_tessellator,
+                vector_paths,
+                mesh,
+            )
+        };
 
         match path_event_src {
             PathEventSource::Buffered(range) => {
@@ -466,6 +838,7 @@ impl draw::render::RenderPrimitive for Path {
                     &ctxt.theme,
                     &mut ctxt.fill_tessellator,
                     &mut ctxt.stroke_tessellator,
+                    ctxt.vector_paths.as_deref_mut(),
                 );
             }
             PathEventSource::Vertex { range, close } => {
@@ -479,6 +852,29 @@ impl draw::render::RenderPrimitive for Path {
                     &ctxt.theme,
                     &mut ctxt.fill_tessellator,
                     &mut ctxt.stroke_tessellator,
+                    ctxt.vector_paths.as_deref_mut(),
+                );
+            }
+            PathEventSource::Weighted {
+                range,
+                close,
+                colored,
+            } => {
+                let theme_prim = draw::theme::Primitive::Path;
+                let path_color = color.unwrap_or_else(|| ctxt.theme.stroke(&theme_prim));
+                let mut points_weighted = ctxt.path_points_weighted_buffer[range]
+                    .iter()
+                    .map(|&(p, c, w)| (p, if colored { c } else { path_color }, w));
+                let src = PathEventSourceIter::Weighted {
+                    points: &mut points_weighted,
+                    close,
+                };
+                render(
+                    src,
+                    &ctxt.theme,
+                    &mut ctxt.fill_tessellator,
+                    &mut ctxt.stroke_tessellator,
+                    ctxt.vector_paths.as_deref_mut(),
                 );
             }
         }
@@ -546,6 +942,40 @@ where
     Some(path_builder.build())
 }
 
+// The index of the weight within the attributes of a weighted path, following the color and
+// texture coordinates expected by the mesh builder.
+const WEIGHTED_ATTRIBUTE_WEIGHT: usize = 6;
+
+/// Create a lyon path for the given iterator of colored, weighted points.
+pub fn points_weighted_to_lyon_path<I>(points: I, close: bool) -> Option<lyon::path::Path>
+where
+    I: IntoIterator<Item = (Vec2, Color, f32)>,
+{
+    // Build a path with a color, uv and weight attribute for each channel.
+    let channels = 7;
+    let mut path_builder = lyon::path::Path::builder_with_attributes(channels);
+
+    // Begin the path.
+    let mut iter = points.into_iter();
+    let (first_point, first_color, first_weight) = iter.next()?;
+    let p = first_point.to_array().into();
+    let [r, g, b, a] = first_color.to_linear().to_f32_array();
+    path_builder.begin(p, &[r, g, b, a, 0.0, 0.0, first_weight]);
+
+    // Add the lines, keeping track of the last
+    for (point, color, weight) in iter {
+        let p = point.to_array().into();
+        let [r, g, b, a] = color.to_linear().to_f32_array();
+        path_builder.line_to(p, &[r, g, b, a, 0.0, 0.0, weight]);
+    }
+
+    // End the path, closing if necessary.
+    path_builder.end(close);
+
+    // Build it!
+    Some(path_builder.build())
+}
+
 impl Path {
     // Initialise a new `Path` with its ranges into the intermediary mesh, ready for drawing.
     fn new(
@@ -554,6 +984,8 @@ impl Path {
         color: Option<Color>,
         path_event_src: PathEventSource,
         options: Options,
+        dashes: Dashes,
+        gradient: Gradient,
     ) -> Self {
         Path {
             color,
@@ -561,8 +993,15 @@ impl Path {
             position,
             path_event_src,
             options,
+            dashes,
+            gradient,
         }
     }
+
+    #[cfg(feature = "serde")]
+    pub(crate) fn offset_buffers(&mut self, offsets: &crate::record::BufferOffsets) {
+        self.path_event_src.offset_buffers(offsets);
+    }
 }
 
 impl<'a> DrawingPathInit<'a> {
@@ -607,6 +1046,64 @@ impl<'a> DrawingPathStroke<'a> {
     pub fn tolerance(self, tolerance: f32) -> Self {
         self.stroke_tolerance(tolerance)
     }
+
+    /// Submit a polyline whose width varies along its length, as points each paired with the
+    /// stroke weight at that point.
+    ///
+    /// The weights replace the stroke weight of the path, and are interpolated along each
+    /// segment. The polyline is left open and is drawn with the color of the path.
+    pub fn weighted_points<I, P>(self, points: I) -> DrawingPath<'a>
+    where
+        I: IntoIterator<Item = (P, f32)>,
+        P: Into<Vec2>,
+    {
+        let mut points = points
+            .into_iter()
+            .map(|(p, w)| (p.into(), Color::default(), w));
+        path_points_weighted(&self.draw, self.index, false, false, &mut points);
+        self.transition()
+    }
+
+    /// Like [`weighted_points`](Self::weighted_points), with a final segment from the last point
+    /// back to the first.
+    pub fn weighted_points_closed<I, P>(self, points: I) -> DrawingPath<'a>
+    where
+        I: IntoIterator<Item = (P, f32)>,
+        P: Into<Vec2>,
+    {
+        let mut points = points
+            .into_iter()
+            .map(|(p, w)| (p.into(), Color::default(), w));
+        path_points_weighted(&self.draw, self.index, true, false, &mut points);
+        self.transition()
+    }
+
+    /// Like [`weighted_points`](Self::weighted_points), with each point also given a color.
+    ///
+    /// The colors replace the color of the path, and are blended along each segment.
+    pub fn weighted_points_colored<I, P, C>(self, points: I) -> DrawingPath<'a>
+    where
+        I: IntoIterator<Item = (P, f32, C)>,
+        P: Into<Vec2>,
+        C: Into<Color>,
+    {
+        let mut points = points.into_iter().map(|(p, w, c)| (p.into(), c.into(), w));
+        path_points_weighted(&self.draw, self.index, false, true, &mut points);
+        self.transition()
+    }
+
+    /// Like [`weighted_points_colored`](Self::weighted_points_colored), with a final segment
+    /// from the last point back to the first.
+    pub fn weighted_points_colored_closed<I, P, C>(self, points: I) -> DrawingPath<'a>
+    where
+        I: IntoIterator<Item = (P, f32, C)>,
+        P: Into<Vec2>,
+        C: Into<Color>,
+    {
+        let mut points = points.into_iter().map(|(p, w, c)| (p.into(), c.into(), w));
+        path_points_weighted(&self.draw, self.index, true, true, &mut points);
+        self.transition()
+    }
 }
 
 impl<'a, T> DrawingPathOptions<'a, T>
@@ -773,6 +1270,25 @@ fn path_points_vertex(
     })
 }
 
+// Submit the path's weighted points, transitioning the primitive from `PathStroke` to `Path`.
+fn path_points_weighted(
+    draw: &draw::Draw,
+    index: usize,
+    close: bool,
+    colored: bool,
+    points: &mut dyn Iterator<Item = (Vec2, Color, f32)>,
+) {
+    drawing::with_primitive_ctxt(draw, index, |prim, ctxt| match prim {
+        Primitive::PathStroke(opts) => {
+            Primitive::Path(opts.weighted_points_inner(ctxt, close, colored, points))
+        }
+        other => {
+            bevy::log::warn_once!("expected a `PathStroke` primitive");
+            other
+        }
+    })
+}
+
 impl SetFill for PathFill {
     fn fill_options_mut(&mut self) -> &mut FillOptions {
         &mut self.opts
@@ -785,6 +1301,18 @@ impl SetStroke for PathStroke {
     }
 }
 
+impl SetDashes for PathStroke {
+    fn dashes_mut(&mut self) -> &mut Dashes {
+        &mut self.dashes
+    }
+}
+
+impl<T> SetGradient for PathOptions<T> {
+    fn gradient_mut(&mut self) -> &mut Gradient {
+        &mut self.gradient
+    }
+}
+
 impl TessellationOptions for FillOptions {
     type Tessellator = FillTessellator;
     fn into_options(self) -> Options {
@@ -835,6 +1363,12 @@ impl SetColor for Path {
     }
 }
 
+impl SetGradient for Path {
+    fn gradient_mut(&mut self) -> &mut Gradient {
+        &mut self.gradient
+    }
+}
+
 impl From<PathInit> for Primitive {
     fn from(prim: PathInit) -> Self {
         Primitive::PathInit(prim)
@@ -0,0 +1,132 @@
+use bevy::prelude::*;
+
+use crate::draw::mesh::MeshExt;
+use crate::draw::picture::{Layer, Picture};
+use crate::draw::primitive::Primitive;
+use crate::draw::properties::spatial::{orientation, position};
+use crate::draw::properties::{SetColor, SetOrientation, SetPosition};
+use crate::draw::{self, Drawing};
+
+/// Properties related to replaying a recorded **Picture**.
+///
+/// Specifying a color tints the whole picture, multiplying the colors with which it was drawn.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrimitivePicture {
+    position: position::Properties,
+    orientation: orientation::Properties,
+    color: Option<Color>,
+    picture: Picture,
+}
+
+/// The drawing context for a **Picture**.
+pub type DrawingPicture<'a> = Drawing<'a, PrimitivePicture>;
+
+impl PrimitivePicture {
+    pub(crate) fn new(picture: &Picture) -> Self {
+        PrimitivePicture {
+            position: Default::default(),
+            orientation: Default::default(),
+            color: None,
+            picture: picture.clone(),
+        }
+    }
+
+    /// The transform from the picture's local space, prior to the draw's transform.
+    pub(crate) fn local_transform(&self) -> Mat4 {
+        self.position.transform() * self.orientation.transform()
+    }
+
+    /// Whether or not a color tint was specified.
+    pub(crate) fn is_tinted(&self) -> bool {
+        self.color.is_some()
+    }
+
+    /// The given color multiplied by the picture's tint.
+    pub(crate) fn tinted(&self, color: Color) -> Color {
+        LinearRgba::from_vec4(LinearRgba::from(color).to_vec4() * self.tint()).into()
+    }
+
+    // The tint as linear RGBA components.
+    fn tint(&self) -> Vec4 {
+        self.color
+            .map(|color| LinearRgba::from(color).to_vec4())
+            .unwrap_or(Vec4::ONE)
+    }
+
+    /// The layers of the picture, one for each shader model with which it was drawn.
+    pub(crate) fn layers(&self) -> &[Layer] {
+        self.picture.layers()
+    }
+
+    /// Extend the given mesh with the given layer, transformed and tinted.
+    pub(crate) fn render_layer(&self, layer: &Layer, transform: Mat4, mesh: &mut Mesh) {
+        let src = &layer.mesh;
+        let tint = self.tint();
+        // Normals are transformed by the inverse transpose so that they remain perpendicular to
+        // the surface under non-uniform scaling.
+        let linear = Mat3::from_mat4(transform);
+        let normal_matrix = if linear.determinant() != 0.0 {
+            linear.inverse().transpose()
+        } else {
+            linear
+        };
+        let vertex_start = mesh.count_vertices() as u32;
+        for (((point, color), tex_coords), normal) in src
+            .points()
+            .iter()
+            .zip(src.colors())
+            .zip(src.tex_coords())
+            .zip(src.normals())
+        {
+            let point = transform.transform_point3(Vec3::from(*point));
+            let color = Vec4::from(*color) * tint;
+            let normal = (normal_matrix * Vec3::from(*normal)).normalize_or_zero();
+            mesh.points_mut().push(point.to_array());
+            mesh.colors_mut().push(color.to_array());
+            mesh.tex_coords_mut().push(*tex_coords);
+            mesh.normals_mut().push(normal.to_array());
+        }
+        for i in 0..src.count_indices() {
+            mesh.push_index(vertex_start + src.get_index(i));
+        }
+    }
+}
+
+/// All layers are rendered into the given mesh, so are drawn with a single shader model.
+///
+/// The renderer instead draws each layer with its own shader model, reusing its mesh.
+impl draw::render::RenderPrimitive for PrimitivePicture {
+    fn render_primitive(self, ctxt: draw::render::RenderContext, mesh: &mut Mesh) {
+        let transform = *ctxt.transform * self.local_transform();
+        for layer in self.picture.layers() {
+            self.render_layer(layer, transform, mesh);
+        }
+    }
+}
+
+impl SetOrientation for PrimitivePicture {
+    fn properties(&mut self) -> &mut orientation::Properties {
+        SetOrientation::properties(&mut self.orientation)
+    }
+}
+
+impl SetPosition for PrimitivePicture {
+    fn properties(&mut self) -> &mut position::Properties {
+        SetPosition::properties(&mut self.position)
+    }
+}
+
+impl SetColor for PrimitivePicture {
+    fn color_mut(&mut self) -> &mut Option<Color> {
+        SetColor::color_mut(&mut self.color)
+    }
+}
+
+// Primitive conversion.
+
+impl From<PrimitivePicture> for Primitive {
+    fn from(prim: PrimitivePicture) -> Self {
+        Primitive::Picture(prim)
+    }
+}
@@ -0,0 +1,204 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+use lyon::tessellation::StrokeOptions;
+
+use crate::draw::primitive::Primitive;
+use crate::draw::primitive::arc::{self, ellipse_wh, section_points};
+use crate::draw::primitive::polygon::{self, PolygonInit, PolygonOptions, SetPolygon};
+use crate::draw::properties::dash::Dashes;
+use crate::draw::properties::gradient::Gradient;
+use crate::draw::properties::spatial::{dimension, orientation, position};
+use crate::draw::properties::{
+    SetColor, SetDashes, SetDimensions, SetGradient, SetOrientation, SetPosition, SetStroke,
+};
+use crate::draw::{self, Drawing};
+
+/// A filled wedge of an ellipse, bounded by an arc and the two radii at either end.
+///
+/// Angles are in radians, counter-clockwise from the positive *x* axis. By default the wedge spans
+/// the full ellipse.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pie {
+    dimensions: dimension::Properties,
+    start_angle: f32,
+    end_angle: f32,
+    resolution: Option<f32>,
+    polygon: PolygonInit,
+}
+
+/// The drawing context for a pie.
+pub type DrawingPie<'a> = Drawing<'a, Pie>;
+
+impl Pie {
+    /// Specify the width and height of the **Pie** via a given **radius**.
+    pub fn radius(self, radius: f32) -> Self {
+        let side = radius * 2.0;
+        self.w_h(side, side)
+    }
+
+    /// The angle at which the wedge begins.
+    pub fn start_angle(mut self, radians: f32) -> Self {
+        self.start_angle = radians;
+        self
+    }
+
+    /// The angle at which the wedge ends.
+    pub fn end_angle(mut self, radians: f32) -> Self {
+        self.end_angle = radians;
+        self
+    }
+
+    /// The angles at which the wedge begins and ends.
+    pub fn angles(self, start: f32, end: f32) -> Self {
+        self.start_angle(start).end_angle(end)
+    }
+
+    /// The number of segments used to draw the arc of the wedge.
+    ///
+    /// By default, the number of segments is determined by the tessellation tolerance.
+    pub fn resolution(mut self, resolution: f32) -> Self {
+        self.resolution = Some(resolution);
+        self
+    }
+}
+
+impl<'a> DrawingPie<'a> {
+    /// Specify the width and height of the **Pie** via a given **radius**.
+    pub fn radius(self, radius: f32) -> Self {
+        let side = radius * 2.0;
+        self.w_h(side, side)
+    }
+
+    /// The angle at which the wedge begins.
+    pub fn start_angle(self, radians: f32) -> Self {
+        update_pie(&self.draw, self.index, |pie| pie.start_angle = radians);
+        self
+    }
+
+    /// The angle at which the wedge ends.
+    pub fn end_angle(self, radians: f32) -> Self {
+        update_pie(&self.draw, self.index, |pie| pie.end_angle = radians);
+        self
+    }
+
+    /// The angles at which the wedge begins and ends.
+    pub fn angles(self, start: f32, end: f32) -> Self {
+        self.start_angle(start).end_angle(end)
+    }
+
+    /// The number of segments used to draw the arc of the wedge.
+    pub fn resolution(self, resolution: f32) -> Self {
+        update_pie(&self.draw, self.index, |pie| {
+            pie.resolution = Some(resolution)
+        });
+        self
+    }
+}
+
+// Update the inner `Pie` of the primitive being drawn at `index`.
+fn update_pie(draw: &crate::draw::Draw, index: usize, f: impl FnOnce(&mut Pie)) {
+    crate::draw::drawing::with_primitive(draw, index, |prim| match prim {
+        Primitive::Pie(pie) => f(pie),
+        _ => bevy::log::warn_once!("expected a `Pie` primitive"),
+    })
+}
+
+impl draw::render::RenderPrimitive for Pie {
+    fn render_primitive(self, ctxt: draw::render::RenderContext, mesh: &mut Mesh) {
+        let Pie {
+            dimensions,
+            start_angle,
+            end_angle,
+            resolution,
+            polygon,
+        } = self;
+        let wh = ellipse_wh(&dimensions, "pie");
+        if wh.cmple(Vec2::ZERO).any() || start_angle == end_angle {
+            return;
+        }
+        // A full pie is an ellipse, so has no edges running to the centre.
+        let centre = (!arc::is_full(start_angle, end_angle)).then_some(Vec2::ZERO);
+        let points = centre
+            .into_iter()
+            .chain(section_points(wh, start_angle, end_angle, resolution))
+            .map(|p| (p, arc::tex_coords(p, wh)));
+        polygon::render_points_themed(
+            polygon.opts,
+            true,
+            points,
+            ctxt,
+            &draw::theme::Primitive::Pie,
+            mesh,
+        );
+    }
+}
+
+impl Default for Pie {
+    fn default() -> Self {
+        Pie {
+            dimensions: Default::default(),
+            start_angle: 0.0,
+            end_angle: TAU,
+            resolution: None,
+            polygon: Default::default(),
+        }
+    }
+}
+
+impl SetOrientation for Pie {
+    fn properties(&mut self) -> &mut orientation::Properties {
+        SetOrientation::properties(&mut self.polygon)
+    }
+}
+
+impl SetPosition for Pie {
+    fn properties(&mut self) -> &mut position::Properties {
+        SetPosition::properties(&mut self.polygon)
+    }
+}
+
+impl SetDimensions for Pie {
+    fn properties(&mut self) -> &mut dimension::Properties {
+        SetDimensions::properties(&mut self.dimensions)
+    }
+}
+
+impl SetColor for Pie {
+    fn color_mut(&mut self) -> &mut Option<Color> {
+        SetColor::color_mut(&mut self.polygon)
+    }
+}
+
+impl SetStroke for Pie {
+    fn stroke_options_mut(&mut self) -> &mut StrokeOptions {
+        SetStroke::stroke_options_mut(&mut self.polygon)
+    }
+}
+
+impl SetDashes for Pie {
+    fn dashes_mut(&mut self) -> &mut Dashes {
+        SetDashes::dashes_mut(&mut self.polygon)
+    }
+}
+
+impl SetGradient for Pie {
+    fn gradient_mut(&mut self) -> &mut Gradient {
+        SetGradient::gradient_mut(&mut self.polygon)
+    }
+}
+
+impl SetPolygon for Pie {
+    fn polygon_options_mut(&mut self) -> &mut PolygonOptions {
+        SetPolygon::polygon_options_mut(&mut self.polygon)
+    }
+}
+
+// Primitive conversion.
+
+impl From<Pie> for Primitive {
+    fn from(prim: Pie) -> Self {
+        Primitive::Pie(prim)
+    }
+}
@@ -0,0 +1,118 @@
+use bevy::prelude::*;
+
+use crate::draw::primitive::Primitive;
+use crate::draw::primitive::solid::{self, Geometry};
+use crate::draw::properties::spatial::{self, dimension, orientation, position};
+use crate::draw::properties::{SetColor, SetDimensions, SetOrientation, SetPosition};
+use crate::draw::{self, Drawing};
+
+/// Properties related to drawing a flat **Plane** facing along the positive *z* axis.
+///
+/// Unlike a `Rect`, a plane may be subdivided into a grid of quads, e.g. for displacement within a
+/// custom shader model.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Plane {
+    spatial: spatial::Properties,
+    color: Option<Color>,
+    subdivisions: u32,
+}
+
+/// The drawing context for a plane.
+pub type DrawingPlane<'a> = Drawing<'a, Plane>;
+
+impl Plane {
+    /// The number of quads along each side, clamped to a minimum of `1`. Defaults to `1`.
+    pub fn subdivisions(mut self, subdivisions: u32) -> Self {
+        self.subdivisions = subdivisions;
+        self
+    }
+}
+
+impl<'a> DrawingPlane<'a> {
+    /// The number of quads along each side, clamped to a minimum of `1`. Defaults to `1`.
+    pub fn subdivisions(self, subdivisions: u32) -> Self {
+        update_plane(&self.draw, self.index, |plane| {
+            plane.subdivisions = subdivisions
+        });
+        self
+    }
+}
+
+// Update the inner `Plane` of the primitive being drawn at `index`.
+fn update_plane(draw: &crate::draw::Draw, index: usize, f: impl FnOnce(&mut Plane)) {
+    crate::draw::drawing::with_primitive(draw, index, |prim| match prim {
+        Primitive::Plane(plane) => f(plane),
+        _ => bevy::log::warn_once!("expected a `Plane` primitive"),
+    })
+}
+
+impl draw::render::RenderPrimitive for Plane {
+    fn render_primitive(self, ctxt: draw::render::RenderContext, mesh: &mut Mesh) {
+        let Plane {
+            spatial,
+            color,
+            subdivisions,
+        } = self;
+        let whd = solid::whd(&spatial.dimensions);
+        if whd.x <= 0.0 || whd.y <= 0.0 {
+            return;
+        }
+        let subdivisions = subdivisions.max(1);
+        let mut geometry = Geometry::default();
+        geometry.grid(subdivisions, subdivisions, |u, v| {
+            let point = Vec3::new((u - 0.5) * whd.x, (v - 0.5) * whd.y, 0.0);
+            (point, Vec3::Z)
+        });
+        geometry.render(
+            spatial.position,
+            spatial.orientation,
+            color,
+            ctxt,
+            &draw::theme::Primitive::Plane,
+            mesh,
+        );
+    }
+}
+
+impl Default for Plane {
+    fn default() -> Self {
+        Plane {
+            spatial: Default::default(),
+            color: None,
+            subdivisions: 1,
+        }
+    }
+}
+
+impl SetOrientation for Plane {
+    fn properties(&mut self) -> &mut orientation::Properties {
+        SetOrientation::properties(&mut self.spatial)
+    }
+}
+
+impl SetPosition for Plane {
+    fn properties(&mut self) -> &mut position::Properties {
+        SetPosition::properties(&mut self.spatial)
+    }
+}
+
+impl SetDimensions for Plane {
+    fn properties(&mut self) -> &mut dimension::Properties {
+        SetDimensions::properties(&mut self.spatial)
+    }
+}
+
+impl SetColor for Plane {
+    fn color_mut(&mut self) -> &mut Option<Color> {
+        SetColor::color_mut(&mut self.color)
+    }
+}
+
+// Primitive conversion.
+
+impl From<Plane> for Primitive {
+    fn from(prim: Plane) -> Self {
+        Primitive::Plane(prim)
+    }
+}
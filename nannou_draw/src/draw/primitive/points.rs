@@ -0,0 +1,430 @@
+use std::f32::consts::TAU;
+
+use bevy::asset::RenderAssetUsages;
+use bevy::mesh::{Indices, MeshVertexAttribute, PrimitiveTopology, VertexFormat};
+use bevy::prelude::*;
+use lyon::path::Winding;
+use lyon::tessellation::FillOptions;
+
+use crate::draw::drawing::DrawingContext;
+use crate::draw::mesh::MeshExt;
+use crate::draw::primitive::{Primitive, path};
+use crate::draw::properties::spatial::{orientation, position};
+use crate::draw::properties::{SetColor, SetOrientation, SetPosition};
+use crate::draw::render::VectorPath;
+use crate::draw::{self, Drawing};
+
+/// The size of each point sprite, read by the points shader alongside the position, color and
+/// the corner of the sprite stored within the texture coordinates.
+pub const ATTRIBUTE_POINT_SIZE: MeshVertexAttribute =
+    MeshVertexAttribute::new("Nannou_PointSize", 988_540_917, VertexFormat::Float32);
+
+// The number of segments used to approximate round points when tessellated on the CPU.
+const ROUND_RESOLUTION: usize = 16;
+
+/// A cloud of points, each drawn as a round or square sprite.
+///
+/// Rather than tessellating each point, points are rendered as sprites expanded by a dedicated
+/// shader, making it practical to draw many thousands of points per frame. Within pictures,
+/// masks and SVG output, points are tessellated as small polygons instead.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Points {
+    position: position::Properties,
+    orientation: orientation::Properties,
+    color: Option<Color>,
+    size: f32,
+    shape: PointShape,
+    space: PointSpace,
+    // The range into the `Draw` context's points buffer.
+    points: std::ops::Range<usize>,
+}
+
+/// A single point within a **Points** cloud.
+///
+/// Points without a color or size use those of the cloud. Points may be converted from a
+/// position, optionally paired with a color and size, e.g. `(pt2(x, y), color)`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Point {
+    pub position: Vec3,
+    pub color: Option<Color>,
+    pub size: Option<f32>,
+}
+
+/// The shape of each point.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PointShape {
+    /// A disc with a diameter of the point's size.
+    #[default]
+    Round,
+    /// A square with sides of the point's size.
+    Square,
+}
+
+/// The space in which the size of each point is measured.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PointSpace {
+    /// Sizes are in logical pixels, so that points remain the same size on screen regardless of
+    /// the transform or the camera.
+    #[default]
+    Screen,
+    /// Sizes are in the same units as positions, so that points scale with the transform and
+    /// shrink with distance under a perspective camera.
+    World,
+}
+
+/// The drawing context for a cloud of points.
+pub type DrawingPoints<'a> = Drawing<'a, Points>;
+
+impl Points {
+    /// Begin drawing the given points.
+    pub fn new<I>(ctxt: DrawingContext, points: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Point>,
+    {
+        let start = ctxt.points_buffer.len();
+        ctxt.points_buffer
+            .extend(points.into_iter().map(Into::into));
+        let end = ctxt.points_buffer.len();
+        Points {
+            position: Default::default(),
+            orientation: Default::default(),
+            color: None,
+            size: 4.0,
+            shape: Default::default(),
+            space: Default::default(),
+            points: start..end,
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    pub(crate) fn offset_buffers(&mut self, offsets: &crate::record::BufferOffsets) {
+        self.points = self.points.start + offsets.points..self.points.end + offsets.points;
+    }
+
+    /// The size of points that do not specify their own.
+    ///
+    /// This is the diameter of round points and the side length of square points. The default is
+    /// `4.0`.
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// The shape of each point.
+    pub fn shape(mut self, shape: PointShape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Draw each point as a disc. This is the default.
+    pub fn round(self) -> Self {
+        self.shape(PointShape::Round)
+    }
+
+    /// Draw each point as a square.
+    pub fn square(self) -> Self {
+        self.shape(PointShape::Square)
+    }
+
+    /// The space in which the size of each point is measured.
+    pub fn space(mut self, space: PointSpace) -> Self {
+        self.space = space;
+        self
+    }
+
+    /// Measure point sizes in logical pixels. This is the default.
+    pub fn screen_space(self) -> Self {
+        self.space(PointSpace::Screen)
+    }
+
+    /// Measure point sizes in the same units as their positions.
+    pub fn world_space(self) -> Self {
+        self.space(PointSpace::World)
+    }
+
+    pub(crate) fn point_shape(&self) -> PointShape {
+        self.shape
+    }
+
+    pub(crate) fn point_space(&self) -> PointSpace {
+        self.space
+    }
+
+    /// Produce a mesh of sprites to be expanded by the points shader.
+    ///
+    /// Each point becomes a quad of four vertices at the point's transformed position, with the
+    /// corner of the quad in the texture coordinates and the size in `ATTRIBUTE_POINT_SIZE`.
+    pub(crate) fn sprite_mesh(
+        &self,
+        points_buffer: &[Point],
+        theme: &draw::Theme,
+        transform: &Mat4,
+    ) -> Mesh {
+        let transform = self.transform(transform);
+        let scale = self.size_scale(transform);
+        let color = self.default_color(theme);
+        let points = &points_buffer[self.points.clone()];
+
+        let mut positions = Vec::with_capacity(points.len() * 4);
+        let mut colors = Vec::with_capacity(points.len() * 4);
+        let mut corners: Vec<[f32; 2]> = Vec::with_capacity(points.len() * 4);
+        let mut sizes = Vec::with_capacity(points.len() * 4);
+        let mut indices = Vec::with_capacity(points.len() * 6);
+        for point in points {
+            let start = positions.len() as u32;
+            let position = transform.transform_point3(point.position).to_array();
+            let color = point.color.unwrap_or(color).to_linear().to_f32_array();
+            let size = point.size.unwrap_or(self.size) * scale;
+            for corner in [[-1.0, -1.0], [1.0, -1.0], [1.0, 1.0], [-1.0, 1.0]] {
+                positions.push(position);
+                colors.push(color);
+                corners.push(corner);
+                sizes.push(size);
+            }
+            indices.extend([0, 1, 2, 0, 2, 3].map(|i| start + i));
+        }
+
+        Mesh::new(
+            PrimitiveTopology::TriangleList,
+            RenderAssetUsages::default(),
+        )
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_COLOR, colors)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, corners)
+        .with_inserted_attribute(ATTRIBUTE_POINT_SIZE, sizes)
+        .with_inserted_indices(Indices::U32(indices))
+    }
+
+    // The transform applied to the position of each point.
+    fn transform(&self, transform: &Mat4) -> Mat4 {
+        *transform * self.position.transform() * self.orientation.transform()
+    }
+
+    // The scale applied to point sizes, which only follow the transform in world space.
+    fn size_scale(&self, transform: Mat4) -> f32 {
+        match self.space {
+            PointSpace::Screen => 1.0,
+            PointSpace::World => {
+                let area = transform
+                    .x_axis
+                    .truncate()
+                    .cross(transform.y_axis.truncate());
+                area.length().sqrt()
+            }
+        }
+    }
+
+    // The color of points that do not specify their own.
+    fn default_color(&self, theme: &draw::Theme) -> Color {
+        self.color
+            .unwrap_or_else(|| theme.fill(&draw::theme::Primitive::Points))
+    }
+}
+
+impl<'a> DrawingPoints<'a> {
+    /// The size of points that do not specify their own.
+    ///
+    /// This is the diameter of round points and the side length of square points. The default is
+    /// `4.0`.
+    pub fn size(self, size: f32) -> Self {
+        update_points(&self.draw, self.index, |points| points.size = size);
+        self
+    }
+
+    /// The shape of each point.
+    pub fn shape(self, shape: PointShape) -> Self {
+        update_points(&self.draw, self.index, |points| points.shape = shape);
+        self
+    }
+
+    /// Draw each point as a disc. This is the default.
+    pub fn round(self) -> Self {
+        self.shape(PointShape::Round)
+    }
+
+    /// Draw each point as a square.
+    pub fn square(self) -> Self {
+        self.shape(PointShape::Square)
+    }
+
+    /// The space in which the size of each point is measured.
+    pub fn space(self, space: PointSpace) -> Self {
+        update_points(&self.draw, self.index, |points| points.space = space);
+        self
+    }
+
+    /// Measure point sizes in logical pixels. This is the default.
+    pub fn screen_space(self) -> Self {
+        self.space(PointSpace::Screen)
+    }
+
+    /// Measure point sizes in the same units as their positions.
+    pub fn world_space(self) -> Self {
+        self.space(PointSpace::World)
+    }
+}
+
+// Update the inner `Points` of the primitive being drawn at `index`.
+fn update_points(draw: &crate::draw::Draw, index: usize, f: impl FnOnce(&mut Points)) {
+    crate::draw::drawing::with_primitive(draw, index, |prim| match prim {
+        Primitive::Points(points) => f(points),
+        _ => bevy::log::warn_once!("expected a `Points` primitive"),
+    })
+}
+
+// Tessellate the points as polygons, e.g. for pictures, masks and SVG output where the points
+// shader is unavailable.
+impl draw::render::RenderPrimitive for Points {
+    fn render_primitive(self, ctxt: draw::render::RenderContext, mesh: &mut Mesh) {
+        let transform = self.transform(ctxt.transform);
+        let scale = self.size_scale(transform);
+        let color = self.default_color(ctxt.theme);
+        let points = &ctxt.points_buffer[self.points.clone()];
+        let corners: Vec<Vec2> = match self.shape {
+            PointShape::Round => (0..ROUND_RESOLUTION)
+                .map(|i| Vec2::from_angle(i as f32 / ROUND_RESOLUTION as f32 * TAU))
+                .collect(),
+            PointShape::Square => vec![
+                vec2(-1.0, -1.0),
+                vec2(1.0, -1.0),
+                vec2(1.0, 1.0),
+                vec2(-1.0, 1.0),
+            ],
+        };
+
+        let mut vector_paths = ctxt.vector_paths;
+        for point in points {
+            let center = transform.transform_point3(point.position);
+            let color = point.color.unwrap_or(color);
+            let half = point.size.unwrap_or(self.size) * scale * 0.5;
+
+            if let Some(vector_paths) = vector_paths.as_deref_mut() {
+                let mut builder = lyon::path::Path::builder();
+                let center_2d = lyon::math::point(center.x, center.y);
+                match self.shape {
+                    PointShape::Round => builder.add_circle(center_2d, half, Winding::Positive),
+                    PointShape::Square => {
+                        let min = lyon::math::point(center.x - half, center.y - half);
+                        let max = lyon::math::point(center.x + half, center.y + half);
+                        let rect = lyon::math::Box2D::new(min, max);
+                        builder.add_rectangle(&rect, Winding::Positive);
+                    }
+                }
+                let path = builder.build();
+                let options = path::Options::Fill(FillOptions::default());
+                vector_paths.push(VectorPath::new(&path, color, Mat4::IDENTITY, options));
+                continue;
+            }
+
+            let start = mesh.count_vertices() as u32;
+            let color = color.to_linear().to_f32_array();
+            for corner in &corners {
+                let p = center + (*corner * half).extend(0.0);
+                mesh.points_mut().push(p.to_array());
+                mesh.colors_mut().push(color);
+                mesh.tex_coords_mut().push([0.0; 2]);
+                mesh.normals_mut().push([0.0, 0.0, 1.0]);
+            }
+            for i in 1..corners.len() as u32 - 1 {
+                mesh.push_index(start);
+                mesh.push_index(start + i);
+                mesh.push_index(start + i + 1);
+            }
+        }
+    }
+}
+
+impl From<Vec2> for Point {
+    fn from(position: Vec2) -> Self {
+        position.extend(0.0).into()
+    }
+}
+
+impl From<Vec3> for Point {
+    fn from(position: Vec3) -> Self {
+        Point {
+            position,
+            color: None,
+            size: None,
+        }
+    }
+}
+
+impl From<(Vec2, Color)> for Point {
+    fn from((position, color): (Vec2, Color)) -> Self {
+        (position.extend(0.0), color).into()
+    }
+}
+
+impl From<(Vec3, Color)> for Point {
+    fn from((position, color): (Vec3, Color)) -> Self {
+        Point {
+            position,
+            color: Some(color),
+            size: None,
+        }
+    }
+}
+
+impl From<(Vec2, f32)> for Point {
+    fn from((position, size): (Vec2, f32)) -> Self {
+        (position.extend(0.0), size).into()
+    }
+}
+
+impl From<(Vec3, f32)> for Point {
+    fn from((position, size): (Vec3, f32)) -> Self {
+        Point {
+            position,
+            color: None,
+            size: Some(size),
+        }
+    }
+}
+
+impl From<(Vec2, Color, f32)> for Point {
+    fn from((position, color, size): (Vec2, Color, f32)) -> Self {
+        (position.extend(0.0), color, size).into()
+    }
+}
+
+impl From<(Vec3, Color, f32)> for Point {
+    fn from((position, color, size): (Vec3, Color, f32)) -> Self {
+        Point {
+            position,
+            color: Some(color),
+            size: Some(size),
+        }
+    }
+}
+
+impl SetOrientation for Points {
+    fn properties(&mut self) -> &mut orientation::Properties {
+        SetOrientation::properties(&mut self.orientation)
+    }
+}
+
+impl SetPosition for Points {
+    fn properties(&mut self) -> &mut position::Properties {
+        SetPosition::properties(&mut self.position)
+    }
+}
+
+impl SetColor for Points {
+    fn color_mut(&mut self) -> &mut Option<Color> {
+        SetColor::color_mut(&mut self.color)
+    }
+}
+
+// Primitive conversion.
+
+impl From<Points> for Primitive {
+    fn from(prim: Points) -> Self {
+        Primitive::Points(prim)
+    }
+}
@@ -5,8 +5,13 @@ use lyon::tessellation::StrokeOptions;
 use crate::draw::drawing::{self, DrawingContext};
 use crate::draw::primitive::Primitive;
 use crate::draw::primitive::path::{self, PathEventSource};
+use crate::draw::properties::dash::Dashes;
+use crate::draw::properties::gradient::Gradient;
 use crate::draw::properties::spatial::{orientation, position};
-use crate::draw::properties::{SetColor, SetOrientation, SetPosition, SetStroke};
+use crate::draw::properties::{
+    SetColor, SetDashes, SetGradient, SetOrientation, SetPosition, SetStroke,
+};
+use crate::draw::render::VectorPath;
 use crate::draw::{self, Drawing};
 
 /// A trait implemented for all polygon draw primitives.
@@ -32,6 +37,14 @@ pub trait SetPolygon: Sized {
         self
     }
 
+    /// Short-hand for the `stroke_color` method.
+    fn stroke<C>(self, color: C) -> Self
+    where
+        C: Into<Color>,
+    {
+        self.stroke_color(color)
+    }
+
     /// Specify the whole set of polygon options.
     fn polygon_options(mut self, opts: PolygonOptions) -> Self {
         *self.polygon_options_mut() = opts;
@@ -41,12 +54,14 @@ pub trait SetPolygon: Sized {
 
 /// State related to drawing a **Polygon**.
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PolygonInit {
     pub(crate) opts: PolygonOptions,
 }
 
 /// The set of options shared by all polygon types.
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PolygonOptions {
     pub position: position::Properties,
     pub orientation: orientation::Properties,
@@ -54,10 +69,14 @@ pub struct PolygonOptions {
     pub stroke_color: Option<Color>,
     pub color: Option<Color>,
     pub stroke: Option<StrokeOptions>,
+    pub dashes: Dashes,
+    /// A gradient used in place of `color` for the fill.
+    pub gradient: Gradient,
 }
 
 /// A polygon with vertices already submitted.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Polygon {
     opts: PolygonOptions,
     path_event_src: PathEventSource,
@@ -70,14 +89,6 @@ pub type DrawingPolygonInit<'a> = Drawing<'a, PolygonInit>;
 pub type DrawingPolygon<'a> = Drawing<'a, Polygon>;
 
 impl PolygonInit {
-    /// Stroke the outline with the given color.
-    pub fn stroke<C>(self, color: C) -> Self
-    where
-        C: Into<Color>,
-    {
-        self.stroke_color(color)
-    }
-
     /// Submit the path events to be tessellated.
     pub(crate) fn events<I>(self, ctxt: DrawingContext, events: I) -> Polygon
     where
@@ -156,6 +167,8 @@ pub fn render_events_themed<F, I>(
         stroke_color,
         color,
         stroke,
+        dashes,
+        gradient,
     } = opts;
 
     // Determine the transform to apply to all points.
@@ -164,24 +177,28 @@ pub fn render_events_themed<F, I>(
     let transform = global_transform * local_transform;
 
     // A function for rendering the path.
-    let mut render =
-        |opts: path::Options,
-         color: Option<Color>,
-         theme: &draw::Theme,
-         fill_tessellator: &mut lyon::tessellation::FillTessellator,
-         stroke_tessellator: &mut lyon::tessellation::StrokeTessellator| {
-            path::render_path_events(
-                events(),
-                color,
-                transform,
-                opts,
-                theme,
-                theme_primitive,
-                fill_tessellator,
-                stroke_tessellator,
-                mesh,
-            )
-        };
+    let mut render = |opts: path::Options,
+                      color: Option<Color>,
+                      gradient: Option<&Gradient>,
+                      theme: &draw::Theme,
+                      fill_tessellator: &mut lyon::tessellation::FillTessellator,
+                      stroke_tessellator: &mut lyon::tessellation::StrokeTessellator,
+                      vector_paths: Option<&mut Vec<VectorPath>>| {
+        path::render_path_events(
+            events(),
+            color,
+            gradient,
+            transform,
+            opts,
+            Some(&dashes),
+            theme,
+            theme_primitive,
+            fill_tessellator,
+            stroke_tessellator,
+            vector_paths,
+            mesh,
+        )
+    };
 
     // Do the fill tessellation first.
     if !no_fill {
@@ -189,9 +206,11 @@ pub fn render_events_themed<F, I>(
         render(
             opts,
             color,
+            Some(&gradient),
             &ctxt.theme,
             &mut ctxt.fill_tessellator,
             &mut ctxt.stroke_tessellator,
+            ctxt.vector_paths.as_deref_mut(),
         );
     }
 
@@ -202,9 +221,11 @@ pub fn render_events_themed<F, I>(
         render(
             opts,
             color,
+            None,
             &ctxt.theme,
             &mut ctxt.fill_tessellator,
             &mut ctxt.stroke_tessellator,
+            ctxt.vector_paths.as_deref_mut(),
         );
     }
 }
@@ -226,6 +247,8 @@ pub fn render_points_themed<I>(
         stroke_color,
         color,
         stroke,
+        dashes,
+        gradient,
     } = opts;
 
     // Determine the transform to apply to all points.
@@ -234,25 +257,29 @@ pub fn render_points_themed<I>(
     let transform = global_transform * local_transform;
 
     // A function for rendering the path.
-    let mut render =
-        |opts: path::Options,
-         color: Option<Color>,
-         theme: &draw::Theme,
-         fill_tessellator: &mut lyon::tessellation::FillTessellator,
-         stroke_tessellator: &mut lyon::tessellation::StrokeTessellator| {
-            path::render_path_points_themed(
-                points.clone(),
-                close,
-                color,
-                transform,
-                opts,
-                theme,
-                theme_primitive,
-                fill_tessellator,
-                stroke_tessellator,
-                mesh,
-            )
-        };
+    let mut render = |opts: path::Options,
+                      color: Option<Color>,
+                      gradient: Option<&Gradient>,
+                      theme: &draw::Theme,
+                      fill_tessellator: &mut lyon::tessellation::FillTessellator,
+                      stroke_tessellator: &mut lyon::tessellation::StrokeTessellator,
+                      vector_paths: Option<&mut Vec<VectorPath>>| {
+        path::render_path_points_themed(
+            points.clone(),
+            close,
+            color,
+            gradient,
+            transform,
+            opts,
+            Some(&dashes),
+            theme,
+            theme_primitive,
+            fill_tessellator,
+            stroke_tessellator,
+            vector_paths,
+            mesh,
+        )
+    };
 
     // Do the fill tessellation first.
     if !no_fill {
@@ -260,9 +287,11 @@ pub fn render_points_themed<I>(
         render(
             opts,
             color,
+            Some(&gradient),
             &ctxt.theme,
             &mut ctxt.fill_tessellator,
             &mut ctxt.stroke_tessellator,
+            ctxt.vector_paths.as_deref_mut(),
         );
     }
 
@@ -273,14 +302,21 @@ pub fn render_points_themed<I>(
         render(
             opts,
             color,
+            None,
             &ctxt.theme,
             &mut ctxt.fill_tessellator,
             &mut ctxt.stroke_tessellator,
+            ctxt.vector_paths.as_deref_mut(),
         );
     }
 }
 
 impl Polygon {
+    #[cfg(feature = "serde")]
+    pub(crate) fn offset_buffers(&mut self, offsets: &crate::record::BufferOffsets) {
+        self.path_event_src.offset_buffers(offsets);
+    }
+
     pub(crate) fn render_themed(
         self,
         ctxt: draw::render::RenderContext,
@@ -297,6 +333,8 @@ impl Polygon {
                     stroke_color,
                     color,
                     stroke,
+                    dashes,
+                    gradient,
                 },
         } = self;
         let draw::render::RenderContext {
@@ -306,6 +344,7 @@ impl Polygon {
             path_points_vertex_buffer,
             transform,
             theme,
+            mut vector_paths,
             ..
         } = ctxt;
 
@@ -315,25 +354,29 @@ impl Polygon {
         let transform = global_transform * local_transform;
 
         // A function for rendering the path.
-        let mut render =
-            |src: path::PathEventSourceIter,
-             opts: path::Options,
-             color: Option<Color>,
-             theme: &draw::Theme,
-             fill_tessellator: &mut lyon::tessellation::FillTessellator,
-             stroke_tessellator: &mut lyon::tessellation::StrokeTessellator| {
-                path::render_path_source(
-                    src,
-                    color,
-                    transform,
-                    opts,
-                    theme,
-                    theme_primitive,
-                    fill_tessellator,
-                    stroke_tessellator,
-                    mesh,
-                )
-            };
+        let mut render = |src: path::PathEventSourceIter,
+                          opts: path::Options,
+                          color: Option<Color>,
+                          gradient: Option<&Gradient>,
+                          theme: &draw::Theme,
+                          fill_tessellator: &mut lyon::tessellation::FillTessellator,
+                          stroke_tessellator: &mut lyon::tessellation::StrokeTessellator,
+                          vector_paths: Option<&mut Vec<VectorPath>>| {
+            path::render_path_source(
+                src,
+                color,
+                gradient,
+                transform,
+                opts,
+                Some(&dashes),
+                theme,
+                theme_primitive,
+                fill_tessellator,
+                stroke_tessellator,
+                vector_paths,
+                mesh,
+            )
+        };
 
         // Do the fill tessellation first.
         if !no_fill {
@@ -346,9 +389,11 @@ impl Polygon {
                         src,
                         opts,
                         color,
+                        Some(&gradient),
                         theme,
                         fill_tessellator,
                         stroke_tessellator,
+                        vector_paths.as_deref_mut(),
                     );
                 }
                 PathEventSource::Vertex { ref range, close } => {
@@ -362,9 +407,11 @@ impl Polygon {
                         src,
                         opts,
                         color,
+                        Some(&gradient),
                         theme,
                         fill_tessellator,
                         stroke_tessellator,
+                        vector_paths.as_deref_mut(),
                     );
                 }
             }
@@ -381,9 +428,11 @@ impl Polygon {
                         src,
                         opts,
                         stroke_color,
+                        None,
                         theme,
                         fill_tessellator,
                         stroke_tessellator,
+                        vector_paths.as_deref_mut(),
                     );
                 }
                 PathEventSource::Vertex { range, close } => {
@@ -400,9 +449,11 @@ impl Polygon {
                         src,
                         opts,
                         stroke_color,
+                        None,
                         theme,
                         fill_tessellator,
                         stroke_tessellator,
+                        vector_paths.as_deref_mut(),
                     );
                 }
             }
@@ -438,6 +489,14 @@ where
         self
     }
 
+    /// Short-hand for the `stroke_color` method.
+    pub fn stroke<C>(self, color: C) -> Self
+    where
+        C: Into<Color>,
+    {
+        self.stroke_color(color)
+    }
+
     /// Specify the whole set of polygon options.
     pub fn polygon_options(self, opts: PolygonOptions) -> Self {
         set_polygon(&self.draw, self.index, Update::Opts(opts));
@@ -446,14 +505,6 @@ where
 }
 
 impl<'a> DrawingPolygonInit<'a> {
-    /// Stroke the outline with the given color.
-    pub fn stroke<C>(self, color: C) -> Self
-    where
-        C: Into<Color>,
-    {
-        self.stroke_color(color)
-    }
-
     /// Describe the polygon with a sequence of path events.
     pub fn events<I>(self, events: I) -> DrawingPolygon<'a>
     where
@@ -540,6 +591,18 @@ impl SetStroke for PolygonInit {
     }
 }
 
+impl SetDashes for PolygonInit {
+    fn dashes_mut(&mut self) -> &mut Dashes {
+        SetDashes::dashes_mut(&mut self.opts.dashes)
+    }
+}
+
+impl SetGradient for PolygonInit {
+    fn gradient_mut(&mut self) -> &mut Gradient {
+        SetGradient::gradient_mut(&mut self.opts.gradient)
+    }
+}
+
 impl SetOrientation for Polygon {
     fn properties(&mut self) -> &mut orientation::Properties {
         SetOrientation::properties(&mut self.opts.orientation)
@@ -558,6 +621,12 @@ impl SetColor for Polygon {
     }
 }
 
+impl SetGradient for Polygon {
+    fn gradient_mut(&mut self) -> &mut Gradient {
+        SetGradient::gradient_mut(&mut self.opts.gradient)
+    }
+}
+
 impl From<PolygonInit> for Primitive {
     fn from(prim: PolygonInit) -> Self {
         Primitive::PolygonInit(prim)
@@ -5,18 +5,26 @@ use nannou_core::geom;
 
 use crate::draw::primitive::Primitive;
 use crate::draw::primitive::polygon::{self, PolygonInit, PolygonOptions, SetPolygon};
+use crate::draw::properties::dash::Dashes;
+use crate::draw::properties::gradient::Gradient;
 use crate::draw::properties::spatial::{dimension, orientation, position};
+use crate::draw::properties::tex_coords::{self, SetTexCoords};
 use crate::draw::properties::{
-    SetColor, SetDimensions, SetOrientation, SetPosition, SetStroke, spatial,
+    SetColor, SetDashes, SetDimensions, SetGradient, SetOrientation, SetPosition, SetStroke,
+    spatial,
 };
 use crate::draw::{self, Drawing};
 
 /// Properties related to drawing a **Quad**.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Quad {
+    #[cfg_attr(feature = "serde", serde(with = "crate::record::geom_quad"))]
     quad: geom::Quad<Vec2>,
     polygon: PolygonInit,
     dimensions: spatial::dimension::Properties,
+    #[cfg_attr(feature = "serde", serde(with = "crate::record::option_geom_rect"))]
+    tex_coords: Option<geom::Rect>,
 }
 
 /// The drawing context for a `Quad`.
@@ -25,14 +33,6 @@ pub type DrawingQuad<'a> = Drawing<'a, Quad>;
 // Quad-specific methods.
 
 impl Quad {
-    /// Stroke the outline with the given color.
-    pub fn stroke<C>(self, color: C) -> Self
-    where
-        C: Into<Color>,
-    {
-        self.stroke_color(color)
-    }
-
     /// Use the given four points as the vertices (corners) of the quad.
     pub fn points<P>(mut self, a: P, b: P, c: P, d: P) -> Self
     where
@@ -54,6 +54,7 @@ impl draw::render::RenderPrimitive for Quad {
             mut quad,
             polygon,
             dimensions,
+            tex_coords,
         } = self;
 
         // If dimensions were specified, scale the points to those dimensions.
@@ -73,12 +74,21 @@ impl draw::render::RenderPrimitive for Quad {
             quad = geom::Quad([new_a, new_b, new_c, new_d]);
         }
 
-        let tex_coords = [
-            Vec2::new(0.0, 0.0), // Bottom-left
-            Vec2::new(1.0, 0.0), // Bottom-right
-            Vec2::new(1.0, 1.0), // Top-right
-            Vec2::new(0.0, 1.0), // Top-left
-        ];
+        let tex_coords = tex_coords
+            .map(|area| {
+                [
+                    area.bottom_left(),
+                    area.bottom_right(),
+                    area.top_right(),
+                    area.top_left(),
+                ]
+            })
+            .unwrap_or([
+                Vec2::new(0.0, 0.0), // Bottom-left
+                Vec2::new(1.0, 0.0), // Bottom-right
+                Vec2::new(1.0, 1.0), // Top-right
+                Vec2::new(0.0, 1.0), // Top-left
+            ]);
 
         let points = quad.vertices().zip(tex_coords.iter().copied());
         polygon::render_points_themed(
@@ -96,10 +106,12 @@ impl From<geom::Quad<Vec2>> for Quad {
     fn from(quad: geom::Quad<Vec2>) -> Self {
         let polygon = Default::default();
         let dimensions = Default::default();
+        let tex_coords = None;
         Quad {
             polygon,
             dimensions,
             quad,
+            tex_coords,
         }
     }
 }
@@ -150,12 +162,30 @@ impl SetStroke for Quad {
     }
 }
 
+impl SetDashes for Quad {
+    fn dashes_mut(&mut self) -> &mut Dashes {
+        SetDashes::dashes_mut(&mut self.polygon)
+    }
+}
+
+impl SetGradient for Quad {
+    fn gradient_mut(&mut self) -> &mut Gradient {
+        SetGradient::gradient_mut(&mut self.polygon)
+    }
+}
+
 impl SetPolygon for Quad {
     fn polygon_options_mut(&mut self) -> &mut PolygonOptions {
         SetPolygon::polygon_options_mut(&mut self.polygon)
     }
 }
 
+impl SetTexCoords for Quad {
+    fn tex_coords_mut(&mut self) -> &mut Option<geom::Rect> {
+        SetTexCoords::tex_coords_mut(&mut self.tex_coords)
+    }
+}
+
 // Primitive conversions.
 
 impl From<Quad> for Primitive {
@@ -179,6 +209,14 @@ impl<'a> DrawingQuad<'a> {
         );
         self
     }
+
+    /// The area of the texture mapped onto the corners of the **Quad**.
+    ///
+    /// The first point maps to the top-left of the area, with the rest following clockwise.
+    pub fn area(self, area: geom::Rect) -> Self {
+        tex_coords::set_tex_coords_area(&self.draw, self.index, area);
+        self
+    }
 }
 
 // Set the corner points of the `Quad` primitive being drawn at `index`.
@@ -1,3 +1,5 @@
+use std::f32::consts::{FRAC_PI_2, PI};
+
 use bevy::prelude::*;
 use lyon::tessellation::StrokeOptions;
 
@@ -5,16 +7,24 @@ use nannou_core::geom;
 
 use crate::draw::primitive::Primitive;
 use crate::draw::primitive::polygon::{self, PolygonInit, PolygonOptions, SetPolygon};
+use crate::draw::properties::dash::Dashes;
+use crate::draw::properties::gradient::Gradient;
 use crate::draw::properties::spatial::{dimension, orientation, position};
 use crate::draw::properties::tex_coords::{self, SetTexCoords};
-use crate::draw::properties::{SetColor, SetDimensions, SetOrientation, SetPosition, SetStroke};
+use crate::draw::properties::{
+    SetColor, SetDashes, SetDimensions, SetGradient, SetOrientation, SetPosition, SetStroke,
+};
 use crate::draw::{self, Drawing};
 
 /// Properties related to drawing a **Rect**.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rect {
     dimensions: dimension::Properties,
+    #[cfg_attr(feature = "serde", serde(with = "crate::record::option_geom_rect"))]
     tex_coords: Option<geom::Rect>,
+    /// The radii of the top-left, top-right, bottom-right and bottom-left corners.
+    corner_radii: [f32; 4],
     polygon: PolygonInit,
 }
 
@@ -24,34 +34,55 @@ pub type DrawingRect<'a> = Drawing<'a, Rect>;
 // Trait implementations.
 
 impl Rect {
-    /// Stroke the outline with the given color.
-    pub fn stroke<C>(self, color: C) -> Self
-    where
-        C: Into<Color>,
-    {
-        self.stroke_color(color)
+    /// Round all corners with the given radius.
+    pub fn corner_radius(self, radius: f32) -> Self {
+        self.corner_radii([radius; 4])
     }
-}
 
-impl<'a> DrawingRect<'a> {
-    /// Stroke the outline with the given color.
-    pub fn stroke<C>(self, color: C) -> Self
-    where
-        C: Into<Color>,
-    {
-        self.stroke_color(color)
+    /// Round the top-left, top-right, bottom-right and bottom-left corners with the given radii.
+    ///
+    /// Radii are scaled down where the sum of two adjacent radii would exceed the side between
+    /// them.
+    pub fn corner_radii(mut self, radii: [f32; 4]) -> Self {
+        self.corner_radii = radii;
+        self
     }
+}
 
+impl<'a> DrawingRect<'a> {
     pub fn area(self, area: geom::Rect) -> Self {
         tex_coords::set_tex_coords_area(&self.draw, self.index, area);
         self
     }
+
+    /// Round all corners with the given radius.
+    pub fn corner_radius(self, radius: f32) -> Self {
+        self.corner_radii([radius; 4])
+    }
+
+    /// Round the top-left, top-right, bottom-right and bottom-left corners with the given radii.
+    ///
+    /// Radii are scaled down where the sum of two adjacent radii would exceed the side between
+    /// them.
+    pub fn corner_radii(self, radii: [f32; 4]) -> Self {
+        update_rect(&self.draw, self.index, |rect| rect.corner_radii = radii);
+        self
+    }
+}
+
+// Update the inner `Rect` of the primitive being drawn at `index`.
+fn update_rect(draw: &crate::draw::Draw, index: usize, f: impl FnOnce(&mut Rect)) {
+    crate::draw::drawing::with_primitive(draw, index, |prim| match prim {
+        Primitive::Rect(rect) => f(rect),
+        _ => bevy::log::warn_once!("expected a `Rect` primitive"),
+    })
 }
 
 impl draw::render::RenderPrimitive for Rect {
     fn render_primitive(self, ctxt: draw::render::RenderContext, mesh: &mut Mesh) {
         let Rect {
             tex_coords,
+            corner_radii,
             polygon,
             dimensions,
         } = self;
@@ -66,6 +97,34 @@ impl draw::render::RenderPrimitive for Rect {
         let h = maybe_y.unwrap_or(100.0);
         let rect = geom::Rect::from_wh([w, h].into());
 
+        if corner_radii.iter().any(|&r| r > 0.0) {
+            // Texture coordinates are interpolated by each point's position within the rect, with
+            // the top edge mapping to the top of the texture as for the square corners below.
+            let area = tex_coords.unwrap_or(geom::Rect::from_corners(Vec2::ZERO, Vec2::ONE));
+            // Corners are subdivided by their radius once drawn, so that scaled up corners stay
+            // smooth.
+            let linear = Mat3::from_mat4(*ctxt.transform);
+            let scale = linear.x_axis.length().max(linear.y_axis.length());
+            let points = rounded_outline(rect, corner_radii, scale)
+                .into_iter()
+                .map(|p| {
+                    let u = (p.x - rect.left()) / rect.w();
+                    let v = (rect.top() - p.y) / rect.h();
+                    let tex = area.bottom_left()
+                        + Vec2::new(u, v) * (area.top_right() - area.bottom_left());
+                    (p, tex)
+                });
+            polygon::render_points_themed(
+                polygon.opts,
+                true,
+                points,
+                ctxt,
+                &draw::theme::Primitive::Rect,
+                mesh,
+            );
+            return;
+        }
+
         let tex_coords = tex_coords
             .map(|area| {
                 [
@@ -113,11 +172,60 @@ impl Default for Rect {
         Rect {
             dimensions,
             tex_coords: None,
+            corner_radii: [0.0; 4],
             polygon,
         }
     }
 }
 
+// The outline of `rect` with rounded corners, counter-clockwise from the bottom-left corner.
+//
+// `draw_scale` is the largest scale applied by the draw's transform, used to choose how finely
+// each corner is subdivided.
+fn rounded_outline(rect: geom::Rect, radii: [f32; 4], draw_scale: f32) -> Vec<Vec2> {
+    let [tl, tr, br, bl] = radii.map(|r| r.max(0.0));
+    let (w, h) = (rect.w().abs(), rect.h().abs());
+    // Scale all radii down evenly so that adjacent corners never overlap.
+    let scale = [w / (bl + br), h / (br + tr), w / (tr + tl), h / (tl + bl)]
+        .into_iter()
+        .filter(|s| s.is_finite())
+        .fold(1.0f32, f32::min);
+    let corners = [
+        (rect.bottom_left(), Vec2::new(1.0, 1.0), bl, PI),
+        (rect.bottom_right(), Vec2::new(-1.0, 1.0), br, PI * 1.5),
+        (rect.top_right(), Vec2::new(-1.0, -1.0), tr, 0.0),
+        (rect.top_left(), Vec2::new(1.0, -1.0), tl, FRAC_PI_2),
+    ];
+    let mut points = Vec::new();
+    let mut push = |p: Vec2| {
+        if points.last() != Some(&p) {
+            points.push(p);
+        }
+    };
+    for (corner, inward, radius, start) in corners {
+        let radius = radius * scale;
+        if radius <= 0.0 {
+            push(corner);
+            continue;
+        }
+        let centre = corner + inward * radius;
+        let segments = arc_segments(radius * draw_scale);
+        for i in 0..=segments {
+            let angle = start + FRAC_PI_2 * i as f32 / segments as f32;
+            push(centre + Vec2::from_angle(angle) * radius);
+        }
+    }
+    points
+}
+
+// The number of segments required to approximate a quarter circle of the given radius within
+// the default tessellation tolerance.
+fn arc_segments(radius: f32) -> usize {
+    let tolerance = lyon::tessellation::FillOptions::DEFAULT_TOLERANCE;
+    let step = 2.0 * (1.0 - tolerance / radius).clamp(-1.0, 1.0).acos();
+    ((FRAC_PI_2 / step).ceil() as usize).clamp(1, 64)
+}
+
 impl SetOrientation for Rect {
     fn properties(&mut self) -> &mut orientation::Properties {
         SetOrientation::properties(&mut self.polygon)
@@ -148,6 +256,18 @@ impl SetStroke for Rect {
     }
 }
 
+impl SetDashes for Rect {
+    fn dashes_mut(&mut self) -> &mut Dashes {
+        SetDashes::dashes_mut(&mut self.polygon)
+    }
+}
+
+impl SetGradient for Rect {
+    fn gradient_mut(&mut self) -> &mut Gradient {
+        SetGradient::gradient_mut(&mut self.polygon)
+    }
+}
+
 impl SetPolygon for Rect {
     fn polygon_options_mut(&mut self) -> &mut PolygonOptions {
         SetPolygon::polygon_options_mut(&mut self.polygon)
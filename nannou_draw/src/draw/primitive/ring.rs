@@ -0,0 +1,249 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+use lyon::tessellation::StrokeOptions;
+
+use crate::draw::primitive::Primitive;
+use crate::draw::primitive::arc::{self, ellipse_wh, section_points};
+use crate::draw::primitive::polygon::{self, PolygonInit, PolygonOptions, SetPolygon};
+use crate::draw::properties::dash::Dashes;
+use crate::draw::properties::gradient::Gradient;
+use crate::draw::properties::spatial::{dimension, orientation, position};
+use crate::draw::properties::{
+    SetColor, SetDashes, SetDimensions, SetGradient, SetOrientation, SetPosition, SetStroke,
+};
+use crate::draw::{self, Drawing};
+
+/// The region between two concentric ellipses (an annulus), or some section of it.
+///
+/// Angles are in radians, counter-clockwise from the positive *x* axis. By default the ring spans
+/// the full ellipse and its inner radius is half of its outer radius.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ring {
+    dimensions: dimension::Properties,
+    inner_radius: Option<f32>,
+    start_angle: f32,
+    end_angle: f32,
+    resolution: Option<f32>,
+    polygon: PolygonInit,
+}
+
+/// The drawing context for a ring.
+pub type DrawingRing<'a> = Drawing<'a, Ring>;
+
+impl Ring {
+    /// Specify the width and height of the outer edge of the **Ring** via a given **radius**.
+    pub fn radius(self, radius: f32) -> Self {
+        let side = radius * 2.0;
+        self.w_h(side, side)
+    }
+
+    /// The radius of the inner edge of the ring.
+    ///
+    /// For elliptical rings, the inner edge is inset from the outer edge by the difference between
+    /// half the width and this radius.
+    pub fn inner_radius(mut self, radius: f32) -> Self {
+        self.inner_radius = Some(radius);
+        self
+    }
+
+    /// The angle at which the ring begins.
+    pub fn start_angle(mut self, radians: f32) -> Self {
+        self.start_angle = radians;
+        self
+    }
+
+    /// The angle at which the ring ends.
+    pub fn end_angle(mut self, radians: f32) -> Self {
+        self.end_angle = radians;
+        self
+    }
+
+    /// The angles at which the ring begins and ends.
+    pub fn angles(self, start: f32, end: f32) -> Self {
+        self.start_angle(start).end_angle(end)
+    }
+
+    /// The number of segments used to draw each edge of the ring.
+    ///
+    /// By default, the number of segments is determined by the tessellation tolerance.
+    pub fn resolution(mut self, resolution: f32) -> Self {
+        self.resolution = Some(resolution);
+        self
+    }
+}
+
+impl<'a> DrawingRing<'a> {
+    /// Specify the width and height of the outer edge of the **Ring** via a given **radius**.
+    pub fn radius(self, radius: f32) -> Self {
+        let side = radius * 2.0;
+        self.w_h(side, side)
+    }
+
+    /// The radius of the inner edge of the ring.
+    pub fn inner_radius(self, radius: f32) -> Self {
+        update_ring(&self.draw, self.index, |ring| {
+            ring.inner_radius = Some(radius)
+        });
+        self
+    }
+
+    /// The angle at which the ring begins.
+    pub fn start_angle(self, radians: f32) -> Self {
+        update_ring(&self.draw, self.index, |ring| ring.start_angle = radians);
+        self
+    }
+
+    /// The angle at which the ring ends.
+    pub fn end_angle(self, radians: f32) -> Self {
+        update_ring(&self.draw, self.index, |ring| ring.end_angle = radians);
+        self
+    }
+
+    /// The angles at which the ring begins and ends.
+    pub fn angles(self, start: f32, end: f32) -> Self {
+        self.start_angle(start).end_angle(end)
+    }
+
+    /// The number of segments used to draw each edge of the ring.
+    pub fn resolution(self, resolution: f32) -> Self {
+        update_ring(&self.draw, self.index, |ring| {
+            ring.resolution = Some(resolution)
+        });
+        self
+    }
+}
+
+// Update the inner `Ring` of the primitive being drawn at `index`.
+fn update_ring(draw: &crate::draw::Draw, index: usize, f: impl FnOnce(&mut Ring)) {
+    crate::draw::drawing::with_primitive(draw, index, |prim| match prim {
+        Primitive::Ring(ring) => f(ring),
+        _ => bevy::log::warn_once!("expected a `Ring` primitive"),
+    })
+}
+
+impl draw::render::RenderPrimitive for Ring {
+    fn render_primitive(self, ctxt: draw::render::RenderContext, mesh: &mut Mesh) {
+        let Ring {
+            dimensions,
+            inner_radius,
+            start_angle,
+            end_angle,
+            resolution,
+            polygon,
+        } = self;
+        let wh = ellipse_wh(&dimensions, "ring");
+        if wh.cmple(Vec2::ZERO).any() || start_angle == end_angle {
+            return;
+        }
+        let inner_radius = inner_radius.unwrap_or(wh.x * 0.25).max(0.0);
+        let thickness = wh.x * 0.5 - inner_radius;
+        let inner_wh = (wh - thickness * 2.0).clamp(Vec2::ZERO, wh);
+
+        let outer = section_points(wh, start_angle, end_angle, resolution);
+        let inner: Vec<_> = section_points(inner_wh, start_angle, end_angle, resolution).collect();
+        let inner = inner.into_iter().rev();
+
+        // A full ring is made of two loops wound in opposite directions so that the inner one
+        // forms a hole, while a section is a single loop along the outer edge and back.
+        let mut builder = lyon::path::Path::builder();
+        if arc::is_full(start_angle, end_angle) {
+            add_loop(&mut builder, outer);
+            add_loop(&mut builder, inner);
+        } else {
+            add_loop(&mut builder, outer.chain(inner));
+        }
+        let path = builder.build();
+
+        polygon::render_events_themed(
+            polygon.opts,
+            || (&path).into_iter(),
+            ctxt,
+            &draw::theme::Primitive::Ring,
+            mesh,
+        );
+    }
+}
+
+// Add a closed sub-path through the given points.
+fn add_loop(builder: &mut lyon::path::path::Builder, points: impl IntoIterator<Item = Vec2>) {
+    let mut points = points.into_iter().map(|p| lyon::math::point(p.x, p.y));
+    let Some(first) = points.next() else {
+        return;
+    };
+    builder.begin(first);
+    for p in points {
+        builder.line_to(p);
+    }
+    builder.end(true);
+}
+
+impl Default for Ring {
+    fn default() -> Self {
+        Ring {
+            dimensions: Default::default(),
+            inner_radius: None,
+            start_angle: 0.0,
+            end_angle: TAU,
+            resolution: None,
+            polygon: Default::default(),
+        }
+    }
+}
+
+impl SetOrientation for Ring {
+    fn properties(&mut self) -> &mut orientation::Properties {
+        SetOrientation::properties(&mut self.polygon)
+    }
+}
+
+impl SetPosition for Ring {
+    fn properties(&mut self) -> &mut position::Properties {
+        SetPosition::properties(&mut self.polygon)
+    }
+}
+
+impl SetDimensions for Ring {
+    fn properties(&mut self) -> &mut dimension::Properties {
+        SetDimensions::properties(&mut self.dimensions)
+    }
+}
+
+impl SetColor for Ring {
+    fn color_mut(&mut self) -> &mut Option<Color> {
+        SetColor::color_mut(&mut self.polygon)
+    }
+}
+
+impl SetStroke for Ring {
+    fn stroke_options_mut(&mut self) -> &mut StrokeOptions {
+        SetStroke::stroke_options_mut(&mut self.polygon)
+    }
+}
+
+impl SetDashes for Ring {
+    fn dashes_mut(&mut self) -> &mut Dashes {
+        SetDashes::dashes_mut(&mut self.polygon)
+    }
+}
+
+impl SetGradient for Ring {
+    fn gradient_mut(&mut self) -> &mut Gradient {
+        SetGradient::gradient_mut(&mut self.polygon)
+    }
+}
+
+impl SetPolygon for Ring {
+    fn polygon_options_mut(&mut self) -> &mut PolygonOptions {
+        SetPolygon::polygon_options_mut(&mut self.polygon)
+    }
+}
+
+// Primitive conversion.
+
+impl From<Ring> for Primitive {
+    fn from(prim: Ring) -> Self {
+        Primitive::Ring(prim)
+    }
+}
@@ -0,0 +1,128 @@
+//! Items shared between the 3D solid primitives.
+
+use bevy::prelude::*;
+
+use crate::draw;
+use crate::draw::mesh::MeshExt;
+use crate::draw::properties::spatial::{orientation, position};
+
+/// The vertices and indices of a solid in its local space, prior to being transformed into the
+/// mesh.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Geometry {
+    points: Vec<Vec3>,
+    normals: Vec<Vec3>,
+    tex_coords: Vec<Vec2>,
+    indices: Vec<u32>,
+}
+
+impl Geometry {
+    // Push a single vertex, returning its index.
+    fn vertex(&mut self, point: Vec3, normal: Vec3, tex_coords: Vec2) -> u32 {
+        let index = self.points.len() as u32;
+        self.points.push(point);
+        self.normals.push(normal);
+        self.tex_coords.push(tex_coords);
+        index
+    }
+
+    /// Add a surface made of a grid of `cols` by `rows` quads.
+    ///
+    /// `vertex` is called with each `u` and `v` from `0.0` to `1.0` and must produce the point and
+    /// normal at that position. The cross product of the partial derivatives along `u` and `v`
+    /// must face outwards, in which case the triangles wind counter-clockwise when viewed from
+    /// the outside. The `u` and `v` are also used as the texture coordinates.
+    pub fn grid<F>(&mut self, cols: u32, rows: u32, vertex: F)
+    where
+        F: Fn(f32, f32) -> (Vec3, Vec3),
+    {
+        let start = self.points.len() as u32;
+        for row in 0..=rows {
+            let v = row as f32 / rows as f32;
+            for col in 0..=cols {
+                let u = col as f32 / cols as f32;
+                let (point, normal) = vertex(u, v);
+                self.vertex(point, normal, Vec2::new(u, v));
+            }
+        }
+        let stride = cols + 1;
+        for row in 0..rows {
+            for col in 0..cols {
+                let a = start + row * stride + col;
+                let (b, c, d) = (a + 1, a + stride, a + stride + 1);
+                self.indices.extend([a, b, d, a, d, c]);
+            }
+        }
+    }
+
+    /// Add a flat elliptical disc facing up or down the *y* axis, as used to cap cylinders and
+    /// cones.
+    pub fn disc(&mut self, y: f32, radii: Vec2, up: bool, resolution: u32) {
+        let normal = if up { Vec3::Y } else { Vec3::NEG_Y };
+        let centre = self.vertex(Vec3::new(0.0, y, 0.0), normal, Vec2::splat(0.5));
+        for i in 0..=resolution {
+            let (sin, cos) = (std::f32::consts::TAU * i as f32 / resolution as f32).sin_cos();
+            let point = Vec3::new(cos * radii.x, y, -sin * radii.y);
+            let tex_coords = Vec2::new(0.5 + cos * 0.5, 0.5 - sin * 0.5);
+            self.vertex(point, normal, tex_coords);
+        }
+        for i in 0..resolution {
+            let (a, b) = (centre + 1 + i, centre + 2 + i);
+            let tri = if up { [centre, a, b] } else { [centre, b, a] };
+            self.indices.extend(tri);
+        }
+    }
+
+    /// Transform the geometry by the given position, orientation and the drawing's transform,
+    /// and append it to the mesh.
+    pub fn render(
+        self,
+        position: position::Properties,
+        orientation: orientation::Properties,
+        color: Option<Color>,
+        ctxt: draw::render::RenderContext,
+        theme_primitive: &draw::theme::Primitive,
+        mesh: &mut Mesh,
+    ) {
+        let Geometry {
+            points,
+            normals,
+            tex_coords,
+            indices,
+        } = self;
+        let transform = *ctxt.transform * position.transform() * orientation.transform();
+        // Normals are transformed by the inverse transpose so that they remain perpendicular to
+        // the surface under non-uniform scaling.
+        let linear = Mat3::from_mat4(transform);
+        let normal_matrix = if linear.determinant() != 0.0 {
+            linear.inverse().transpose()
+        } else {
+            linear
+        };
+        let color = color
+            .unwrap_or_else(|| ctxt.theme.fill(theme_primitive))
+            .to_linear()
+            .to_f32_array();
+
+        let start = mesh.count_vertices() as u32;
+        for ((point, normal), tex_coords) in points.into_iter().zip(normals).zip(tex_coords) {
+            let point = transform.transform_point3(point);
+            let normal = (normal_matrix * normal).normalize_or_zero();
+            mesh.points_mut().push(point.to_array());
+            mesh.colors_mut().push(color);
+            mesh.tex_coords_mut().push(tex_coords.to_array());
+            mesh.normals_mut().push(normal.to_array());
+        }
+        for index in indices {
+            mesh.push_index(start + index);
+        }
+    }
+}
+
+/// The width, height and depth of a solid, defaulting to `100.0` along each axis.
+pub(crate) fn whd(dimensions: &draw::properties::spatial::dimension::Properties) -> Vec3 {
+    let w = dimensions.x.map(f32::abs).unwrap_or(100.0);
+    let h = dimensions.y.map(f32::abs).unwrap_or(100.0);
+    let d = dimensions.z.map(f32::abs).unwrap_or(100.0);
+    Vec3::new(w, h, d)
+}
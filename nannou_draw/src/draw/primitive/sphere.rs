@@ -0,0 +1,155 @@
+use std::f32::consts::{FRAC_PI_2, PI, TAU};
+
+use bevy::prelude::*;
+
+use crate::draw::primitive::Primitive;
+use crate::draw::primitive::solid::{self, Geometry};
+use crate::draw::properties::spatial::{self, dimension, orientation, position};
+use crate::draw::properties::{SetColor, SetDimensions, SetOrientation, SetPosition};
+use crate::draw::{self, Drawing};
+
+/// Properties related to drawing a solid **Sphere**, or an ellipsoid if its dimensions differ.
+///
+/// The poles lie along the *y* axis. Texture coordinates wrap once around the equator and span
+/// from the bottom pole to the top.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sphere {
+    spatial: spatial::Properties,
+    color: Option<Color>,
+    resolution: u32,
+    rings: Option<u32>,
+}
+
+/// The drawing context for a sphere.
+pub type DrawingSphere<'a> = Drawing<'a, Sphere>;
+
+impl Sphere {
+    /// Specify the width, height and depth of the **Sphere** via a given **radius**.
+    pub fn radius(self, radius: f32) -> Self {
+        let side = radius * 2.0;
+        self.w_h_d(side, side, side)
+    }
+
+    /// The number of segments around the equator of the **Sphere**, i.e. its number of
+    /// meridians.
+    ///
+    /// Clamped to a minimum of `3`. Defaults to `32`.
+    pub fn resolution(mut self, resolution: u32) -> Self {
+        self.resolution = resolution;
+        self
+    }
+
+    /// The number of rings from pole to pole, clamped to a minimum of `2`.
+    ///
+    /// Defaults to half of the resolution, so that the faces near the equator are roughly square.
+    pub fn rings(mut self, rings: u32) -> Self {
+        self.rings = Some(rings);
+        self
+    }
+}
+
+impl<'a> DrawingSphere<'a> {
+    /// Specify the width, height and depth of the **Sphere** via a given **radius**.
+    pub fn radius(self, radius: f32) -> Self {
+        let side = radius * 2.0;
+        self.w_h_d(side, side, side)
+    }
+
+    /// The number of segments around the equator of the sphere.
+    pub fn resolution(self, resolution: u32) -> Self {
+        update_sphere(&self.draw, self.index, |sphere| {
+            sphere.resolution = resolution
+        });
+        self
+    }
+
+    /// The number of rings from pole to pole.
+    pub fn rings(self, rings: u32) -> Self {
+        update_sphere(&self.draw, self.index, |sphere| sphere.rings = Some(rings));
+        self
+    }
+}
+
+// Update the inner `Sphere` of the primitive being drawn at `index`.
+fn update_sphere(draw: &crate::draw::Draw, index: usize, f: impl FnOnce(&mut Sphere)) {
+    crate::draw::drawing::with_primitive(draw, index, |prim| match prim {
+        Primitive::Sphere(sphere) => f(sphere),
+        _ => bevy::log::warn_once!("expected a `Sphere` primitive"),
+    })
+}
+
+impl draw::render::RenderPrimitive for Sphere {
+    fn render_primitive(self, ctxt: draw::render::RenderContext, mesh: &mut Mesh) {
+        let Sphere {
+            spatial,
+            color,
+            resolution,
+            rings,
+        } = self;
+        let radii = solid::whd(&spatial.dimensions) * 0.5;
+        if radii.cmple(Vec3::ZERO).any() {
+            return;
+        }
+        let resolution = resolution.max(3);
+        let rings = rings.unwrap_or(resolution / 2).max(2);
+        let mut geometry = Geometry::default();
+        geometry.grid(resolution, rings, |u, v| {
+            let (sin_lon, cos_lon) = (u * TAU).sin_cos();
+            let (sin_lat, cos_lat) = (v * PI - FRAC_PI_2).sin_cos();
+            let unit = Vec3::new(cos_lat * cos_lon, sin_lat, -cos_lat * sin_lon);
+            (unit * radii, (unit / radii).normalize())
+        });
+        geometry.render(
+            spatial.position,
+            spatial.orientation,
+            color,
+            ctxt,
+            &draw::theme::Primitive::Sphere,
+            mesh,
+        );
+    }
+}
+
+impl Default for Sphere {
+    fn default() -> Self {
+        Sphere {
+            spatial: Default::default(),
+            color: None,
+            resolution: 32,
+            rings: None,
+        }
+    }
+}
+
+impl SetOrientation for Sphere {
+    fn properties(&mut self) -> &mut orientation::Properties {
+        SetOrientation::properties(&mut self.spatial)
+    }
+}
+
+impl SetPosition for Sphere {
+    fn properties(&mut self) -> &mut position::Properties {
+        SetPosition::properties(&mut self.spatial)
+    }
+}
+
+impl SetDimensions for Sphere {
+    fn properties(&mut self) -> &mut dimension::Properties {
+        SetDimensions::properties(&mut self.spatial)
+    }
+}
+
+impl SetColor for Sphere {
+    fn color_mut(&mut self) -> &mut Option<Color> {
+        SetColor::color_mut(&mut self.color)
+    }
+}
+
+// Primitive conversion.
+
+impl From<Sphere> for Primitive {
+    fn from(prim: Sphere) -> Self {
+        Primitive::Sphere(prim)
+    }
+}
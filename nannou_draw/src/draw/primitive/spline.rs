@@ -0,0 +1,169 @@
+use bevy::prelude::*;
+use lyon::path::PathEvent;
+use lyon::tessellation::StrokeOptions;
+
+use crate::draw::drawing::{self, DrawingContext};
+use crate::draw::primitive::Primitive;
+use crate::draw::primitive::curve::cubic_events;
+use crate::draw::primitive::polygon::{
+    DrawingPolygon, Polygon, PolygonInit, PolygonOptions, SetPolygon,
+};
+use crate::draw::properties::dash::Dashes;
+use crate::draw::properties::gradient::Gradient;
+use crate::draw::properties::spatial::{orientation, position};
+use crate::draw::properties::{
+    SetColor, SetDashes, SetGradient, SetOrientation, SetPosition, SetStroke,
+};
+use crate::draw::{self, Drawing};
+
+/// A smooth uniform cubic B-spline shaped by a sequence of control points.
+///
+/// Unlike a `Curve`, the spline only passes through its first and last points, and is pulled
+/// towards the others. Like a polygon, the spline is filled by default. Use `no_fill` and `stroke`
+/// to draw its outline instead.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Spline {
+    polygon: PolygonInit,
+    closed: bool,
+}
+
+/// The drawing context for a spline, prior to submitting its points.
+pub type DrawingSpline<'a> = Drawing<'a, Spline>;
+
+impl Spline {
+    /// Join the last point back to the first so that the spline forms a smooth loop.
+    ///
+    /// Closed splines pass through none of their points.
+    pub fn closed(mut self) -> Self {
+        self.closed = true;
+        self
+    }
+
+    /// Submit the control points of the spline.
+    pub fn points<I>(self, ctxt: DrawingContext, points: I) -> Polygon
+    where
+        I: IntoIterator,
+        I::Item: Into<Vec2>,
+    {
+        let points: Vec<Vec2> = points.into_iter().map(Into::into).collect();
+        let events = b_spline(&points, self.closed);
+        self.polygon.events(ctxt, events)
+    }
+}
+
+impl<'a> DrawingSpline<'a> {
+    /// Join the last point back to the first so that the spline forms a smooth loop.
+    ///
+    /// Closed splines pass through none of their points.
+    pub fn closed(self) -> Self {
+        update_spline(&self.draw, self.index, |spline| spline.closed = true);
+        self
+    }
+
+    /// Submit the control points of the spline.
+    pub fn points<I>(self, points: I) -> DrawingPolygon<'a>
+    where
+        I: IntoIterator,
+        I::Item: Into<Vec2>,
+    {
+        let points: Vec<Vec2> = points.into_iter().map(Into::into).collect();
+        drawing::with_primitive_ctxt(&self.draw, self.index, |prim, ctxt| match prim {
+            Primitive::Spline(s) => Primitive::Polygon(s.points(ctxt, points)),
+            other => {
+                bevy::log::warn_once!("expected a `Spline` primitive");
+                other
+            }
+        });
+        self.transition()
+    }
+}
+
+// Update the inner `Spline` of the primitive being drawn at `index`.
+fn update_spline(draw: &draw::Draw, index: usize, f: impl FnOnce(&mut Spline)) {
+    drawing::with_primitive(draw, index, |prim| match prim {
+        Primitive::Spline(spline) => f(spline),
+        _ => bevy::log::warn_once!("expected a `Spline` primitive"),
+    })
+}
+
+/// The path events for a uniform cubic B-spline with the given control points.
+///
+/// Open splines begin and end at their first and last points, while closed splines form a loop
+/// through none of them.
+pub fn b_spline(points: &[Vec2], closed: bool) -> Vec<PathEvent> {
+    let n = points.len();
+    if n < 2 {
+        return vec![];
+    }
+    // Repeating the end points of open splines clamps the spline to them.
+    let padded: Vec<Vec2> = if closed {
+        (0..n + 3).map(|i| points[(i + n - 1) % n]).collect()
+    } else {
+        let (first, last) = (points[0], points[n - 1]);
+        [first, first]
+            .into_iter()
+            .chain(points.iter().copied())
+            .chain([last, last])
+            .collect()
+    };
+    // Each window of four control points describes one cubic bezier segment.
+    let start = (padded[0] + padded[1] * 4.0 + padded[2]) / 6.0;
+    let cubics = padded.windows(4).map(|w| {
+        let ctrl1 = (w[1] * 2.0 + w[2]) / 3.0;
+        let ctrl2 = (w[1] + w[2] * 2.0) / 3.0;
+        let to = (w[1] + w[2] * 4.0 + w[3]) / 6.0;
+        [ctrl1, ctrl2, to]
+    });
+    cubic_events(start, cubics, closed)
+}
+
+impl SetOrientation for Spline {
+    fn properties(&mut self) -> &mut orientation::Properties {
+        SetOrientation::properties(&mut self.polygon)
+    }
+}
+
+impl SetPosition for Spline {
+    fn properties(&mut self) -> &mut position::Properties {
+        SetPosition::properties(&mut self.polygon)
+    }
+}
+
+impl SetColor for Spline {
+    fn color_mut(&mut self) -> &mut Option<Color> {
+        SetColor::color_mut(&mut self.polygon)
+    }
+}
+
+impl SetStroke for Spline {
+    fn stroke_options_mut(&mut self) -> &mut StrokeOptions {
+        SetStroke::stroke_options_mut(&mut self.polygon)
+    }
+}
+
+impl SetDashes for Spline {
+    fn dashes_mut(&mut self) -> &mut Dashes {
+        SetDashes::dashes_mut(&mut self.polygon)
+    }
+}
+
+impl SetGradient for Spline {
+    fn gradient_mut(&mut self) -> &mut Gradient {
+        SetGradient::gradient_mut(&mut self.polygon)
+    }
+}
+
+impl SetPolygon for Spline {
+    fn polygon_options_mut(&mut self) -> &mut PolygonOptions {
+        SetPolygon::polygon_options_mut(&mut self.polygon)
+    }
+}
+
+// Primitive conversion.
+
+impl From<Spline> for Primitive {
+    fn from(prim: Spline) -> Self {
+        Primitive::Spline(prim)
+    }
+}
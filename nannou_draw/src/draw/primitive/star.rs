@@ -0,0 +1,211 @@
+use std::f32::consts::{FRAC_PI_2, PI};
+
+use bevy::prelude::*;
+use lyon::tessellation::StrokeOptions;
+
+use nannou_core::geom;
+
+use crate::draw::primitive::Primitive;
+use crate::draw::primitive::arc::ellipse_wh;
+use crate::draw::primitive::ngon;
+use crate::draw::primitive::polygon::{PolygonInit, PolygonOptions, SetPolygon};
+use crate::draw::properties::dash::Dashes;
+use crate::draw::properties::gradient::Gradient;
+use crate::draw::properties::spatial::{dimension, orientation, position};
+use crate::draw::properties::tex_coords::{self, SetTexCoords};
+use crate::draw::properties::{
+    SetColor, SetDashes, SetDimensions, SetGradient, SetOrientation, SetPosition, SetStroke,
+};
+use crate::draw::{self, Drawing};
+
+/// Properties related to drawing a **Star**.
+///
+/// The outer points lie on the ellipse described by the dimensions, with the first pointing
+/// straight up. By default the star has five points and its inner radius is half of its outer
+/// radius.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Star {
+    dimensions: dimension::Properties,
+    #[cfg_attr(feature = "serde", serde(with = "crate::record::option_geom_rect"))]
+    tex_coords: Option<geom::Rect>,
+    points: usize,
+    inner_radius: Option<f32>,
+    polygon: PolygonInit,
+}
+
+/// The drawing context for a star.
+pub type DrawingStar<'a> = Drawing<'a, Star>;
+
+impl Star {
+    /// Specify the width and height of the **Star** via the radius of its outer points.
+    pub fn radius(self, radius: f32) -> Self {
+        let side = radius * 2.0;
+        self.w_h(side, side)
+    }
+
+    /// The number of points, clamped to a minimum of `2`.
+    pub fn points(mut self, points: usize) -> Self {
+        self.points = points;
+        self
+    }
+
+    /// The radius of the inner vertices between the points.
+    ///
+    /// For elliptical stars, the inner vertices are scaled by the ratio of this radius to half
+    /// the width.
+    pub fn inner_radius(mut self, radius: f32) -> Self {
+        self.inner_radius = Some(radius);
+        self
+    }
+}
+
+impl<'a> DrawingStar<'a> {
+    /// Specify the width and height of the **Star** via the radius of its outer points.
+    pub fn radius(self, radius: f32) -> Self {
+        let side = radius * 2.0;
+        self.w_h(side, side)
+    }
+
+    /// The number of points, clamped to a minimum of `2`.
+    pub fn points(self, points: usize) -> Self {
+        update_star(&self.draw, self.index, |star| star.points = points);
+        self
+    }
+
+    /// The radius of the inner vertices between the points.
+    pub fn inner_radius(self, radius: f32) -> Self {
+        update_star(&self.draw, self.index, |star| {
+            star.inner_radius = Some(radius)
+        });
+        self
+    }
+
+    /// The area of the texture mapped onto the bounds of the **Star**.
+    pub fn area(self, area: geom::Rect) -> Self {
+        tex_coords::set_tex_coords_area(&self.draw, self.index, area);
+        self
+    }
+}
+
+// Update the inner `Star` of the primitive being drawn at `index`.
+fn update_star(draw: &crate::draw::Draw, index: usize, f: impl FnOnce(&mut Star)) {
+    crate::draw::drawing::with_primitive(draw, index, |prim| match prim {
+        Primitive::Star(star) => f(star),
+        _ => bevy::log::warn_once!("expected a `Star` primitive"),
+    })
+}
+
+/// The vertices of a star with the given number of points inscribed within an ellipse of the
+/// given size, alternating between the outer points and the inner vertices scaled by `inner`.
+pub(crate) fn vertices(wh: Vec2, points: usize, inner: f32) -> impl Clone + Iterator<Item = Vec2> {
+    let radii = wh * 0.5;
+    (0..points * 2).map(move |i| {
+        let angle = FRAC_PI_2 + PI * i as f32 / points as f32;
+        let scale = if i % 2 == 0 { 1.0 } else { inner };
+        Vec2::from_angle(angle) * radii * scale
+    })
+}
+
+impl draw::render::RenderPrimitive for Star {
+    fn render_primitive(self, ctxt: draw::render::RenderContext, mesh: &mut Mesh) {
+        let Star {
+            dimensions,
+            tex_coords,
+            points,
+            inner_radius,
+            polygon,
+        } = self;
+        let wh = ellipse_wh(&dimensions, "star");
+        if wh.cmple(Vec2::ZERO).any() {
+            return;
+        }
+        let inner = inner_radius
+            .map(|radius| (radius / (wh.x * 0.5)).max(0.0))
+            .unwrap_or(0.5);
+        let points = vertices(wh, points.max(2), inner);
+        ngon::render_points(
+            points,
+            wh,
+            tex_coords,
+            polygon,
+            ctxt,
+            &draw::theme::Primitive::Star,
+            mesh,
+        );
+    }
+}
+
+impl Default for Star {
+    fn default() -> Self {
+        Star {
+            dimensions: Default::default(),
+            tex_coords: None,
+            points: 5,
+            inner_radius: None,
+            polygon: Default::default(),
+        }
+    }
+}
+
+impl SetOrientation for Star {
+    fn properties(&mut self) -> &mut orientation::Properties {
+        SetOrientation::properties(&mut self.polygon)
+    }
+}
+
+impl SetPosition for Star {
+    fn properties(&mut self) -> &mut position::Properties {
+        SetPosition::properties(&mut self.polygon)
+    }
+}
+
+impl SetDimensions for Star {
+    fn properties(&mut self) -> &mut dimension::Properties {
+        SetDimensions::properties(&mut self.dimensions)
+    }
+}
+
+impl SetColor for Star {
+    fn color_mut(&mut self) -> &mut Option<Color> {
+        SetColor::color_mut(&mut self.polygon)
+    }
+}
+
+impl SetStroke for Star {
+    fn stroke_options_mut(&mut self) -> &mut StrokeOptions {
+        SetStroke::stroke_options_mut(&mut self.polygon)
+    }
+}
+
+impl SetDashes for Star {
+    fn dashes_mut(&mut self) -> &mut Dashes {
+        SetDashes::dashes_mut(&mut self.polygon)
+    }
+}
+
+impl SetGradient for Star {
+    fn gradient_mut(&mut self) -> &mut Gradient {
+        SetGradient::gradient_mut(&mut self.polygon)
+    }
+}
+
+impl SetPolygon for Star {
+    fn polygon_options_mut(&mut self) -> &mut PolygonOptions {
+        SetPolygon::polygon_options_mut(&mut self.polygon)
+    }
+}
+
+impl SetTexCoords for Star {
+    fn tex_coords_mut(&mut self) -> &mut Option<geom::Rect> {
+        SetTexCoords::tex_coords_mut(&mut self.tex_coords)
+    }
+}
+
+// Primitive conversion.
+
+impl From<Star> for Primitive {
+    fn from(prim: Star) -> Self {
+        Primitive::Star(prim)
+    }
+}
@@ -0,0 +1,192 @@
+use std::sync::Arc;
+
+use bevy::prelude::*;
+use lyon::tessellation::FillOptions;
+
+use crate::draw::primitive::{Primitive, path};
+use crate::draw::properties::spatial::{self, dimension, orientation, position};
+use crate::draw::properties::{SetColor, SetDimensions, SetOrientation, SetPosition};
+use crate::draw::{self, Drawing};
+use crate::svg::{Svg, SvgData};
+
+/// Properties related to drawing a loaded **Svg** document.
+///
+/// The document is centred on its position. By default it is drawn at the size specified by the
+/// document itself. If only one of the width or height is specified, the other is scaled to
+/// preserve the document's aspect ratio.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrimitiveSvg {
+    spatial: spatial::Properties,
+    color: Option<Color>,
+    // Only the resolved document is serialized.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    handle: Option<Handle<Svg>>,
+    data: Option<Arc<SvgData>>,
+}
+
+/// The drawing context for an **Svg**.
+pub type DrawingSvg<'a> = Drawing<'a, PrimitiveSvg>;
+
+impl PrimitiveSvg {
+    /// Draw the SVG asset with the given handle.
+    ///
+    /// The paths of the asset are looked up when the draw is rendered. Nothing is drawn if the
+    /// asset has not yet finished loading.
+    pub fn new(handle: &Handle<Svg>) -> Self {
+        PrimitiveSvg {
+            spatial: Default::default(),
+            color: None,
+            handle: Some(handle.clone()),
+            data: None,
+        }
+    }
+
+    /// The handle of the SVG asset to be drawn, if the document was not provided directly.
+    pub fn handle(&self) -> Option<&Handle<Svg>> {
+        self.handle.as_ref()
+    }
+
+    /// Whether or not the paths of the document are available for rendering.
+    pub fn is_resolved(&self) -> bool {
+        self.data.is_some()
+    }
+
+    /// Look up the document paths within the given SVG assets.
+    ///
+    /// This is called by the renderer before the primitive is rendered.
+    pub(crate) fn resolve(&mut self, svgs: &Assets<Svg>) {
+        if self.data.is_some() {
+            return;
+        }
+        if let Some(svg) = self.handle.as_ref().and_then(|h| svgs.get(h)) {
+            self.data = Some(svg.data().clone());
+        }
+    }
+}
+
+impl From<&Handle<Svg>> for PrimitiveSvg {
+    fn from(handle: &Handle<Svg>) -> Self {
+        PrimitiveSvg::new(handle)
+    }
+}
+
+impl From<&Svg> for PrimitiveSvg {
+    fn from(svg: &Svg) -> Self {
+        PrimitiveSvg {
+            spatial: Default::default(),
+            color: None,
+            handle: None,
+            data: Some(svg.data().clone()),
+        }
+    }
+}
+
+impl draw::render::RenderPrimitive for PrimitiveSvg {
+    fn render_primitive(self, ctxt: draw::render::RenderContext, mesh: &mut Mesh) {
+        let PrimitiveSvg {
+            spatial,
+            color,
+            data,
+            ..
+        } = self;
+        let Some(data) = data else {
+            return;
+        };
+
+        // Scale the document to the specified dimensions.
+        let size = data.size;
+        let scale = match (spatial.dimensions.x, spatial.dimensions.y) {
+            (None, None) => Vec2::ONE,
+            (Some(w), None) => Vec2::splat(w / size.x),
+            (None, Some(h)) => Vec2::splat(h / size.y),
+            (Some(w), Some(h)) => Vec2::new(w / size.x, h / size.y),
+        };
+        let scale = if scale.is_finite() { scale } else { Vec2::ONE };
+
+        // Determine the transform to apply to all points.
+        let global_transform = *ctxt.transform;
+        let local_transform = spatial.position.transform()
+            * spatial.orientation.transform()
+            * Mat4::from_scale(scale.extend(1.0));
+        let transform = global_transform * local_transform;
+
+        let draw::render::RenderContext {
+            theme,
+            fill_tessellator,
+            stroke_tessellator,
+            mut vector_paths,
+            ..
+        } = ctxt;
+        let theme_prim = draw::theme::Primitive::Path;
+        for svg_path in &data.paths {
+            if let Some(fill) = &svg_path.fill {
+                let options = FillOptions::default().with_fill_rule(fill.rule);
+                path::render_path_events(
+                    svg_path.events.iter().copied(),
+                    Some(color.unwrap_or(fill.color)),
+                    fill.gradient.as_ref().filter(|_| color.is_none()),
+                    transform,
+                    path::Options::Fill(options),
+                    None,
+                    theme,
+                    &theme_prim,
+                    fill_tessellator,
+                    stroke_tessellator,
+                    vector_paths.as_deref_mut(),
+                    mesh,
+                );
+            }
+            if let Some(stroke) = &svg_path.stroke {
+                path::render_path_events(
+                    svg_path.events.iter().copied(),
+                    Some(color.unwrap_or(stroke.color)),
+                    stroke.gradient.as_ref().filter(|_| color.is_none()),
+                    transform,
+                    path::Options::Stroke(stroke.options),
+                    None,
+                    theme,
+                    &theme_prim,
+                    fill_tessellator,
+                    stroke_tessellator,
+                    vector_paths.as_deref_mut(),
+                    mesh,
+                );
+            }
+        }
+    }
+}
+
+impl SetOrientation for PrimitiveSvg {
+    fn properties(&mut self) -> &mut orientation::Properties {
+        SetOrientation::properties(&mut self.spatial)
+    }
+}
+
+impl SetPosition for PrimitiveSvg {
+    fn properties(&mut self) -> &mut position::Properties {
+        SetPosition::properties(&mut self.spatial)
+    }
+}
+
+impl SetDimensions for PrimitiveSvg {
+    fn properties(&mut self) -> &mut dimension::Properties {
+        SetDimensions::properties(&mut self.spatial)
+    }
+}
+
+/// Specifying a color overrides the fill and stroke colors and gradients of all paths within the
+/// document.
+impl SetColor for PrimitiveSvg {
+    fn color_mut(&mut self) -> &mut Option<Color> {
+        SetColor::color_mut(&mut self.color)
+    }
+}
+
+// Primitive conversion.
+
+impl From<PrimitiveSvg> for Primitive {
+    fn from(prim: PrimitiveSvg) -> Self {
+        Primitive::Svg(prim)
+    }
+}
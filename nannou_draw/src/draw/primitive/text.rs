@@ -2,30 +2,70 @@ use core::hash::BuildHasher;
 
 use crate::draw::drawing::DrawingContext;
 use crate::draw::mesh::MeshExt;
-use crate::draw::primitive::Primitive;
+use crate::draw::primitive::{Primitive, path};
 use crate::draw::properties::spatial::{self, dimension, orientation, position};
-use crate::draw::properties::{SetColor, SetDimensions, SetOrientation, SetPosition};
+use crate::draw::properties::{SetColor, SetDimensions, SetOrientation, SetPosition, SetStroke};
+use crate::draw::render::VectorPath;
 use crate::draw::{self, Drawing};
-use crate::text::{self, Align, FontSize, Justify, Layout, Scalar, Wrap};
+use crate::text::{self, Align, FontFeature, FontSize, FontStyle, Justify, Layout, Scalar, Wrap};
 use bevy::platform::hash::FixedHasher;
 use bevy::prelude::*;
 use bevy::text::{
     FontAtlasKey, FontAtlasSet, FontHinting, FontSmoothing, GlyphCacheKey, ScaleCx,
     add_glyph_to_atlas, get_glyph_atlas_info,
 };
+use lyon::path::PathEvent;
+use lyon::tessellation::{FillOptions, FillTessellator, StrokeOptions, StrokeTessellator};
 use swash::FontRef;
 
 /// Properties related to drawing the **Text** primitive.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Text {
     spatial: spatial::Properties,
     style: Style,
     // The byte range into the `Draw` context's text buffer.
     text: std::ops::Range<usize>,
+    // Styled spans of the text, with byte ranges relative to the start of `text`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    spans: Vec<text::Span>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    path: TextPath,
+    #[cfg_attr(feature = "serde", serde(default))]
+    mode: TextMode,
+    // The options used to stroke glyph outlines in `TextMode::Stroke`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    stroke: StrokeOptions,
+}
+
+/// How the glyphs of a **Text** primitive are rendered.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TextMode {
+    /// Glyphs are rasterised into a font atlas and drawn as textured quads.
+    ///
+    /// This is the most efficient mode and is best suited to text drawn at its natural size.
+    #[default]
+    Atlas,
+    /// The vector outline of each glyph is filled, remaining sharp when scaled or rotated in 3D.
+    Outline,
+    /// The vector outline of each glyph is stroked, as described by the text's stroke options.
+    Stroke,
+}
+
+// The path along which text is laid out, if any.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct TextPath {
+    // The range of the path's events within the `Draw` context's path event buffer.
+    events: Option<std::ops::Range<usize>>,
+    offset: Scalar,
+    overflow: text::path::Overflow,
 }
 
 /// Styling properties for the **Text** primitive.
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Style {
     pub color: Option<Color>,
     pub glyph_colors: Vec<Color>, // Overrides `color` if non-empty.
@@ -48,6 +88,36 @@ impl Text {
             spatial,
             style,
             text,
+            spans: vec![],
+            path: Default::default(),
+            mode: Default::default(),
+            stroke: Default::default(),
+        }
+    }
+
+    // Append the given text as a span with the given style.
+    fn push_span(&mut self, ctxt: DrawingContext, s: &str, style: text::SpanStyle) {
+        let buffer = ctxt.text_buffer;
+        // Keep the text contiguous, moving it to the end of the buffer if other text has been
+        // pushed since.
+        if self.text.end != buffer.len() {
+            let existing = buffer[self.text.clone()].to_string();
+            let start = buffer.len();
+            buffer.push_str(&existing);
+            self.text = start..buffer.len();
+        }
+        let start = self.text.len();
+        buffer.push_str(s);
+        self.text.end = buffer.len();
+        let range = start..self.text.len();
+        self.spans.push(text::Span { range, style });
+    }
+
+    #[cfg(feature = "serde")]
+    pub(crate) fn offset_buffers(&mut self, offsets: &crate::record::BufferOffsets) {
+        self.text = self.text.start + offsets.text..self.text.end + offsets.text;
+        if let Some(events) = self.path.events.as_mut() {
+            *events = events.start + offsets.path_events..events.end + offsets.path_events;
         }
     }
 
@@ -135,6 +205,61 @@ impl Text {
         self.map_layout(|l| l.align_bottom())
     }
 
+    /// The weight of the font, from `100.0` (thin) to `900.0` (black).
+    pub fn font_weight(self, weight: f32) -> Self {
+        self.map_layout(|l| l.font_weight(weight))
+    }
+
+    /// Short-hand for a font weight of `700.0`.
+    pub fn bold(self) -> Self {
+        self.map_layout(|l| l.bold())
+    }
+
+    /// Whether the font should be upright, italic or oblique.
+    pub fn font_style(self, style: FontStyle) -> Self {
+        self.map_layout(|l| l.font_style(style))
+    }
+
+    /// Use the italic style of the font.
+    pub fn italic(self) -> Self {
+        self.map_layout(|l| l.italic())
+    }
+
+    /// The width of the font as a ratio of its normal width, e.g. `0.75` for a condensed face.
+    pub fn font_stretch(self, ratio: f32) -> Self {
+        self.map_layout(|l| l.font_stretch(ratio))
+    }
+
+    /// Additional space between each character.
+    pub fn letter_spacing(self, spacing: Scalar) -> Self {
+        self.map_layout(|l| l.letter_spacing(spacing))
+    }
+
+    /// Additional space between each word.
+    pub fn word_spacing(self, spacing: Scalar) -> Self {
+        self.map_layout(|l| l.word_spacing(spacing))
+    }
+
+    /// Enable, disable or select an alternative for the given OpenType feature.
+    pub fn font_feature(self, feature: FontFeature) -> Self {
+        self.map_layout(|l| l.font_feature(feature))
+    }
+
+    /// Enable or disable standard ligatures, e.g. "fi".
+    pub fn ligatures(self, enabled: bool) -> Self {
+        self.map_layout(|l| l.ligatures(enabled))
+    }
+
+    /// Use numerals of equal width, e.g. so that columns of numbers line up.
+    pub fn tabular_numerals(self) -> Self {
+        self.map_layout(|l| l.tabular_numerals())
+    }
+
+    /// Display lowercase letters as small capitals.
+    pub fn small_caps(self) -> Self {
+        self.map_layout(|l| l.small_caps())
+    }
+
     /// Set all the parameters via an existing `Layout`
     pub fn layout(self, layout: &Layout) -> Self {
         self.map_layout(|l| l.layout(layout))
@@ -154,6 +279,88 @@ impl Text {
 }
 
 impl<'a> DrawingText<'a> {
+    /// Append a span of text, styled by the given function.
+    ///
+    /// Properties not set on the span are inherited from the whole text, e.g.
+    /// `draw.rich_text().span("bold", |s| s.bold()).span(" and regular", |s| s)`.
+    pub fn span<F>(self, s: &str, style: F) -> Self
+    where
+        F: FnOnce(text::SpanStyle) -> text::SpanStyle,
+    {
+        push_span(&self.draw, self.index, s, style(Default::default()));
+        self
+    }
+
+    /// Specify how the glyphs are rendered.
+    pub fn mode(self, mode: TextMode) -> Self {
+        update_text(&self.draw, self.index, |text| text.mode = mode);
+        self
+    }
+
+    /// Fill the vector outline of each glyph rather than drawing glyphs from the font atlas.
+    ///
+    /// Outlined text remains sharp at any scale and orientation, at the cost of tessellation.
+    pub fn outline(self) -> Self {
+        self.mode(TextMode::Outline)
+    }
+
+    /// Stroke the vector outline of each glyph.
+    ///
+    /// The stroke may be styled via `stroke_weight`, `join` and the other stroke methods.
+    pub fn stroke(self) -> Self {
+        self.mode(TextMode::Stroke)
+    }
+
+    /// Lay the text out along the given path rather than within its bounding rectangle.
+    ///
+    /// Each glyph is placed upright on the path by arc length, so that the first baseline follows
+    /// the path. The text is aligned to the start, middle or end of the path via its
+    /// justification and does not wrap. Subsequent lines follow beneath the path.
+    ///
+    /// The path's coordinates are used as-is, so the position of the text is ignored.
+    /// Underlines and strikethroughs are not drawn along paths.
+    pub fn along_path<I>(self, events: I) -> Self
+    where
+        I: IntoIterator<Item = PathEvent>,
+    {
+        crate::draw::drawing::with_primitive_ctxt(
+            &self.draw,
+            self.index,
+            |prim, ctxt| match prim {
+                Primitive::Text(mut text) => {
+                    let start = ctxt.path_event_buffer.len();
+                    ctxt.path_event_buffer.extend(events);
+                    let end = ctxt.path_event_buffer.len();
+                    text.path.events = Some(start..end);
+                    text.style.layout = std::mem::take(&mut text.style.layout).no_line_wrap();
+                    Primitive::Text(text)
+                }
+                prim => {
+                    bevy::log::warn_once!("expected a `Text` primitive");
+                    prim
+                }
+            },
+        );
+        self
+    }
+
+    /// The distance by which text laid out along a path is shifted along the path from its
+    /// aligned position.
+    ///
+    /// Animating this scrolls the text along the path.
+    pub fn path_offset(self, offset: text::Scalar) -> Self {
+        update_text(&self.draw, self.index, |text| text.path.offset = offset);
+        self
+    }
+
+    /// How glyphs beyond the ends of the path are handled when laid out along a path.
+    ///
+    /// By default such glyphs are hidden.
+    pub fn path_overflow(self, overflow: text::path::Overflow) -> Self {
+        update_text(&self.draw, self.index, |text| text.path.overflow = overflow);
+        self
+    }
+
     /// The font size to use for the text.
     pub fn font_size(self, size: text::FontSize) -> Self {
         update_text_layout(&self.draw, self.index, |l| l.font_size(size));
@@ -220,6 +427,72 @@ impl<'a> DrawingText<'a> {
         self
     }
 
+    /// The weight of the font, from `100.0` (thin) to `900.0` (black).
+    pub fn font_weight(self, weight: f32) -> Self {
+        update_text_layout(&self.draw, self.index, |l| l.font_weight(weight));
+        self
+    }
+
+    /// Short-hand for a font weight of `700.0`.
+    pub fn bold(self) -> Self {
+        update_text_layout(&self.draw, self.index, |l| l.bold());
+        self
+    }
+
+    /// Whether the font should be upright, italic or oblique.
+    pub fn font_style(self, style: FontStyle) -> Self {
+        update_text_layout(&self.draw, self.index, |l| l.font_style(style));
+        self
+    }
+
+    /// Use the italic style of the font.
+    pub fn italic(self) -> Self {
+        update_text_layout(&self.draw, self.index, |l| l.italic());
+        self
+    }
+
+    /// The width of the font as a ratio of its normal width, e.g. `0.75` for a condensed face.
+    pub fn font_stretch(self, ratio: f32) -> Self {
+        update_text_layout(&self.draw, self.index, |l| l.font_stretch(ratio));
+        self
+    }
+
+    /// Additional space between each character.
+    pub fn letter_spacing(self, spacing: Scalar) -> Self {
+        update_text_layout(&self.draw, self.index, |l| l.letter_spacing(spacing));
+        self
+    }
+
+    /// Additional space between each word.
+    pub fn word_spacing(self, spacing: Scalar) -> Self {
+        update_text_layout(&self.draw, self.index, |l| l.word_spacing(spacing));
+        self
+    }
+
+    /// Enable, disable or select an alternative for the given OpenType feature.
+    pub fn font_feature(self, feature: FontFeature) -> Self {
+        update_text_layout(&self.draw, self.index, |l| l.font_feature(feature));
+        self
+    }
+
+    /// Enable or disable standard ligatures, e.g. "fi".
+    pub fn ligatures(self, enabled: bool) -> Self {
+        update_text_layout(&self.draw, self.index, |l| l.ligatures(enabled));
+        self
+    }
+
+    /// Use numerals of equal width, e.g. so that columns of numbers line up.
+    pub fn tabular_numerals(self) -> Self {
+        update_text_layout(&self.draw, self.index, |l| l.tabular_numerals());
+        self
+    }
+
+    /// Display lowercase letters as small capitals.
+    pub fn small_caps(self) -> Self {
+        update_text_layout(&self.draw, self.index, |l| l.small_caps());
+        self
+    }
+
     /// Specify how the whole text should be aligned along the y axis of its bounding rectangle
     pub fn y_align_text(self, align: Align) -> Self {
         update_text_layout(&self.draw, self.index, |l| l.y_align(align));
@@ -282,6 +555,22 @@ fn update_text_layout(
     })
 }
 
+// Append a span to the `Text` primitive being drawn at `index`.
+//
+// Non-generic so that the body compiles here rather than in the caller's crate.
+fn push_span(draw: &crate::draw::Draw, index: usize, s: &str, style: text::SpanStyle) {
+    crate::draw::drawing::with_primitive_ctxt(draw, index, |prim, ctxt| match prim {
+        Primitive::Text(mut text) => {
+            text.push_span(ctxt, s, style);
+            Primitive::Text(text)
+        }
+        prim => {
+            bevy::log::warn_once!("expected a `Text` primitive");
+            prim
+        }
+    })
+}
+
 // Set the font family of the `Text` primitive being drawn at `index`.
 //
 // Non-generic so that the body compiles here rather than in the caller's crate.
@@ -296,9 +585,9 @@ fn set_glyph_colors(draw: &crate::draw::Draw, index: usize, colors: Vec<Color>)
     update_text(draw, index, |text| text.style.glyph_colors = colors)
 }
 
-/// A run of glyph quads sampling a single font atlas texture.
+/// A run of glyph quads sampling a single font atlas texture, or untextured decoration quads.
 pub(crate) struct TextQuadBatch {
-    pub texture: Handle<Image>,
+    pub texture: Option<Handle<Image>>,
     pub mesh: Mesh,
 }
 
@@ -312,7 +601,7 @@ impl Text {
     #[allow(clippy::too_many_arguments)]
     pub(crate) fn render_atlas_quads(
         self,
-        text_buffer: &str,
+        buffers: &draw::IntermediaryState,
         theme: &draw::Theme,
         transform: &Mat4,
         output_attachment_size: Vec2,
@@ -322,39 +611,44 @@ impl Text {
         images: &mut Assets<Image>,
         scale_cx: &mut ScaleCx,
     ) -> Vec<TextQuadBatch> {
-        let s = &text_buffer[self.text.clone()];
+        let s = &buffers.text_buffer[self.text.clone()];
         if s.is_empty() {
             return Vec::new();
         }
 
-        let layout_params = self.style.layout.build();
-        let w = self
-            .spatial
-            .dimensions
-            .x
-            .unwrap_or(output_attachment_size.x);
-        let h = self
-            .spatial
-            .dimensions
-            .y
-            .unwrap_or(output_attachment_size.y);
-        let x = self.spatial.position.point.x;
-        let y = self.spatial.position.point.y;
-        let rect = nannou_core::geom::Rect::from_x_y_w_h(x, y, w, h);
-
-        let mut inner = text_cx.0.lock().unwrap();
-        let text_obj =
-            text::Text::layout_with_inner(&mut inner, s, &layout_params, rect, scale_factor);
-        drop(inner);
+        // Outlined and stroked text is tessellated rather than sampled from the glyph atlas.
+        if self.mode != TextMode::Atlas {
+            let mesh = self.render_outline_mesh(
+                buffers,
+                theme,
+                transform,
+                output_attachment_size,
+                text_cx,
+            );
+            return vec![TextQuadBatch {
+                texture: None,
+                mesh,
+            }];
+        }
 
         let default_color = self
             .style
             .color
             .unwrap_or_else(|| theme.fill(&draw::theme::Primitive::Text));
+        let (text_obj, pos_offset) = self.layout(
+            s,
+            default_color,
+            output_attachment_size,
+            scale_factor,
+            text_cx,
+        );
         let glyph_colors = &self.style.glyph_colors;
-
-        let rect_center = Vec2::new(x, y);
-        let pos_offset = text_obj.position_offset_value() + rect_center;
+        let path_transforms = self.path_glyph_transforms(
+            &buffers.path_event_buffer,
+            &text_obj,
+            pos_offset,
+            scale_factor,
+        );
 
         // Rasterise with bevy's defaults so atlas entries are shared with bevy UI text.
         let font_smoothing = FontSmoothing::AntiAliased;
@@ -372,6 +666,7 @@ impl Text {
                 };
 
                 let run = glyph_run.run();
+                let run_color = glyph_run.style().brush;
                 let font = run.font();
                 let font_size = run.font_size();
                 let coords = run.normalized_coords();
@@ -403,6 +698,15 @@ impl Text {
                     let i = glyph_index;
                     glyph_index += 1;
 
+                    // Glyphs laid out along a path are moved onto it, or skipped if they overflow.
+                    let glyph_transform = match path_transforms {
+                        None => *transform,
+                        Some(ref transforms) => match transforms.get(i).copied().flatten() {
+                            Some(m) => *transform * m,
+                            None => continue,
+                        },
+                    };
+
                     let Ok(glyph_id) = u16::try_from(glyph.id) else {
                         continue;
                     };
@@ -446,7 +750,7 @@ impl Text {
                     // Monochrome glyphs are white-on-alpha in the atlas and tinted by
                     // vertex colour; colour glyphs (e.g. emoji) are sampled as-is.
                     let color = if atlas_info.is_alpha_mask {
-                        glyph_colors.get(i).copied().unwrap_or(default_color)
+                        glyph_colors.get(i).copied().unwrap_or(run_color)
                     } else {
                         Color::WHITE
                     };
@@ -465,18 +769,21 @@ impl Text {
                     );
 
                     let batch = match batches.last_mut() {
-                        Some(batch) if batch.texture.id() == atlas_info.texture => batch,
+                        Some(batch)
+                            if batch.texture.as_ref().map(|t| t.id())
+                                == Some(atlas_info.texture) =>
+                        {
+                            batch
+                        }
                         _ => {
                             batches.push(TextQuadBatch {
-                                texture: atlas.texture.clone(),
+                                texture: Some(atlas.texture.clone()),
                                 mesh: Mesh::init(),
                             });
                             batches.last_mut().unwrap()
                         }
                     };
 
-                    let mesh = &mut batch.mesh;
-                    let base = mesh.points().len() as u32;
                     // Corners ordered top-left, top-right, bottom-right, bottom-left.
                     let corners = [
                         (cx - hw, cy + hh, uv_l, uv_t),
@@ -484,23 +791,292 @@ impl Text {
                         (cx + hw, cy - hh, uv_r, uv_b),
                         (cx - hw, cy - hh, uv_l, uv_b),
                     ];
-                    for (px, py, u, v) in corners {
-                        let p = *transform * Vec4::new(px, py, 0.0, 1.0);
-                        mesh.points_mut().push([p.x, p.y, p.z]);
-                        mesh.colors_mut().push(color_arr);
-                        mesh.tex_coords_mut().push([u, v]);
-                        mesh.normals_mut().push([0.0, 0.0, 1.0]);
-                    }
-                    // Two triangles wound counter-clockwise in y-up space.
-                    for idx in [0, 3, 2, 0, 2, 1] {
-                        mesh.push_index(base + idx);
-                    }
+                    push_quad(&mut batch.mesh, &glyph_transform, corners, color_arr);
                 }
             }
         }
 
+        // Underlines and strikethroughs are drawn over the glyphs without a texture.
+        let decorations = text_obj.decoration_rects();
+        if !decorations.is_empty() && path_transforms.is_none() {
+            let centre = self.centre();
+            let mut mesh = Mesh::init();
+            for (rect, color) in decorations {
+                let rect = rect.shift(centre);
+                let corners = [
+                    (rect.left(), rect.top(), 0.0, 0.0),
+                    (rect.right(), rect.top(), 1.0, 0.0),
+                    (rect.right(), rect.bottom(), 1.0, 1.0),
+                    (rect.left(), rect.bottom(), 0.0, 1.0),
+                ];
+                let color_arr = LinearRgba::from(color).to_f32_array();
+                push_quad(&mut mesh, transform, corners, color_arr);
+            }
+            batches.push(TextQuadBatch {
+                texture: None,
+                mesh,
+            });
+        }
+
         batches
     }
+
+    /// Lay out the text and collect the outline of each glyph as a vector path.
+    ///
+    /// Outlines are filled unless the text is drawn in [TextMode::Stroke].
+    pub(crate) fn vector_paths(
+        self,
+        buffers: &draw::IntermediaryState,
+        theme: &draw::Theme,
+        transform: &Mat4,
+        output_attachment_size: Vec2,
+        text_cx: &crate::text::font::SharedTextCx,
+    ) -> Vec<VectorPath> {
+        let options = self.outline_options();
+        self.outlines(buffers, theme, output_attachment_size, text_cx)
+            .into_iter()
+            .map(|(events, color, glyph_transform)| {
+                VectorPath::new(events, color, *transform * glyph_transform, options.clone())
+            })
+            .collect()
+    }
+
+    // Lay out the text and tessellate the outline of each glyph into a single untextured mesh.
+    fn render_outline_mesh(
+        self,
+        buffers: &draw::IntermediaryState,
+        theme: &draw::Theme,
+        transform: &Mat4,
+        output_attachment_size: Vec2,
+        text_cx: &crate::text::font::SharedTextCx,
+    ) -> Mesh {
+        let options = self.outline_options();
+        let theme_prim = draw::theme::Primitive::Text;
+        let mut fill_tessellator = FillTessellator::new();
+        let mut stroke_tessellator = StrokeTessellator::new();
+        let mut mesh = Mesh::init();
+        for (events, color, glyph_transform) in
+            self.outlines(buffers, theme, output_attachment_size, text_cx)
+        {
+            path::render_path_events(
+                events,
+                Some(color),
+                None,
+                *transform * glyph_transform,
+                options.clone(),
+                None,
+                theme,
+                &theme_prim,
+                &mut fill_tessellator,
+                &mut stroke_tessellator,
+                None,
+                &mut mesh,
+            );
+        }
+        mesh
+    }
+
+    // The tessellation options used for glyph outlines.
+    fn outline_options(&self) -> path::Options {
+        match self.mode {
+            TextMode::Stroke => path::Options::Stroke(self.stroke),
+            TextMode::Atlas | TextMode::Outline => path::Options::Fill(FillOptions::non_zero()),
+        }
+    }
+
+    // Lay out the text and collect the outline of each glyph and decoration, along with its
+    // color and the transform that moves it into place, e.g. onto the text's path.
+    fn outlines(
+        &self,
+        buffers: &draw::IntermediaryState,
+        theme: &draw::Theme,
+        output_attachment_size: Vec2,
+        text_cx: &crate::text::font::SharedTextCx,
+    ) -> Vec<(Vec<PathEvent>, Color, Mat4)> {
+        let s = &buffers.text_buffer[self.text.clone()];
+        if s.is_empty() {
+            return Vec::new();
+        }
+
+        let default_color = self
+            .style
+            .color
+            .unwrap_or_else(|| theme.fill(&draw::theme::Primitive::Text));
+        let (text_obj, pos_offset) =
+            self.layout(s, default_color, output_attachment_size, 1.0, text_cx);
+
+        let run_colors = glyph_run_colors(text_obj.parley_layout());
+        let path_transforms =
+            self.path_glyph_transforms(&buffers.path_event_buffer, &text_obj, pos_offset, 1.0);
+        let mut outlines: Vec<_> =
+            text::glyph::per_glyph_path_events(text_obj.parley_layout(), pos_offset, 1.0)
+                .into_iter()
+                .enumerate()
+                .filter(|(_, events)| !events.is_empty())
+                .filter_map(|(i, events)| {
+                    let glyph_transform = match path_transforms {
+                        None => Mat4::IDENTITY,
+                        Some(ref transforms) => transforms.get(i).copied()??,
+                    };
+                    let color = self.style.glyph_colors.get(i).copied();
+                    let color = color
+                        .or_else(|| run_colors.get(i).copied())
+                        .unwrap_or(default_color);
+                    Some((events, color, glyph_transform))
+                })
+                .collect();
+
+        // Underlines and strikethroughs are only drawn for text laid out within its rect.
+        if path_transforms.is_some() {
+            return outlines;
+        }
+        let centre = self.centre();
+        for (rect, color) in text_obj.decoration_rects() {
+            let rect = rect.shift(centre);
+            let min = lyon::math::point(rect.left(), rect.bottom());
+            let max = lyon::math::point(rect.right(), rect.top());
+            let mut builder = lyon::path::Path::builder();
+            builder.add_rectangle(
+                &lyon::math::Box2D::new(min, max),
+                lyon::path::Winding::Positive,
+            );
+            let events: Vec<PathEvent> = builder.build().iter().collect();
+            outlines.push((events, color, Mat4::IDENTITY));
+        }
+        outlines
+    }
+
+    // For text laid out along a path, the transform that moves each positioned glyph from its
+    // laid out position onto the path, or `None` for glyphs that overflow the path.
+    fn path_glyph_transforms(
+        &self,
+        path_event_buffer: &[PathEvent],
+        text_obj: &text::Text,
+        pos_offset: Vec2,
+        scale_factor: f32,
+    ) -> Option<Vec<Option<Mat4>>> {
+        let events = self.path.events.clone()?;
+        let events = path_event_buffer.get(events)?.iter().copied();
+        let tolerance = lyon::tessellation::FillOptions::DEFAULT_TOLERANCE;
+        let path = text::path::ArcLengthPath::new(events, tolerance);
+        let layout = text_obj.parley_layout();
+        let baseline = layout
+            .lines()
+            .next()
+            .map(|line| line.metrics().baseline)
+            .unwrap_or(0.0);
+        let width = layout.width() / scale_factor;
+        let justify = self.style.layout.build().justify;
+        let start = text::path::start_distance(path.length(), width, justify, self.path.offset);
+
+        let mut transforms = vec![];
+        for line in layout.lines() {
+            for item in line.items() {
+                let parley::PositionedLayoutItem::GlyphRun(glyph_run) = item else {
+                    continue;
+                };
+                for glyph in glyph_run.positioned_glyphs() {
+                    // The middle of the glyph's advance on the first baseline.
+                    let x = (glyph.x + glyph.advance * 0.5) / scale_factor;
+                    let anchor = pos_offset + Vec2::new(x, -baseline / scale_factor);
+                    let transform = path
+                        .sample(start + x, self.path.overflow)
+                        .map(|(point, dir)| text::path::glyph_transform(anchor, point, dir));
+                    transforms.push(transform);
+                }
+            }
+        }
+        Some(transforms)
+    }
+
+    // The centre of the rect within which the text is laid out.
+    fn centre(&self) -> Vec2 {
+        let point = self.spatial.position.point;
+        Vec2::new(point.x, point.y)
+    }
+
+    // Lay out the text within its rect, returning the layout along with the offset of the
+    // layout origin in nannou's coordinate space.
+    fn layout(
+        &self,
+        s: &str,
+        color: Color,
+        output_attachment_size: Vec2,
+        scale_factor: f32,
+        text_cx: &crate::text::font::SharedTextCx,
+    ) -> (text::Text, Vec2) {
+        let layout_params = self.style.layout.build();
+        let w = self
+            .spatial
+            .dimensions
+            .x
+            .unwrap_or(output_attachment_size.x);
+        let h = self
+            .spatial
+            .dimensions
+            .y
+            .unwrap_or(output_attachment_size.y);
+        let x = self.spatial.position.point.x;
+        let y = self.spatial.position.point.y;
+        let rect = nannou_core::geom::Rect::from_x_y_w_h(x, y, w, h);
+
+        let mut inner = text_cx.0.lock().unwrap();
+        let text_obj = text::Text::layout_with_inner(
+            &mut inner,
+            s,
+            &layout_params,
+            &self.spans,
+            color,
+            rect,
+            scale_factor,
+        );
+        drop(inner);
+
+        let rect_center = self.centre();
+        let pos_offset = text_obj.position_offset_value() + rect_center;
+        (text_obj, pos_offset)
+    }
+}
+
+// Push a quad with the given corners and texture coordinates to the mesh.
+fn push_quad(
+    mesh: &mut Mesh,
+    transform: &Mat4,
+    corners: [(f32, f32, f32, f32); 4],
+    color: [f32; 4],
+) {
+    let base = mesh.points().len() as u32;
+    for (px, py, u, v) in corners {
+        let p = *transform * Vec4::new(px, py, 0.0, 1.0);
+        mesh.points_mut().push([p.x, p.y, p.z]);
+        mesh.colors_mut().push(color);
+        mesh.tex_coords_mut().push([u, v]);
+        mesh.normals_mut().push([0.0, 0.0, 1.0]);
+    }
+    // Two triangles wound counter-clockwise in y-up space.
+    for idx in [0, 3, 2, 0, 2, 1] {
+        mesh.push_index(base + idx);
+    }
+}
+
+// The color of each positioned glyph, as set by the style of its run.
+fn glyph_run_colors(layout: &parley::Layout<Color>) -> Vec<Color> {
+    let mut colors = Vec::new();
+    for line in layout.lines() {
+        for item in line.items() {
+            if let parley::PositionedLayoutItem::GlyphRun(glyph_run) = item {
+                let n = glyph_run.positioned_glyphs().count();
+                colors.extend(std::iter::repeat_n(glyph_run.style().brush, n));
+            }
+        }
+    }
+    colors
+}
+
+impl SetStroke for Text {
+    fn stroke_options_mut(&mut self) -> &mut StrokeOptions {
+        &mut self.stroke
+    }
 }
 
 impl SetOrientation for Text {
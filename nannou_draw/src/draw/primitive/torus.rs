@@ -0,0 +1,170 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+use crate::draw::primitive::Primitive;
+use crate::draw::primitive::solid::Geometry;
+use crate::draw::properties::spatial::{orientation, position};
+use crate::draw::properties::{SetColor, SetOrientation, SetPosition};
+use crate::draw::{self, Drawing};
+
+/// Properties related to drawing a solid **Torus**.
+///
+/// The torus lies within the *xy* plane, around the *z* axis. Its `radius` is the distance from
+/// its centre to the centre of the tube, which defaults to `40.0` with a tube radius of `10.0`.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Torus {
+    position: position::Properties,
+    orientation: orientation::Properties,
+    color: Option<Color>,
+    radius: f32,
+    tube_radius: f32,
+    resolution: u32,
+    tube_resolution: u32,
+}
+
+/// The drawing context for a torus.
+pub type DrawingTorus<'a> = Drawing<'a, Torus>;
+
+impl Torus {
+    /// The distance from the centre of the **Torus** to the centre of its tube.
+    pub fn radius(mut self, radius: f32) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    /// The radius of the tube.
+    pub fn tube_radius(mut self, radius: f32) -> Self {
+        self.tube_radius = radius;
+        self
+    }
+
+    /// The number of segments around the ring of the **Torus**, i.e. along the path traced by
+    /// the centre of its tube.
+    ///
+    /// Clamped to a minimum of `3`. Defaults to `32`.
+    pub fn resolution(mut self, resolution: u32) -> Self {
+        self.resolution = resolution;
+        self
+    }
+
+    /// The number of segments around the circular cross-section of the tube.
+    ///
+    /// Clamped to a minimum of `3`. Defaults to `16`.
+    pub fn tube_resolution(mut self, resolution: u32) -> Self {
+        self.tube_resolution = resolution;
+        self
+    }
+}
+
+impl<'a> DrawingTorus<'a> {
+    /// The distance from the centre of the **Torus** to the centre of its tube.
+    pub fn radius(self, radius: f32) -> Self {
+        update_torus(&self.draw, self.index, |torus| torus.radius = radius);
+        self
+    }
+
+    /// The radius of the tube.
+    pub fn tube_radius(self, radius: f32) -> Self {
+        update_torus(&self.draw, self.index, |torus| torus.tube_radius = radius);
+        self
+    }
+
+    /// The number of segments around the ring of the torus.
+    pub fn resolution(self, resolution: u32) -> Self {
+        update_torus(&self.draw, self.index, |torus| {
+            torus.resolution = resolution
+        });
+        self
+    }
+
+    /// The number of segments around the cross-section of the tube.
+    pub fn tube_resolution(self, resolution: u32) -> Self {
+        update_torus(&self.draw, self.index, |torus| {
+            torus.tube_resolution = resolution
+        });
+        self
+    }
+}
+
+// Update the inner `Torus` of the primitive being drawn at `index`.
+fn update_torus(draw: &crate::draw::Draw, index: usize, f: impl FnOnce(&mut Torus)) {
+    crate::draw::drawing::with_primitive(draw, index, |prim| match prim {
+        Primitive::Torus(torus) => f(torus),
+        _ => bevy::log::warn_once!("expected a `Torus` primitive"),
+    })
+}
+
+impl draw::render::RenderPrimitive for Torus {
+    fn render_primitive(self, ctxt: draw::render::RenderContext, mesh: &mut Mesh) {
+        let Torus {
+            position,
+            orientation,
+            color,
+            radius,
+            tube_radius,
+            resolution,
+            tube_resolution,
+        } = self;
+        if radius <= 0.0 || tube_radius <= 0.0 {
+            return;
+        }
+        let mut geometry = Geometry::default();
+        geometry.grid(resolution.max(3), tube_resolution.max(3), |u, v| {
+            let (sin_u, cos_u) = (u * TAU).sin_cos();
+            let (sin_v, cos_v) = (v * TAU).sin_cos();
+            let normal = Vec3::new(cos_v * cos_u, cos_v * sin_u, sin_v);
+            let centre = Vec3::new(cos_u, sin_u, 0.0) * radius;
+            (centre + normal * tube_radius, normal)
+        });
+        geometry.render(
+            position,
+            orientation,
+            color,
+            ctxt,
+            &draw::theme::Primitive::Torus,
+            mesh,
+        );
+    }
+}
+
+impl Default for Torus {
+    fn default() -> Self {
+        Torus {
+            position: Default::default(),
+            orientation: Default::default(),
+            color: None,
+            radius: 40.0,
+            tube_radius: 10.0,
+            resolution: 32,
+            tube_resolution: 16,
+        }
+    }
+}
+
+impl SetOrientation for Torus {
+    fn properties(&mut self) -> &mut orientation::Properties {
+        SetOrientation::properties(&mut self.orientation)
+    }
+}
+
+impl SetPosition for Torus {
+    fn properties(&mut self) -> &mut position::Properties {
+        SetPosition::properties(&mut self.position)
+    }
+}
+
+impl SetColor for Torus {
+    fn color_mut(&mut self) -> &mut Option<Color> {
+        SetColor::color_mut(&mut self.color)
+    }
+}
+
+// Primitive conversion.
+
+impl From<Torus> for Primitive {
+    fn from(prim: Torus) -> Self {
+        Primitive::Torus(prim)
+    }
+}
@@ -5,13 +5,19 @@ use nannou_core::geom;
 
 use crate::draw::primitive::Primitive;
 use crate::draw::primitive::polygon::{self, PolygonInit, PolygonOptions, SetPolygon};
+use crate::draw::properties::dash::Dashes;
+use crate::draw::properties::gradient::Gradient;
 use crate::draw::properties::spatial::{dimension, orientation, position};
-use crate::draw::properties::{SetColor, SetDimensions, SetOrientation, SetPosition, SetStroke};
+use crate::draw::properties::{
+    SetColor, SetDashes, SetDimensions, SetGradient, SetOrientation, SetPosition, SetStroke,
+};
 use crate::draw::{self, Drawing};
 
 /// Properties related to drawing a **Tri**.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Tri {
+    #[cfg_attr(feature = "serde", serde(with = "crate::record::geom_tri"))]
     tri: geom::Tri<Vec2>,
     dimensions: dimension::Properties,
     polygon: PolygonInit,
@@ -23,14 +29,6 @@ pub type DrawingTri<'a> = Drawing<'a, Tri>;
 // Tri-specific methods.
 
 impl Tri {
-    /// Stroke the outline with the given color.
-    pub fn stroke<C>(self, color: C) -> Self
-    where
-        C: Into<Color>,
-    {
-        self.stroke_color(color)
-    }
-
     /// Use the given three points as the vertices (corners) of the triangle.
     pub fn points<P>(mut self, a: P, b: P, c: P) -> Self
     where
@@ -47,14 +45,6 @@ impl Tri {
 // Drawing methods.
 
 impl<'a> DrawingTri<'a> {
-    /// Stroke the outline with the given color.
-    pub fn stroke<C>(self, color: C) -> Self
-    where
-        C: Into<Color>,
-    {
-        self.stroke_color(color)
-    }
-
     /// Use the given points as the vertices (corners) of the triangle.
     pub fn points<P>(self, a: P, b: P, c: P) -> Self
     where
@@ -177,6 +167,18 @@ impl SetStroke for Tri {
     }
 }
 
+impl SetDashes for Tri {
+    fn dashes_mut(&mut self) -> &mut Dashes {
+        SetDashes::dashes_mut(&mut self.polygon)
+    }
+}
+
+impl SetGradient for Tri {
+    fn gradient_mut(&mut self) -> &mut Gradient {
+        SetGradient::gradient_mut(&mut self.polygon)
+    }
+}
+
 impl SetPolygon for Tri {
     fn polygon_options_mut(&mut self) -> &mut PolygonOptions {
         SetPolygon::polygon_options_mut(&mut self.polygon)
@@ -0,0 +1,195 @@
+use bevy::prelude::*;
+use lyon::path::PathEvent;
+use lyon::path::iterator::{FromPolyline, PathIterator};
+
+use crate::draw::{Draw, drawing};
+
+// The minimum distance travelled along a segment between splits, relative to its length.
+const MIN_STEP: f32 = 1e-5;
+
+/// A dash pattern for stroked paths, matching SVG's `stroke-dasharray` and `stroke-dashoffset`.
+///
+/// Paths are split into dashes by arc length prior to stroke tessellation. The pattern restarts
+/// at the beginning of each sub-path.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dashes {
+    /// Alternating lengths of dashes and gaps, beginning with a dash.
+    ///
+    /// A pattern with an odd number of lengths is repeated to yield an even number. The stroke is
+    /// solid if the pattern is empty, contains a negative length or sums to no more than
+    /// `f32::EPSILON`.
+    pub pattern: Vec<f32>,
+    /// The distance into the pattern at which each sub-path begins.
+    pub offset: f32,
+}
+
+/// Nodes that support dashed strokes.
+pub trait SetDashes: Sized {
+    /// Provide a mutable reference to the dash pattern.
+    fn dashes_mut(&mut self) -> &mut Dashes;
+
+    /// Split the stroke into dashes with the given alternating lengths of dashes and gaps.
+    ///
+    /// A length of `0.0` produces a dot when combined with round or square caps.
+    fn dashes(mut self, pattern: &[f32]) -> Self {
+        self.dashes_mut().pattern = pattern.to_vec();
+        self
+    }
+
+    /// The distance into the dash pattern at which the stroke begins.
+    ///
+    /// Animating this produces the "marching ants" effect.
+    fn dash_offset(mut self, offset: f32) -> Self {
+        self.dashes_mut().offset = offset;
+        self
+    }
+}
+
+impl SetDashes for Dashes {
+    fn dashes_mut(&mut self) -> &mut Dashes {
+        self
+    }
+}
+
+impl Dashes {
+    /// Whether or not the stroke is drawn without dashes.
+    pub fn is_solid(&self) -> bool {
+        self.pattern.is_empty()
+            || self.pattern.iter().any(|&l| !l.is_finite() || l < 0.0)
+            || self.pattern.iter().sum::<f32>() <= f32::EPSILON
+    }
+
+    /// Split the given path into dashes, returning the path events of the dashes.
+    ///
+    /// Curves are first flattened to lines with the given tolerance.
+    pub fn split_events<I>(&self, events: I, tolerance: f32) -> Vec<PathEvent>
+    where
+        I: IntoIterator<Item = PathEvent>,
+    {
+        let mut dashed = vec![];
+        let mut polyline = vec![];
+        for event in events.into_iter().flattened(tolerance) {
+            match event {
+                PathEvent::Begin { at } => {
+                    polyline.clear();
+                    polyline.push((Vec2::new(at.x, at.y), ()));
+                }
+                PathEvent::Line { to, .. } => polyline.push((Vec2::new(to.x, to.y), ())),
+                PathEvent::End { close, .. } => {
+                    for dash in self.split_points(polyline.drain(..), close, |_, _, _| ()) {
+                        let points = dash.into_iter().map(|(p, _)| lyon::math::point(p.x, p.y));
+                        dashed.extend(FromPolyline::new(false, points));
+                    }
+                }
+                // Flattened paths contain only lines.
+                PathEvent::Quadratic { .. } | PathEvent::Cubic { .. } => (),
+            }
+        }
+        dashed
+    }
+
+    /// Split the given polyline into dashes, each described by its own polyline.
+    ///
+    /// If the pattern [is solid](Self::is_solid), the whole polyline is returned as a single dash.
+    ///
+    /// Each point carries some attribute (e.g. a color or texture coordinate) which is
+    /// interpolated via `lerp` for points that lie between those of the original polyline.
+    pub fn split_points<I, V, F>(&self, points: I, close: bool, lerp: F) -> Vec<Vec<(Vec2, V)>>
+    where
+        I: IntoIterator<Item = (Vec2, V)>,
+        V: Clone,
+        F: Fn(&V, &V, f32) -> V,
+    {
+        let points: Vec<_> = points.into_iter().collect();
+        let mut dashes = vec![];
+        let Some(first) = points.first() else {
+            return dashes;
+        };
+
+        // Solid patterns are ignored, yielding the whole polyline as a single dash.
+        if self.is_solid() {
+            let mut dash = points.clone();
+            if close {
+                dash.push(first.clone());
+            }
+            dashes.push(dash);
+            return dashes;
+        }
+
+        // Repeat odd patterns so that dashes and gaps alternate.
+        let mut pattern = self.pattern.clone();
+        if pattern.len() % 2 == 1 {
+            pattern.extend_from_within(..);
+        }
+
+        // Find the position within the pattern at which the path begins.
+        let total: f32 = pattern.iter().sum();
+        let mut offset = self.offset.rem_euclid(total);
+        let mut ix = 0;
+        while offset > 0.0 && offset >= pattern[ix] {
+            offset -= pattern[ix];
+            ix = (ix + 1) % pattern.len();
+        }
+        let mut remaining = pattern[ix] - offset.max(0.0);
+        let mut on = ix % 2 == 0;
+
+        // Walk each segment, splitting it wherever the pattern switches between dash and gap.
+        let mut dash = vec![];
+        if on {
+            dash.push(first.clone());
+        }
+        let n_segments = if close {
+            points.len()
+        } else {
+            points.len() - 1
+        };
+        for i in 0..n_segments {
+            let (a, b) = (&points[i], &points[(i + 1) % points.len()]);
+            let len = a.0.distance(b.0);
+            // Each non-zero length advances by at least a small fraction of the segment, as `f32`
+            // rounding would otherwise swallow lengths that are tiny relative to the distance
+            // travelled. Zero lengths (dots) remain exact.
+            let min_step = len * MIN_STEP;
+            let mut dist = 0.0;
+            while len - dist > remaining {
+                if remaining > 0.0 {
+                    dist = (dist + remaining.max(min_step)).min(len);
+                }
+                let t = dist / len;
+                dash.push((a.0.lerp(b.0, t), lerp(&a.1, &b.1, t)));
+                if on {
+                    dashes.push(std::mem::take(&mut dash));
+                }
+                on = !on;
+                ix = (ix + 1) % pattern.len();
+                remaining = pattern[ix];
+            }
+            remaining -= len - dist;
+            if on {
+                dash.push(b.clone());
+            }
+        }
+        if on && dash.len() > 1 {
+            dashes.push(dash);
+        }
+        dashes
+    }
+}
+
+// An update to a primitive's dash pattern.
+pub(crate) enum Update {
+    Pattern(Vec<f32>),
+    Offset(f32),
+}
+
+// Update the dash pattern of the primitive being drawn at `index`.
+pub(crate) fn set_dashes(draw: &Draw, index: usize, update: Update) {
+    drawing::with_primitive(draw, index, |prim| match prim.dashes_mut() {
+        Some(dashes) => match update {
+            Update::Pattern(pattern) => dashes.pattern = pattern,
+            Update::Offset(offset) => dashes.offset = offset,
+        },
+        None => bevy::log::warn_once!("drawing primitive does not support `dashes`"),
+    })
+}
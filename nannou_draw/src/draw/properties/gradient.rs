@@ -0,0 +1,206 @@
+use bevy::prelude::*;
+
+use crate::draw::{Draw, drawing};
+
+/// A linear or radial color gradient, used in place of a primitive's color.
+///
+/// Coordinates are given in the primitive's local space, i.e. relative to its position and prior
+/// to its orientation. Points beyond the first or last stop take the color of that stop.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Gradient {
+    /// The shape of the gradient. No gradient is applied if this is `None`.
+    pub kind: Option<GradientKind>,
+    /// The colors of the gradient, each paired with its offset along the gradient in `0.0..=1.0`.
+    ///
+    /// Stops are expected to be sorted by offset. No gradient is applied if there are no stops.
+    pub stops: Vec<(f32, Color)>,
+    /// The color space in which colors are interpolated between stops.
+    pub interpolation: Interpolation,
+    /// The number of times that each tessellated triangle is subdivided.
+    ///
+    /// Colors are only sampled at the vertices of the tessellated mesh and are interpolated
+    /// linearly in between. Subdividing adds vertices for more accurate multi-stop, radial and
+    /// non-sRGB gradients at the cost of four times as many triangles per level. Levels beyond
+    /// [MAX_SUBDIVISIONS] are ignored.
+    pub subdivisions: u32,
+}
+
+/// The maximum number of times that each triangle of a gradient is subdivided.
+///
+/// Each level quadruples the number of triangles, so six levels already split each triangle into
+/// 4096.
+pub const MAX_SUBDIVISIONS: u32 = 6;
+
+/// The shape of a [Gradient].
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GradientKind {
+    /// Colors vary along the line from `start` to `end`.
+    Linear { start: Vec2, end: Vec2 },
+    /// Colors vary with the distance from `center`, reaching the last stop at `radius`.
+    Radial { center: Vec2, radius: f32 },
+}
+
+/// The color space in which gradient colors are interpolated.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Interpolation {
+    /// Interpolate gamma-encoded sRGB components, as in SVG and CSS by default.
+    #[default]
+    Srgb,
+    /// Interpolate linear RGB components, producing brighter midpoints.
+    Linear,
+    /// Interpolate in the perceptually uniform Oklab space.
+    Oklab,
+}
+
+/// Nodes that support gradient colors.
+pub trait SetGradient: Sized {
+    /// Provide a mutable reference to the gradient.
+    fn gradient_mut(&mut self) -> &mut Gradient;
+
+    /// Color the primitive with a gradient along the line from `start` to `end`.
+    fn linear_gradient<I, C>(mut self, start: Vec2, end: Vec2, stops: I) -> Self
+    where
+        I: IntoIterator<Item = (f32, C)>,
+        C: Into<Color>,
+    {
+        let kind = GradientKind::Linear { start, end };
+        self.gradient_mut().set(kind, collect_stops(stops));
+        self
+    }
+
+    /// Color the primitive with a gradient radiating from `center` out to `radius`.
+    fn radial_gradient<I, C>(mut self, center: Vec2, radius: f32, stops: I) -> Self
+    where
+        I: IntoIterator<Item = (f32, C)>,
+        C: Into<Color>,
+    {
+        let kind = GradientKind::Radial { center, radius };
+        self.gradient_mut().set(kind, collect_stops(stops));
+        self
+    }
+
+    /// The color space in which gradient colors are interpolated.
+    fn gradient_interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.gradient_mut().interpolation = interpolation;
+        self
+    }
+
+    /// The number of times that each tessellated triangle is subdivided for gradient fidelity.
+    ///
+    /// This is clamped to [MAX_SUBDIVISIONS].
+    fn gradient_subdivisions(mut self, subdivisions: u32) -> Self {
+        self.gradient_mut().subdivisions = subdivisions.min(MAX_SUBDIVISIONS);
+        self
+    }
+}
+
+impl SetGradient for Gradient {
+    fn gradient_mut(&mut self) -> &mut Gradient {
+        self
+    }
+}
+
+impl Gradient {
+    /// Whether or not the gradient is applied in place of the primitive's color.
+    pub fn is_active(&self) -> bool {
+        self.kind.is_some() && !self.stops.is_empty()
+    }
+
+    /// The position of the given point along the gradient, where `0.0` is the start and `1.0` is
+    /// the end.
+    ///
+    /// The result is not clamped. Returns `0.0` if the gradient is inactive or degenerate.
+    pub fn position_at(&self, point: Vec2) -> f32 {
+        let t = match self.kind {
+            None => return 0.0,
+            Some(GradientKind::Linear { start, end }) => {
+                let dir = end - start;
+                (point - start).dot(dir) / dir.length_squared()
+            }
+            Some(GradientKind::Radial { center, radius }) => point.distance(center) / radius,
+        };
+        if t.is_finite() { t } else { 0.0 }
+    }
+
+    /// The color of the gradient at the given point in the primitive's local space.
+    ///
+    /// Returns `None` if the gradient is inactive.
+    pub fn color_at(&self, point: Vec2) -> Option<Color> {
+        if !self.is_active() {
+            return None;
+        }
+        Some(self.color_at_position(self.position_at(point)))
+    }
+
+    /// The color at the given position along the gradient, as produced by `position_at`.
+    ///
+    /// Panics if there are no stops.
+    pub fn color_at_position(&self, t: f32) -> Color {
+        let ix = self.stops.partition_point(|&(offset, _)| offset <= t);
+        if ix == 0 {
+            return self.stops[0].1;
+        }
+        let (a_offset, a) = self.stops[ix - 1];
+        let Some(&(b_offset, b)) = self.stops.get(ix) else {
+            return a;
+        };
+        let t = (t - a_offset) / (b_offset - a_offset);
+        match self.interpolation {
+            Interpolation::Srgb => Srgba::from(a).mix(&Srgba::from(b), t).into(),
+            Interpolation::Linear => LinearRgba::from(a).mix(&LinearRgba::from(b), t).into(),
+            Interpolation::Oklab => Oklaba::from(a).mix(&Oklaba::from(b), t).into(),
+        }
+    }
+
+    // Replace the shape and stops, retaining the interpolation and subdivisions.
+    fn set(&mut self, kind: GradientKind, stops: Vec<(f32, Color)>) {
+        self.kind = Some(kind);
+        self.stops = stops;
+    }
+}
+
+// Collect the given stops, sorted by offset.
+fn collect_stops<I, C>(stops: I) -> Vec<(f32, Color)>
+where
+    I: IntoIterator<Item = (f32, C)>,
+    C: Into<Color>,
+{
+    let mut stops: Vec<_> = stops.into_iter().map(|(o, c)| (o, c.into())).collect();
+    stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+    stops
+}
+
+// An update to a primitive's gradient.
+pub(crate) enum Update {
+    Kind(GradientKind, Vec<(f32, Color)>),
+    Interpolation(Interpolation),
+    Subdivisions(u32),
+}
+
+impl Update {
+    // Sort the given stops and produce an update to the gradient's shape.
+    pub(crate) fn kind<I, C>(kind: GradientKind, stops: I) -> Self
+    where
+        I: IntoIterator<Item = (f32, C)>,
+        C: Into<Color>,
+    {
+        Update::Kind(kind, collect_stops(stops))
+    }
+}
+
+// Update the gradient of the primitive being drawn at `index`.
+pub(crate) fn set_gradient(draw: &Draw, index: usize, update: Update) {
+    drawing::with_primitive(draw, index, |prim| match prim.gradient_mut() {
+        Some(gradient) => match update {
+            Update::Kind(kind, stops) => gradient.set(kind, stops),
+            Update::Interpolation(interpolation) => gradient.interpolation = interpolation,
+            Update::Subdivisions(subdivisions) => {
+                gradient.subdivisions = subdivisions.min(MAX_SUBDIVISIONS)
+            }
+        },
+        None => bevy::log::warn_once!("drawing primitive does not support gradients"),
+    })
+}
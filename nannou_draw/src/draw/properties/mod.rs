@@ -7,14 +7,18 @@
 //! a unique **node::Index** to simplify this.
 
 pub use self::color::SetColor;
+pub use self::dash::SetDashes;
 pub use self::fill::SetFill;
+pub use self::gradient::SetGradient;
 pub use self::spatial::dimension::SetDimensions;
 pub use self::spatial::orientation::SetOrientation;
 pub use self::spatial::position::SetPosition;
 pub use self::stroke::SetStroke;
 
 pub mod color;
+pub mod dash;
 pub mod fill;
+pub mod gradient;
 pub mod spatial;
 pub mod stroke;
 pub mod tex_coords;
@@ -4,6 +4,7 @@ use crate::draw::{Draw, drawing};
 
 /// Dimension properties for **Drawing** a **Primitive**.
 #[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Properties {
     pub x: Option<f32>,
     pub y: Option<f32>,
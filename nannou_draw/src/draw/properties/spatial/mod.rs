@@ -12,6 +12,7 @@ pub trait SetSpatial: SetDimensions + SetPosition + SetOrientation {}
 impl<T> SetSpatial for T where T: SetDimensions + SetPosition + SetOrientation {}
 
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Properties {
     pub position: position::Properties,
     pub dimensions: dimension::Properties,
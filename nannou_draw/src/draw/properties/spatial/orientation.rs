@@ -4,6 +4,7 @@ use crate::draw::{Draw, drawing};
 
 /// Orientation properties for **Drawing** a **Primitive**.
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Properties {
     /// The orientation described by an angle along each axis.
     Axes(Vec3),
@@ -5,6 +5,7 @@ use crate::draw::{Draw, drawing};
 
 /// Position properties for **Drawing** a **Primitive**.
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Properties {
     pub point: Vec3,
 }
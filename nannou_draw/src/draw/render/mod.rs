@@ -16,27 +16,157 @@ pub struct RenderContext<'a> {
     pub intermediary_mesh: &'a Mesh,
     pub path_event_buffer: &'a [PathEvent],
     pub path_points_vertex_buffer: &'a [(Vec2, Color, Vec2)],
+    pub path_points_weighted_buffer: &'a [(Vec2, Color, f32)],
+    pub points_buffer: &'a [draw::primitive::points::Point],
     pub text_buffer: &'a str,
     pub theme: &'a draw::Theme,
     pub fill_tessellator: &'a mut FillTessellator,
     pub stroke_tessellator: &'a mut StrokeTessellator,
     pub output_attachment_size: Vec2, // logical coords
     pub output_attachment_scale_factor: f32,
+    /// If `Some`, paths are collected here rather than tessellated into the mesh, e.g. for SVG.
+    pub vector_paths: Option<&'a mut Vec<VectorPath>>,
 }
 
-/// The position and dimensions of the scissor.
+/// A path collected in place of tessellation, with the drawing's transform already applied.
+#[derive(Clone, Debug)]
+pub struct VectorPath {
+    pub events: Vec<PathEvent>,
+    pub color: Color,
+    pub options: draw::primitive::path::Options,
+    /// The uniform scale of the transform applied to the path, used to scale stroke weights.
+    pub scale: f32,
+    /// A gradient used in place of `color`, along with the transform from the gradient's local
+    /// space.
+    pub gradient: Option<(draw::properties::gradient::Gradient, Mat4)>,
+}
+
+/// The position and dimensions of the scissor in physical pixels, where the origin is the
+/// top-left of the output attachment.
 #[derive(Clone, Copy, Debug, PartialEq)]
-#[allow(dead_code)]
 pub struct Scissor {
     pub left: u32,
-    pub bottom: u32,
+    pub top: u32,
     pub width: u32,
     pub height: u32,
 }
 
+impl Scissor {
+    /// Convert the given draw scissor to physical pixels for an output attachment with the given
+    /// logical size and scale factor, clamping it to the bounds of the attachment.
+    ///
+    /// Returns `None` for `Scissor::Full`, in which case the full attachment should be used.
+    pub fn from_draw_scissor(
+        scissor: draw::Scissor,
+        output_attachment_size: Vec2,
+        output_attachment_scale_factor: f32,
+    ) -> Option<Self> {
+        let rect = match scissor {
+            draw::Scissor::Full => return None,
+            draw::Scissor::Rect(rect) => rect,
+            draw::Scissor::NoOverlap => {
+                return Some(Scissor {
+                    left: 0,
+                    top: 0,
+                    width: 0,
+                    height: 0,
+                });
+            }
+        };
+        // Convert from the centred, y-up coordinate space to a top-left origin.
+        let half = output_attachment_size * 0.5;
+        let left = (rect.left() + half.x).clamp(0.0, output_attachment_size.x);
+        let right = (rect.right() + half.x).clamp(0.0, output_attachment_size.x);
+        let top = (half.y - rect.top()).clamp(0.0, output_attachment_size.y);
+        let bottom = (half.y - rect.bottom()).clamp(0.0, output_attachment_size.y);
+        let scale = output_attachment_scale_factor;
+        let left = (left * scale).round() as u32;
+        let top = (top * scale).round() as u32;
+        let right = (right * scale).round() as u32;
+        let bottom = (bottom * scale).round() as u32;
+        Some(Scissor {
+            left,
+            top,
+            width: right.saturating_sub(left),
+            height: bottom.saturating_sub(top),
+        })
+    }
+}
+
+impl VectorPath {
+    pub(crate) fn new<I>(
+        events: I,
+        color: Color,
+        transform: Mat4,
+        options: draw::primitive::path::Options,
+    ) -> Self
+    where
+        I: IntoIterator<Item = PathEvent>,
+    {
+        let point = |p: lyon::math::Point| {
+            let p = transform.transform_point3(Vec3::new(p.x, p.y, 0.0));
+            lyon::math::point(p.x, p.y)
+        };
+        let events = events
+            .into_iter()
+            .map(|event| match event {
+                PathEvent::Begin { at } => PathEvent::Begin { at: point(at) },
+                PathEvent::Line { from, to } => PathEvent::Line {
+                    from: point(from),
+                    to: point(to),
+                },
+                PathEvent::Quadratic { from, ctrl, to } => PathEvent::Quadratic {
+                    from: point(from),
+                    ctrl: point(ctrl),
+                    to: point(to),
+                },
+                PathEvent::Cubic {
+                    from,
+                    ctrl1,
+                    ctrl2,
+                    to,
+                } => PathEvent::Cubic {
+                    from: point(from),
+                    ctrl1: point(ctrl1),
+                    ctrl2: point(ctrl2),
+                    to: point(to),
+                },
+                PathEvent::End { last, first, close } => PathEvent::End {
+                    last: point(last),
+                    first: point(first),
+                    close,
+                },
+            })
+            .collect();
+        let scale = transform
+            .x_axis
+            .truncate()
+            .cross(transform.y_axis.truncate());
+        let scale = scale.length().sqrt();
+        VectorPath {
+            events,
+            color,
+            options,
+            scale,
+            gradient: None,
+        }
+    }
+
+    /// Use the given gradient in place of the path's color.
+    pub(crate) fn with_gradient(
+        mut self,
+        gradient: Option<&draw::properties::gradient::Gradient>,
+        transform: Mat4,
+    ) -> Self {
+        self.gradient = gradient.map(|g| (g.clone(), transform));
+        self
+    }
+}
+
 impl RenderPrimitive for draw::Primitive {
     fn render_primitive(self, ctxt: RenderContext, mesh: &mut Mesh) {
         match self {
+            draw::Primitive::Arc(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Arrow(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Mesh(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Path(prim) => prim.render_primitive(ctxt, mesh),
@@ -45,7 +175,21 @@ impl RenderPrimitive for draw::Primitive {
             draw::Primitive::Ellipse(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Quad(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Rect(prim) => prim.render_primitive(ctxt, mesh),
+            draw::Primitive::Pie(prim) => prim.render_primitive(ctxt, mesh),
+            draw::Primitive::Ring(prim) => prim.render_primitive(ctxt, mesh),
+            draw::Primitive::Ngon(prim) => prim.render_primitive(ctxt, mesh),
+            draw::Primitive::NineSlice(prim) => prim.render_primitive(ctxt, mesh),
+            draw::Primitive::Star(prim) => prim.render_primitive(ctxt, mesh),
+            draw::Primitive::Cuboid(prim) => prim.render_primitive(ctxt, mesh),
+            draw::Primitive::Sphere(prim) => prim.render_primitive(ctxt, mesh),
+            draw::Primitive::Cylinder(prim) => prim.render_primitive(ctxt, mesh),
+            draw::Primitive::Cone(prim) => prim.render_primitive(ctxt, mesh),
+            draw::Primitive::Torus(prim) => prim.render_primitive(ctxt, mesh),
+            draw::Primitive::Plane(prim) => prim.render_primitive(ctxt, mesh),
+            draw::Primitive::Points(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Line(prim) => prim.render_primitive(ctxt, mesh),
+            draw::Primitive::Svg(prim) => prim.render_primitive(ctxt, mesh),
+            draw::Primitive::Picture(prim) => prim.render_primitive(ctxt, mesh),
             // `Text` is handled by the renderer directly (it renders as quads
             // textured by the glyph atlas rather than into the shared mesh), so it
             // falls through to the catch-all here, e.g. for instanced/indirect
@@ -54,3 +198,72 @@ impl RenderPrimitive for draw::Primitive {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use nannou_core::geom;
+
+    use super::*;
+
+    // The logical size of the output attachment used by each test.
+    const SIZE: Vec2 = Vec2::new(200.0, 100.0);
+
+    fn pixels(min: Vec2, max: Vec2, scale: f32) -> Option<Scissor> {
+        let rect = geom::Rect::from_corners(min, max);
+        Scissor::from_draw_scissor(draw::Scissor::Rect(rect), SIZE, scale)
+    }
+
+    fn scissor(left: u32, top: u32, width: u32, height: u32) -> Option<Scissor> {
+        Some(Scissor {
+            left,
+            top,
+            width,
+            height,
+        })
+    }
+
+    #[test]
+    fn test_full_scissor_uses_attachment() {
+        assert_eq!(
+            Scissor::from_draw_scissor(draw::Scissor::Full, SIZE, 1.0),
+            None
+        );
+    }
+
+    #[test]
+    fn test_scissor_flips_y() {
+        // The top-left quadrant of the window.
+        let top_left = pixels(Vec2::new(-100.0, 0.0), Vec2::new(0.0, 50.0), 1.0);
+        assert_eq!(top_left, scissor(0, 0, 100, 50));
+        // A small rect just above the bottom edge, right of centre.
+        let bottom = pixels(Vec2::new(0.0, -50.0), Vec2::new(20.0, -40.0), 1.0);
+        assert_eq!(bottom, scissor(100, 90, 20, 10));
+    }
+
+    #[test]
+    fn test_scissor_scale_factor() {
+        let bottom = pixels(Vec2::new(0.0, -50.0), Vec2::new(20.0, -40.0), 2.0);
+        assert_eq!(bottom, scissor(200, 180, 40, 20));
+        let fractional = pixels(Vec2::new(-10.0, -10.0), Vec2::new(10.0, 10.0), 1.5);
+        assert_eq!(fractional, scissor(135, 60, 30, 30));
+    }
+
+    #[test]
+    fn test_scissor_clamped_to_attachment() {
+        let larger = pixels(Vec2::new(-150.0, -80.0), Vec2::new(150.0, 80.0), 2.0);
+        assert_eq!(larger, scissor(0, 0, 400, 200));
+        let partial = pixels(Vec2::new(50.0, 0.0), Vec2::new(300.0, 10.0), 1.0);
+        assert_eq!(partial, scissor(150, 40, 50, 10));
+        // Rects entirely outside of the attachment are empty.
+        let outside = pixels(Vec2::new(150.0, 0.0), Vec2::new(200.0, 10.0), 1.0);
+        assert_eq!(outside.map(|s| s.width), Some(0));
+    }
+
+    #[test]
+    fn test_no_overlap_scissor_is_empty() {
+        assert_eq!(
+            Scissor::from_draw_scissor(draw::Scissor::NoOverlap, SIZE, 2.0),
+            scissor(0, 0, 0, 0)
+        );
+    }
+}
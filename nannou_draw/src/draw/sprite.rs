@@ -0,0 +1,198 @@
+//! Sprite sheets and frame animation for drawing sub-regions of a texture.
+//!
+//! A [SpriteSheet] pairs a texture with the normalised areas of its frames, e.g. sliced from a
+//! regular grid via [SpriteSheet::from_grid] or taken from a Bevy [TextureAtlasLayout]. Frames
+//! are drawn via [Draw::sprite](crate::draw::Draw::sprite), while a [SpriteAnimation] selects
+//! the frame to draw at a given time.
+
+use std::ops::Range;
+
+use bevy::image::TextureAtlasLayout;
+use bevy::prelude::*;
+use nannou_core::geom;
+
+/// A texture divided into a set of frames that may be drawn individually.
+///
+/// Frame areas are normalised texture coordinates, where `(0.0, 0.0)` is the top-left of the
+/// texture and `(1.0, 1.0)` is the bottom-right, as expected by `area` on textured primitives.
+#[derive(Clone, Debug)]
+pub struct SpriteSheet {
+    texture: Handle<Image>,
+    frames: Vec<geom::Rect>,
+    /// The size of the texture in pixels, if known.
+    size: Option<Vec2>,
+}
+
+/// How a [SpriteAnimation] behaves once it reaches its last frame.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Playback {
+    /// Start again from the first frame.
+    #[default]
+    Loop,
+    /// Hold the last frame.
+    Once,
+    /// Play the frames in reverse back to the first frame, then forwards again.
+    PingPong,
+}
+
+/// Plays through a range of sprite sheet frames at a fixed rate.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpriteAnimation {
+    frames: Range<usize>,
+    fps: f32,
+    playback: Playback,
+}
+
+impl SpriteSheet {
+    /// A sprite sheet with no frames.
+    ///
+    /// Frames may be added via [SpriteSheet::add_frame].
+    pub fn new(texture: Handle<Image>) -> Self {
+        SpriteSheet {
+            texture,
+            frames: vec![],
+            size: None,
+        }
+    }
+
+    /// Slice the texture into a grid of equally sized frames.
+    ///
+    /// Frames are indexed row by row, starting from the top-left of the texture.
+    pub fn from_grid(texture: Handle<Image>, columns: u32, rows: u32) -> Self {
+        let (columns, rows) = (columns.max(1), rows.max(1));
+        let frame = Vec2::new(1.0 / columns as f32, 1.0 / rows as f32);
+        let frames = (0..rows)
+            .flat_map(|row| (0..columns).map(move |column| (column, row)))
+            .map(|(column, row)| {
+                let min = Vec2::new(column as f32, row as f32) * frame;
+                geom::Rect::from_corners(min, min + frame)
+            })
+            .collect();
+        SpriteSheet {
+            texture,
+            frames,
+            size: None,
+        }
+    }
+
+    /// Use the frames of the given Bevy texture atlas layout.
+    ///
+    /// Layouts describe their frames in pixels, so frames drawn via
+    /// [Draw::sprite](crate::draw::Draw::sprite) default to their size in pixels.
+    pub fn from_layout(texture: Handle<Image>, layout: &TextureAtlasLayout) -> Self {
+        let size = layout.size.as_vec2().max(Vec2::ONE);
+        let frames = layout
+            .textures
+            .iter()
+            .map(|rect| {
+                geom::Rect::from_corners(rect.min.as_vec2() / size, rect.max.as_vec2() / size)
+            })
+            .collect();
+        SpriteSheet {
+            texture,
+            frames,
+            size: Some(size),
+        }
+    }
+
+    /// Specify the size of the texture in pixels.
+    ///
+    /// Frames drawn via [Draw::sprite](crate::draw::Draw::sprite) default to their size in pixels.
+    pub fn with_size(mut self, size: Vec2) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Add a frame with the given normalised area, returning its index.
+    pub fn add_frame(&mut self, area: geom::Rect) -> usize {
+        self.frames.push(area);
+        self.frames.len() - 1
+    }
+
+    /// The texture from which frames are drawn.
+    pub fn texture(&self) -> &Handle<Image> {
+        &self.texture
+    }
+
+    /// The number of frames.
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Whether the sprite sheet has no frames.
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// The normalised area of the frame at the given index.
+    pub fn area(&self, index: usize) -> Option<geom::Rect> {
+        self.frames.get(index).copied()
+    }
+
+    /// The size of the frame at the given index in pixels, if the texture size is known.
+    pub fn frame_size(&self, index: usize) -> Option<Vec2> {
+        let area = self.area(index)?;
+        self.size.map(|size| Vec2::new(area.w(), area.h()) * size)
+    }
+}
+
+impl SpriteAnimation {
+    /// Play the given range of frames at the given number of frames per second.
+    ///
+    /// The animation loops by default.
+    pub fn new(frames: Range<usize>, fps: f32) -> Self {
+        SpriteAnimation {
+            frames,
+            fps,
+            playback: Playback::Loop,
+        }
+    }
+
+    /// Specify how the animation behaves once it reaches its last frame.
+    pub fn playback(mut self, playback: Playback) -> Self {
+        self.playback = playback;
+        self
+    }
+
+    /// Play the animation once, holding the last frame.
+    pub fn once(self) -> Self {
+        self.playback(Playback::Once)
+    }
+
+    /// Play the animation back and forth.
+    pub fn ping_pong(self) -> Self {
+        self.playback(Playback::PingPong)
+    }
+
+    /// The number of frames played since the start of the animation at the given time.
+    fn elapsed_frames(&self, secs: f32) -> usize {
+        (secs.max(0.0) * self.fps.max(0.0)).floor() as usize
+    }
+
+    /// The index of the frame to draw the given number of seconds into the animation.
+    pub fn frame(&self, secs: f32) -> usize {
+        let Range { start, end } = self.frames;
+        let len = end.saturating_sub(start);
+        if len <= 1 {
+            return start;
+        }
+        let n = self.elapsed_frames(secs);
+        let offset = match self.playback {
+            Playback::Loop => n % len,
+            Playback::Once => n.min(len - 1),
+            Playback::PingPong => {
+                let period = 2 * (len - 1);
+                let n = n % period;
+                if n < len { n } else { period - n }
+            }
+        };
+        start + offset
+    }
+
+    /// Whether an animation played once has shown its last frame for a full frame's duration.
+    ///
+    /// Looping animations never finish.
+    pub fn is_finished(&self, secs: f32) -> bool {
+        self.playback == Playback::Once && self.elapsed_frames(secs) >= self.frames.len()
+    }
+}
@@ -0,0 +1,394 @@
+//! Writing the contents of a [Draw] to an SVG document.
+//!
+//! Rather than tessellating primitives into meshes, paths are collected in their original vector
+//! form along with their fill or stroke style. This allows for producing output suitable for
+//! print and pen-plotters without requiring a GPU. See [Draw::to_svg] for details.
+
+use std::fmt::Write;
+use std::ops::RangeFrom;
+use std::sync::Arc;
+
+use bevy::prelude::*;
+use lyon::path::PathEvent;
+use lyon::tessellation::{
+    FillRule, FillTessellator, LineCap, LineJoin, StrokeOptions, StrokeTessellator,
+};
+use nannou_core::geom;
+
+use crate::draw::mesh::MeshExt;
+use crate::draw::primitive::{Primitive, path};
+use crate::draw::properties::gradient::{Gradient, GradientKind, Interpolation};
+use crate::draw::render::{RenderContext, RenderPrimitive, VectorPath};
+use crate::draw::{Draw, DrawCommand, DrawContext, Mask, Scissor, mask};
+
+/// Produce an SVG document describing all drawings recorded by the given draw so far.
+pub(crate) fn document(draw: &Draw, rect: geom::Rect) -> String {
+    draw.finish_remaining_drawings();
+    let state = draw.state.read().unwrap();
+    let intermediary_state = state.intermediary_state.read().unwrap();
+    let cmds = state.draw_commands.iter().flatten();
+
+    let (x, y, w, h) = (rect.left(), 0.0 - rect.top(), rect.w(), rect.h());
+    let mut svg = String::new();
+    writeln!(svg, r#"<?xml version="1.0" encoding="UTF-8"?>"#).unwrap();
+    writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" version="1.1" width="{w}" height="{h}" viewBox="{x} {y} {w} {h}">"#
+    )
+    .unwrap();
+
+    // The background is cleared before anything is drawn, regardless of when it was set.
+    let background = cmds.clone().fold(None, |bg, cmd| match cmd {
+        DrawCommand::BackgroundColor(color) => Some(*color),
+        _ => bg,
+    });
+    if let Some(color) = background {
+        writeln!(
+            svg,
+            r#"<rect x="{x}" y="{y}" width="{w}" height="{h}"{}/>"#,
+            paint("fill", color)
+        )
+        .unwrap();
+    }
+
+    let mut fill_tessellator = FillTessellator::new();
+    let mut stroke_tessellator = StrokeTessellator::new();
+    let mut curr_ctx = DrawContext::default();
+    let mut clip = Clip::default();
+    let mut vector_paths = vec![];
+    let mut mesh = Mesh::init();
+    let mut gradient_ids = 0..;
+
+    for cmd in cmds {
+        match cmd {
+            DrawCommand::Primitive(Primitive::Text(prim)) => {
+                if clip.scissor == Scissor::NoOverlap {
+                    continue;
+                }
+                clip.update(&mut svg, rect);
+                let paths = prim.clone().vector_paths(
+                    &intermediary_state,
+                    &state.theme,
+                    &curr_ctx.transform,
+                    rect.wh(),
+                    &draw.text_cx,
+                );
+                for path in paths {
+                    write_path(&mut svg, &path, &mut gradient_ids);
+                }
+            }
+            DrawCommand::Primitive(prim) => {
+                if clip.scissor == Scissor::NoOverlap {
+                    continue;
+                }
+                clip.update(&mut svg, rect);
+                let ctxt = RenderContext {
+                    intermediary_mesh: &intermediary_state.intermediary_mesh,
+                    path_event_buffer: &intermediary_state.path_event_buffer,
+                    path_points_vertex_buffer: &intermediary_state.path_points_vertex_buffer,
+                    path_points_weighted_buffer: &intermediary_state.path_points_weighted_buffer,
+                    points_buffer: &intermediary_state.points_buffer,
+                    text_buffer: &intermediary_state.text_buffer,
+                    theme: &state.theme,
+                    transform: &curr_ctx.transform,
+                    fill_tessellator: &mut fill_tessellator,
+                    stroke_tessellator: &mut stroke_tessellator,
+                    output_attachment_size: rect.wh(),
+                    output_attachment_scale_factor: 1.0,
+                    vector_paths: Some(&mut vector_paths),
+                };
+                prim.clone().render_primitive(ctxt, &mut mesh);
+                for path in vector_paths.drain(..) {
+                    write_path(&mut svg, &path, &mut gradient_ids);
+                }
+                // Primitives without a vector form (e.g. meshes) are written as triangles.
+                write_triangles(&mut svg, &mesh);
+                mesh.clear();
+            }
+            // Instances are positioned by the GPU, so are not supported.
+            DrawCommand::Instanced(..) | DrawCommand::Indirect(..) => (),
+            DrawCommand::Context(ctx) => curr_ctx = ctx.clone(),
+            DrawCommand::ShaderModel(_) | DrawCommand::BackgroundColor(_) => (),
+            DrawCommand::Scissor(scissor) => {
+                clip.dirty |= clip.scissor != *scissor;
+                clip.scissor = *scissor;
+            }
+            DrawCommand::Mask(mask) => {
+                clip.dirty |= !mask::same(&clip.mask, mask);
+                clip.mask = mask.clone();
+            }
+        }
+    }
+
+    clip.close_groups(&mut svg);
+    writeln!(svg, "</svg>").unwrap();
+    svg
+}
+
+// The clipping currently applied to written elements.
+#[derive(Default)]
+struct Clip {
+    scissor: Scissor,
+    mask: Option<Arc<Mask>>,
+    // Whether the scissor or mask have changed since the open groups were written.
+    dirty: bool,
+    // The number of `<g>` elements currently open.
+    open_groups: usize,
+    // Used to generate unique IDs for clip paths and masks.
+    next_id: usize,
+}
+
+impl Clip {
+    // If the scissor or mask have changed, close any open groups and open new ones.
+    fn update(&mut self, svg: &mut String, rect: geom::Rect) {
+        if !self.dirty {
+            return;
+        }
+        self.dirty = false;
+        self.close_groups(svg);
+
+        if let Scissor::Rect(r) = self.scissor {
+            let id = self.next_id();
+            writeln!(
+                svg,
+                r#"<clipPath id="{id}"><rect x="{}" y="{}" width="{}" height="{}"/></clipPath>"#,
+                r.left(),
+                0.0 - r.top(),
+                r.w(),
+                r.h()
+            )
+            .unwrap();
+            self.open_group(svg, &format!(r#"clip-path="url(#{id})""#));
+        }
+
+        // Masks are nested within their parents, clipping to their overlap.
+        let mut masks = vec![];
+        let mut next = self.mask.clone();
+        while let Some(mask) = next {
+            next = mask.parent().cloned();
+            masks.push(mask);
+        }
+        for mask in masks.into_iter().rev() {
+            let id = self.next_id();
+            let d = triangles_path_data(mask.triangles());
+            if mask.is_inverse() {
+                let (x, y, w, h) = (rect.left(), 0.0 - rect.top(), rect.w(), rect.h());
+                writeln!(
+                    svg,
+                    r#"<mask id="{id}" maskUnits="userSpaceOnUse" x="{x}" y="{y}" width="{w}" height="{h}"><rect x="{x}" y="{y}" width="{w}" height="{h}" fill="white"/><path d="{d}" fill="black"/></mask>"#
+                )
+                .unwrap();
+                self.open_group(svg, &format!(r#"mask="url(#{id})""#));
+            } else {
+                writeln!(svg, r#"<clipPath id="{id}"><path d="{d}"/></clipPath>"#).unwrap();
+                self.open_group(svg, &format!(r#"clip-path="url(#{id})""#));
+            }
+        }
+    }
+
+    fn next_id(&mut self) -> String {
+        let id = format!("clip{}", self.next_id);
+        self.next_id += 1;
+        id
+    }
+
+    fn open_group(&mut self, svg: &mut String, attrs: &str) {
+        writeln!(svg, "<g {attrs}>").unwrap();
+        self.open_groups += 1;
+    }
+
+    fn close_groups(&mut self, svg: &mut String) {
+        for _ in 0..self.open_groups {
+            writeln!(svg, "</g>").unwrap();
+        }
+        self.open_groups = 0;
+    }
+}
+
+// Write a `<path>` element for the given vector path, preceded by its gradient if it has one.
+fn write_path(svg: &mut String, path: &VectorPath, gradient_ids: &mut RangeFrom<usize>) {
+    let d = path_data(&path.events);
+    if d.is_empty() {
+        return;
+    }
+    let mut path_paint = |property: &str, svg: &mut String| match &path.gradient {
+        Some((gradient, transform)) => {
+            let id = format!("gradient{}", gradient_ids.next().unwrap());
+            write_gradient(svg, &id, gradient, *transform);
+            format!(r#" {property}="url(#{id})""#)
+        }
+        None => paint(property, path.color),
+    };
+    let style = match &path.options {
+        path::Options::Fill(opts) => {
+            let mut style = path_paint("fill", svg);
+            if opts.fill_rule == FillRule::EvenOdd {
+                style.push_str(r#" fill-rule="evenodd""#);
+            }
+            style
+        }
+        path::Options::Stroke(opts) => {
+            format!(
+                r#" fill="none"{}{}"#,
+                path_paint("stroke", svg),
+                stroke_style(opts, path.scale)
+            )
+        }
+    };
+    writeln!(svg, r#"<path d="{d}"{style}/>"#).unwrap();
+}
+
+// Write a `<linearGradient>` or `<radialGradient>` element for the given gradient, where
+// `transform` maps from the gradient's local space to that of the drawing.
+//
+// SVG only interpolates in sRGB or linear RGB and clamps offsets to `0.0..=1.0`, so other
+// gradients are approximated by sampling additional stops.
+fn write_gradient(svg: &mut String, id: &str, gradient: &Gradient, transform: Mat4) {
+    // Flip the *y* axis of the transformed points, as in `svg_point`.
+    let (x, y, w) = (transform.x_axis, transform.y_axis, transform.w_axis);
+    let matrix = [x.x, 0.0 - x.y, y.x, 0.0 - y.y, w.x, 0.0 - w.y].map(|v| v.to_string());
+    let attrs = format!(
+        r#"id="{id}" gradientUnits="userSpaceOnUse" gradientTransform="matrix({})""#,
+        matrix.join(" ")
+    );
+    let (element, shape) = match gradient.kind {
+        Some(GradientKind::Linear { start, end }) => (
+            "linearGradient",
+            format!(
+                r#"x1="{}" y1="{}" x2="{}" y2="{}""#,
+                start.x, start.y, end.x, end.y
+            ),
+        ),
+        Some(GradientKind::Radial { center, radius }) => (
+            "radialGradient",
+            format!(r#"cx="{}" cy="{}" r="{radius}""#, center.x, center.y),
+        ),
+        None => return,
+    };
+    let interpolation = match gradient.interpolation {
+        Interpolation::Linear => r#" color-interpolation="linearRGB""#,
+        Interpolation::Srgb | Interpolation::Oklab => "",
+    };
+    writeln!(svg, "<defs><{element} {attrs} {shape}{interpolation}>").unwrap();
+
+    const SAMPLES: usize = 32;
+    let resample = gradient.interpolation == Interpolation::Oklab
+        || gradient.stops.iter().any(|(o, _)| !(0.0..=1.0).contains(o));
+    let stops: Vec<(f32, Color)> = if resample {
+        (0..=SAMPLES)
+            .map(|i| i as f32 / SAMPLES as f32)
+            .map(|t| (t, gradient.color_at_position(t)))
+            .collect()
+    } else {
+        gradient.stops.clone()
+    };
+    for (offset, color) in stops {
+        let (hex, alpha) = hex_color(color);
+        writeln!(
+            svg,
+            r#"<stop offset="{offset}" stop-color="{hex}" stop-opacity="{alpha}"/>"#
+        )
+        .unwrap();
+    }
+    writeln!(svg, "</{element}></defs>").unwrap();
+}
+
+// Write each triangle of the given mesh as a `<path>` element filled with its average color.
+fn write_triangles(svg: &mut String, mesh: &Mesh) {
+    let points = mesh.points();
+    let colors = mesh.colors();
+    for tri in 0..mesh.count_indices() / 3 {
+        let ix = |i: usize| mesh.get_index(tri * 3 + i) as usize;
+        let [a, b, c] = [ix(0), ix(1), ix(2)];
+        let color = (Vec4::from(colors[a]) + Vec4::from(colors[b]) + Vec4::from(colors[c])) / 3.0;
+        let color = Color::from(LinearRgba::from_f32_array(color.to_array()));
+        let p = |i: usize| Vec2::new(points[i][0], points[i][1]);
+        let d = triangles_path_data(&[[p(a), p(b), p(c)]]);
+        writeln!(svg, r#"<path d="{d}"{}/>"#, paint("fill", color)).unwrap();
+    }
+}
+
+// Color and opacity attributes for the given paint property (i.e. `fill` or `stroke`).
+fn paint(property: &str, color: Color) -> String {
+    let (hex, alpha) = hex_color(color);
+    let mut attrs = format!(r#" {property}="{hex}""#);
+    if alpha < 1.0 {
+        write!(attrs, r#" {property}-opacity="{alpha}""#).unwrap();
+    }
+    attrs
+}
+
+// The given color as a hex sRGB string along with its alpha.
+fn hex_color(color: Color) -> (String, f32) {
+    let Srgba {
+        red,
+        green,
+        blue,
+        alpha,
+    } = Srgba::from(color);
+    let [r, g, b] = [red, green, blue].map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8);
+    (format!("#{r:02x}{g:02x}{b:02x}"), alpha)
+}
+
+fn stroke_style(opts: &StrokeOptions, scale: f32) -> String {
+    let cap = match opts.start_cap {
+        LineCap::Butt => "butt",
+        LineCap::Square => "square",
+        LineCap::Round => "round",
+    };
+    let join = match opts.line_join {
+        LineJoin::Miter => "miter",
+        LineJoin::MiterClip => "miter-clip",
+        LineJoin::Round => "round",
+        LineJoin::Bevel => "bevel",
+    };
+    format!(
+        r#" stroke-width="{}" stroke-linecap="{cap}" stroke-linejoin="{join}" stroke-miterlimit="{}""#,
+        opts.line_width * scale,
+        opts.miter_limit,
+    )
+}
+
+// SVG path data for the given events, flipping the *y* axis to SVG's y-down coordinates.
+fn path_data(events: &[PathEvent]) -> String {
+    let p = |p: lyon::math::Point| svg_point(Vec2::new(p.x, p.y));
+    let mut d = String::new();
+    for event in events {
+        let _ = match *event {
+            PathEvent::Begin { at } => write!(d, "M{} ", p(at)),
+            PathEvent::Line { to, .. } => write!(d, "L{} ", p(to)),
+            PathEvent::Quadratic { ctrl, to, .. } => write!(d, "Q{} {} ", p(ctrl), p(to)),
+            PathEvent::Cubic {
+                ctrl1, ctrl2, to, ..
+            } => write!(d, "C{} {} {} ", p(ctrl1), p(ctrl2), p(to)),
+            PathEvent::End { close: true, .. } => write!(d, "Z "),
+            PathEvent::End { close: false, .. } => Ok(()),
+        };
+    }
+    d.truncate(d.trim_end().len());
+    d
+}
+
+// SVG path data for the given triangles, all wound in the same direction so that they form
+// their union under the default `nonzero` fill rule.
+fn triangles_path_data(triangles: &[[Vec2; 3]]) -> String {
+    let mut d = String::new();
+    for &[a, b, c] in triangles {
+        let [a, b, c] = if (b - a).perp_dot(c - a) >= 0.0 {
+            [a, b, c]
+        } else {
+            [a, c, b]
+        };
+        let (a, b, c) = (svg_point(a), svg_point(b), svg_point(c));
+        let _ = write!(d, "M{a} L{b} L{c} Z ");
+    }
+    d.truncate(d.trim_end().len());
+    d
+}
+
+// Format the point as SVG coordinates, flipping the *y* axis.
+//
+// Adding to zero avoids writing negative zero as `-0`.
+fn svg_point(p: Vec2) -> String {
+    format!("{} {}", p.x + 0.0, 0.0 - p.y)
+}
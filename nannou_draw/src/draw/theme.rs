@@ -24,17 +24,29 @@ pub struct ThemeColor {
 /// These are used as keys into the **Theme**'s geometry primitive default values.
 #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
 pub enum Primitive {
+    Arc,
     Arrow,
+    Cone,
     Cuboid,
+    Cylinder,
     Ellipse,
     Line,
     Mesh,
+    Ngon,
+    NineSlice,
     Path,
+    Pie,
+    Plane,
+    Points,
     Polygon,
     Quad,
     Rect,
+    Ring,
+    Sphere,
+    Star,
     Text,
     Texture,
+    Torus,
     Tri,
 }
 
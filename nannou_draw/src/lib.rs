@@ -12,14 +12,22 @@
 //!
 //! [Bevy]: https://bevyengine.org
 
-use crate::render::NannouRenderPlugin;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, Weak};
+
+use crate::render::{NannouCamera, NannouRenderPlugin};
+use bevy::asset::StrongHandle;
 use bevy::prelude::*;
 use draw::Draw;
 use text::font::SharedTextCx;
 
+pub mod accumulate;
 pub mod color;
 pub mod draw;
+#[cfg(feature = "serde")]
+pub mod record;
 pub mod render;
+pub mod svg;
 pub mod text;
 
 pub struct NannouDrawPlugin;
@@ -31,11 +39,32 @@ impl Plugin for NannouDrawPlugin {
             app.add_plugins(bevy::text::TextPlugin);
         }
         text::font::init_shared_text_cx(app);
-        app.init_resource::<DrawFrozen>()
+        app.init_asset::<svg::Svg>()
+            .init_asset_loader::<svg::SvgLoader>()
+            .init_resource::<DrawFrozen>()
+            .init_resource::<ImageDraws>()
             .add_plugins(NannouRenderPlugin)
             // `spawn_draw` stays ungated so newly created windows always get a `Draw`;
             // `reset_draw` is skipped while frozen so the recorded scene is retained.
-            .add_systems(First, (spawn_draw, reset_draw.run_if(draw_active)).chain());
+            .add_systems(
+                First,
+                (
+                    despawn_unused_image_draws,
+                    spawn_draw,
+                    reset_draw.run_if(draw_active),
+                )
+                    .chain(),
+            )
+            .add_systems(
+                PostUpdate,
+                attach_image_targets.before(render::update_draw_mesh),
+            )
+            // Resolve loaded font families once bevy has registered the fonts themselves.
+            .add_systems(
+                PostUpdate,
+                text::font::update_font_families
+                    .after(bevy::text::load_font_assets_into_font_collection),
+            );
     }
 }
 
@@ -53,6 +82,97 @@ pub(crate) fn draw_active(frozen: Res<DrawFrozen>) -> bool {
     !frozen.0
 }
 
+/// The [Draw] instances rendering into offscreen images, keyed by image.
+///
+/// Each image is rendered via its own [NannouCamera], spawned the first time a [Draw] is requested
+/// for the image. The camera is despawned once the image is removed from `Assets<Image>` or once
+/// the handle with which the draw was first requested, along with all of its clones, is dropped.
+#[derive(Resource, Default)]
+pub struct ImageDraws(Mutex<HashMap<AssetId<Image>, ImageDraw>>);
+
+// The draw rendering into an image, along with the handle with which it was requested.
+struct ImageDraw {
+    draw: Draw,
+    // `None` for images with a UUID, which live until they are removed.
+    handle: Option<Weak<StrongHandle>>,
+    // Whether the camera has been given its own handle to the image as its render target.
+    has_target: bool,
+}
+
+impl ImageDraws {
+    /// The [Draw] rendering into the given image, spawning its camera via `commands` if necessary.
+    ///
+    /// Like a window's [Draw], it is reset at the start of each frame. The image must be usable
+    /// as a render attachment, e.g. as created via [render::new_target_image]. Once rendered, it
+    /// may be drawn by other draws via `Drawing::texture`.
+    pub fn draw(
+        &self,
+        commands: &mut Commands,
+        image: &Handle<Image>,
+        text_cx: &SharedTextCx,
+    ) -> Draw {
+        let mut draws = self.0.lock().unwrap();
+        draws
+            .entry(image.id())
+            .or_insert_with(|| {
+                // The camera is given its own handle to the image once it has been added, rather
+                // than a clone of the given handle, so that dropping the latter may be detected.
+                let mut camera = commands.spawn_empty();
+                let draw = Draw::new(camera.id(), text_cx.clone());
+                camera.insert(draw.clone());
+                let handle = match image {
+                    Handle::Strong(handle) => Some(Arc::downgrade(handle)),
+                    Handle::Uuid(..) => None,
+                };
+                ImageDraw {
+                    draw,
+                    handle,
+                    has_target: false,
+                }
+            })
+            .draw
+            .clone()
+    }
+}
+
+fn despawn_unused_image_draws(
+    mut commands: Commands,
+    image_draws: Res<ImageDraws>,
+    images: Res<Assets<Image>>,
+) {
+    image_draws.0.lock().unwrap().retain(|image, image_draw| {
+        let dropped = image_draw
+            .handle
+            .as_ref()
+            .is_some_and(|handle| handle.strong_count() == 0);
+        let removed = image_draw.has_target && !images.contains(*image);
+        let used = !dropped && !removed;
+        if !used {
+            commands.entity(image_draw.draw.window).despawn();
+        }
+        used
+    });
+}
+
+// Give the camera of each new image draw its render target once the image has been added.
+fn attach_image_targets(
+    mut commands: Commands,
+    image_draws: Res<ImageDraws>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    for (&image, image_draw) in image_draws.0.lock().unwrap().iter_mut() {
+        if image_draw.has_target {
+            continue;
+        }
+        if let Some(image) = images.get_strong_handle(image) {
+            commands
+                .entity(image_draw.draw.window)
+                .insert(NannouCamera::for_image(image));
+            image_draw.has_target = true;
+        }
+    }
+}
+
 fn reset_draw(mut draw_q: Query<&mut Draw>) {
     for mut draw in draw_q.iter_mut() {
         draw.reset();
@@ -70,3 +190,120 @@ fn spawn_draw(
             .insert(Draw::new(entity, text_cx.clone()));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+    use parley::{FontContext, LayoutContext};
+
+    use super::*;
+    use crate::text::font::NannouTextCxInner;
+
+    fn new_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<Assets<Image>>();
+        world.init_resource::<ImageDraws>();
+        world.insert_resource(SharedTextCx(Arc::new(Mutex::new(NannouTextCxInner {
+            font: FontContext::default(),
+            layout: LayoutContext::new(),
+        }))));
+        world
+    }
+
+    // Request the draw of the given image, as `App::draw_to_image` does.
+    fn image_draw(world: &mut World, image: &Handle<Image>) -> Draw {
+        let image = image.clone();
+        let draw = world
+            .run_system_once(
+                move |mut commands: Commands,
+                      image_draws: Res<ImageDraws>,
+                      text_cx: Res<SharedTextCx>| {
+                    image_draws.draw(&mut commands, &image, &text_cx)
+                },
+            )
+            .unwrap();
+        world.run_system_once(attach_image_targets).unwrap();
+        draw
+    }
+
+    fn camera_count(world: &mut World) -> usize {
+        world
+            .query_filtered::<(), With<NannouCamera>>()
+            .iter(world)
+            .count()
+    }
+
+    #[test]
+    fn test_image_draw_registered_once() {
+        let mut world = new_world();
+        let image = world
+            .resource_mut::<Assets<Image>>()
+            .add(render::new_target_image(16, 16));
+        let first = image_draw(&mut world, &image);
+        let second = image_draw(&mut world, &image);
+        // Both requests share the draw and camera registered for the image.
+        assert_eq!(first.window, second.window);
+        assert_eq!(camera_count(&mut world), 1);
+        assert!(world.get::<Draw>(first.window).is_some());
+
+        // Each image has its own draw.
+        let other = world
+            .resource_mut::<Assets<Image>>()
+            .add(render::new_target_image(16, 16));
+        assert_ne!(image_draw(&mut world, &other).window, first.window);
+        assert_eq!(camera_count(&mut world), 2);
+    }
+
+    #[test]
+    fn test_image_draw_reset_each_frame() {
+        let mut world = new_world();
+        let image = world
+            .resource_mut::<Assets<Image>>()
+            .add(render::new_target_image(16, 16));
+        let draw = image_draw(&mut world, &image);
+        draw.ellipse();
+        world.run_system_once(reset_draw).unwrap();
+        assert_eq!(draw.drain_commands().count(), 0);
+    }
+
+    #[test]
+    fn test_image_draw_removed_with_image() {
+        let mut world = new_world();
+        let image = world
+            .resource_mut::<Assets<Image>>()
+            .add(render::new_target_image(16, 16));
+        let draw = image_draw(&mut world, &image);
+        world.run_system_once(despawn_unused_image_draws).unwrap();
+        assert!(world.get_entity(draw.window).is_ok());
+
+        world.resource_mut::<Assets<Image>>().remove(&image);
+        world.run_system_once(despawn_unused_image_draws).unwrap();
+        assert!(world.get_entity(draw.window).is_err());
+        assert!(world.resource::<ImageDraws>().0.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_image_draw_removed_with_dropped_handle() {
+        let mut world = new_world();
+        let image = world
+            .resource_mut::<Assets<Image>>()
+            .add(render::new_target_image(16, 16));
+        let id = image.id();
+        let draw = image_draw(&mut world, &image);
+        // Clones of the handle keep the draw alive.
+        let clone = image.clone();
+        drop(image);
+        world.run_system_once(despawn_unused_image_draws).unwrap();
+        assert!(world.get_entity(draw.window).is_ok());
+
+        drop(clone);
+        world.run_system_once(despawn_unused_image_draws).unwrap();
+        assert!(world.get_entity(draw.window).is_err());
+        assert!(world.resource::<ImageDraws>().0.lock().unwrap().is_empty());
+        // The camera's handle no longer keeps the image alive.
+        world
+            .run_system_once(Assets::<Image>::track_assets)
+            .unwrap();
+        assert!(!world.resource::<Assets<Image>>().contains(id));
+    }
+}
@@ -5,6 +5,7 @@
 }
 
 const FLAGS_TEXTURE_BIT: u32 = 1u;
+const FLAGS_PREMULTIPLY_BIT: u32 = 2u;
 
 struct ShaderModel {
     color: vec4<f32>,
@@ -81,5 +82,9 @@ fn fragment(
         output_color = output_color * textureSample(texture, texture_sampler, mesh.uv);
     }
 
+    if ((model.flags & FLAGS_PREMULTIPLY_BIT) != 0u) {
+        output_color = vec4<f32>(output_color.rgb * output_color.a, output_color.a);
+    }
+
     return output_color;
 }
@@ -0,0 +1,74 @@
+#import bevy_pbr::{
+    mesh_functions,
+    mesh_view_bindings::view,
+}
+
+const FLAGS_ROUND_BIT: u32 = 1u;
+const FLAGS_WORLD_SPACE_BIT: u32 = 2u;
+const FLAGS_PREMULTIPLY_BIT: u32 = 4u;
+
+struct PointsShaderModel {
+    scale_factor: f32,
+    flags: u32,
+};
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(0) var<uniform> model: PointsShaderModel;
+
+struct Vertex {
+    @builtin(instance_index) instance_index: u32,
+    @location(0) position: vec3<f32>,
+    // The corner of the sprite, in the range -1 to 1.
+    @location(1) corner: vec2<f32>,
+    @location(2) color: vec4<f32>,
+    @location(3) size: f32,
+};
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) corner: vec2<f32>,
+    @location(1) color: vec4<f32>,
+};
+
+@vertex
+fn vertex(vertex: Vertex) -> VertexOutput {
+    var out: VertexOutput;
+
+    let world_from_local = mesh_functions::get_world_from_local(vertex.instance_index);
+    let center = mesh_functions::mesh_position_local_to_world(world_from_local, vec4<f32>(vertex.position, 1.0));
+    let offset = vertex.corner * vertex.size * 0.5;
+
+    if ((model.flags & FLAGS_WORLD_SPACE_BIT) != 0u) {
+        // Offset the corner within the plane facing the camera.
+        let right = view.world_from_view[0].xyz;
+        let up = view.world_from_view[1].xyz;
+        let world_position = center.xyz + right * offset.x + up * offset.y;
+        out.position = view.clip_from_world * vec4<f32>(world_position, 1.0);
+    } else {
+        // Offset the corner in logical pixels, regardless of depth.
+        out.position = view.clip_from_world * vec4<f32>(center.xyz, 1.0);
+        let ndc_offset = offset * model.scale_factor * 2.0 / view.viewport.zw;
+        out.position = out.position + vec4<f32>(ndc_offset * out.position.w, 0.0, 0.0);
+    }
+
+    out.corner = vertex.corner;
+    out.color = vertex.color;
+    return out;
+}
+
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    var color = in.color;
+    if ((model.flags & FLAGS_ROUND_BIT) != 0u) {
+        // Smooth the edge of the disc over roughly one pixel.
+        let distance = length(in.corner);
+        let edge = fwidth(distance);
+        color.a = color.a * (1.0 - smoothstep(1.0 - edge, 1.0, distance));
+        if (color.a <= 0.0) {
+            discard;
+        }
+    }
+    if ((model.flags & FLAGS_PREMULTIPLY_BIT) != 0u) {
+        color = vec4<f32>(color.rgb * color.a, color.a);
+    }
+    return color;
+}
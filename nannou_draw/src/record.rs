@@ -0,0 +1,550 @@
+//! Recording the draw commands of each frame and replaying them later.
+//!
+//! With the `serde` feature enabled, [DrawCommand]s and all primitives may be serialized. A
+//! [Frame] captures the commands recorded by a [Draw] along with the buffers and shader models
+//! that they refer to. Adding a [DrawRecorder] to a window writes each of its frames as a line of
+//! JSON, while a [DrawPlayer] reads them back and feeds them into a [Draw]. This is useful for
+//! diffing frames in tests, sending a sketch's output to another machine and reproducing visual
+//! bugs exactly.
+//!
+//! A few things cannot be recorded:
+//!
+//! - Custom shader models. Primitives drawn with them are replayed with the draw's current model.
+//! - Textures without an asset path, e.g. those created at runtime. Textures with an asset path
+//!   are loaded again when replayed via [Frame::draw_with_assets] or [DrawPlayer::with_assets].
+//! - Indirect drawings, as their buffers live on the GPU.
+
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+
+use bevy::asset::UntypedAssetId;
+use bevy::prelude::*;
+use bevy::render::render_resource::{BlendState, PolygonMode};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::draw::mesh::MeshExt;
+use crate::draw::primitive::Primitive;
+use crate::draw::{Draw, DrawCommand, IntermediaryState};
+use crate::render::DefaultNannouShaderModel;
+use crate::svg::Svg;
+
+/// The draw commands of a single frame, along with everything that they refer to.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Frame {
+    /// The recorded draw commands, in the order in which they were drawn.
+    pub commands: Vec<DrawCommand>,
+    /// The buffers referred to by primitives, e.g. path events, mesh vertices and text.
+    pub buffers: IntermediaryState,
+    /// The default shader models selected by the frame's commands, keyed by ID.
+    models: HashMap<Uuid, RecordedModel>,
+}
+
+/// The serializable parts of a [DefaultNannouShaderModel].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct RecordedModel {
+    color: Color,
+    /// The asset path of the texture, if any.
+    texture: Option<String>,
+    polygon_mode: PolygonMode,
+    blend: Option<BlendState>,
+    #[serde(default)]
+    premultiply_alpha: bool,
+}
+
+/// Writes each frame drawn by the [Draw] of the entity to which it is added as a line of JSON.
+///
+/// Frames are written after the app's `Update` schedule, just before the draw is rendered. If a
+/// frame fails to be written, an error is logged and the recorder is removed.
+#[derive(Component)]
+pub struct DrawRecorder {
+    writer: Box<dyn Write + Send + Sync>,
+}
+
+/// Reads frames written by a [DrawRecorder] and feeds them into a [Draw].
+pub struct DrawPlayer {
+    reader: Box<dyn BufRead + Send + Sync>,
+    assets: Option<AssetServer>,
+    line: String,
+}
+
+/// Errors that might occur while recording or replaying frames.
+#[derive(Debug, Error)]
+pub enum RecordError {
+    #[error("failed to read or write a frame: {0}")]
+    Io(#[from] io::Error),
+    #[error("failed to serialize or deserialize a frame: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// The amount by which to offset the buffer ranges of a replayed primitive.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct BufferOffsets {
+    pub(crate) vertices: usize,
+    pub(crate) indices: usize,
+    pub(crate) path_events: usize,
+    pub(crate) path_points: usize,
+    pub(crate) path_points_weighted: usize,
+    pub(crate) points: usize,
+    pub(crate) text: usize,
+}
+
+impl Frame {
+    /// Capture all commands drawn to the given draw so far this frame.
+    ///
+    /// The draw itself is left untouched. SVG primitives drawn via an asset handle are only
+    /// captured once resolved via [Frame::resolve_svgs].
+    pub fn from_draw(draw: &Draw) -> Self {
+        draw.finish_remaining_drawings();
+        let state = draw.state.read().unwrap();
+        let buffers = state.intermediary_state.read().unwrap().clone();
+        let mut models = HashMap::new();
+        let mut commands = Vec::with_capacity(state.draw_commands.len());
+        for cmd in state.draw_commands.iter().flatten() {
+            match cmd {
+                DrawCommand::Indirect(..) => {
+                    bevy::log::warn_once!("indirect drawings cannot be recorded and are skipped");
+                    continue;
+                }
+                DrawCommand::ShaderModel(id) => {
+                    let model = state.shader_models.get(id).and_then(|model| {
+                        model.as_any().downcast_ref::<DefaultNannouShaderModel>()
+                    });
+                    match (id, model) {
+                        (UntypedAssetId::Uuid { uuid, .. }, Some(model)) => {
+                            models
+                                .entry(*uuid)
+                                .or_insert_with(|| RecordedModel::new(model));
+                        }
+                        _ => bevy::log::warn_once!(
+                            "custom shader models cannot be recorded; primitives drawn with \
+                             them are replayed with the shader model of the replaying draw"
+                        ),
+                    }
+                }
+                _ => (),
+            }
+            commands.push(cmd.clone());
+        }
+        Frame {
+            commands,
+            buffers,
+            models,
+        }
+    }
+
+    /// Look up the paths of SVG primitives drawn via an asset handle, so that they are recorded.
+    pub fn resolve_svgs(&mut self, svgs: &Assets<Svg>) {
+        for cmd in &mut self.commands {
+            if let DrawCommand::Primitive(Primitive::Svg(svg))
+            | DrawCommand::Instanced(Primitive::Svg(svg), _) = cmd
+            {
+                svg.resolve(svgs);
+            }
+        }
+    }
+
+    /// Draw the frame's commands to the given draw, after anything already drawn.
+    ///
+    /// Recorded textures are not loaded. See [Frame::draw_with_assets].
+    pub fn draw(&self, draw: &Draw) {
+        self.replay(draw, None);
+    }
+
+    /// Draw the frame's commands to the given draw, loading recorded textures via `assets`.
+    pub fn draw_with_assets(&self, draw: &Draw, assets: &AssetServer) {
+        self.replay(draw, Some(assets));
+    }
+
+    fn replay(&self, draw: &Draw, assets: Option<&AssetServer>) {
+        draw.finish_remaining_drawings();
+        let mut state = draw.state.write().unwrap();
+        let offsets = extend_buffers(
+            &mut state.intermediary_state.write().unwrap(),
+            &self.buffers,
+        );
+        let type_id = TypeId::of::<DefaultNannouShaderModel>();
+        for (&uuid, model) in &self.models {
+            let id = UntypedAssetId::Uuid { type_id, uuid };
+            state
+                .shader_models
+                .entry(id)
+                .or_insert_with(|| Box::new(model.model(assets)));
+        }
+        // The frame's commands assume no scissor or mask, as they were recorded from a fresh draw.
+        state.reset_clip();
+        for cmd in &self.commands {
+            let mut cmd = cmd.clone();
+            match &mut cmd {
+                DrawCommand::Primitive(prim) | DrawCommand::Instanced(prim, _) => {
+                    prim.offset_buffers(&offsets);
+                    if let Primitive::Picture(picture) = prim {
+                        for layer in picture.layers() {
+                            state
+                                .shader_models
+                                .entry(layer.model_id)
+                                .or_insert_with(|| layer.model.clone_erased());
+                        }
+                    }
+                }
+                // Models that were not recorded fall back to the draw's current model.
+                DrawCommand::ShaderModel(id) => {
+                    if !state.shader_models.contains_key(id) {
+                        *id = draw.shader_model;
+                    }
+                }
+                _ => (),
+            }
+            state.push_command(cmd);
+        }
+    }
+}
+
+impl RecordedModel {
+    pub(crate) fn new(model: &DefaultNannouShaderModel) -> Self {
+        let texture = model.texture.as_ref().and_then(|texture| {
+            let path = texture.path().map(|path| path.to_string());
+            if path.is_none() {
+                bevy::log::warn_once!("textures without an asset path cannot be recorded");
+            }
+            path
+        });
+        RecordedModel {
+            color: model.color,
+            texture,
+            polygon_mode: model.polygon_mode,
+            blend: model.blend,
+            premultiply_alpha: model.premultiply_alpha,
+        }
+    }
+
+    /// Produce the recorded model, loading its texture via `assets` if given.
+    pub(crate) fn model(&self, assets: Option<&AssetServer>) -> DefaultNannouShaderModel {
+        let texture = self
+            .texture
+            .as_ref()
+            .zip(assets)
+            .map(|(path, assets)| assets.load(path.clone()));
+        DefaultNannouShaderModel {
+            color: self.color,
+            texture,
+            polygon_mode: self.polygon_mode,
+            blend: self.blend,
+            premultiply_alpha: self.premultiply_alpha,
+        }
+    }
+}
+
+impl DrawRecorder {
+    /// Record frames to the file at the given path, replacing any existing file.
+    pub fn create<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let file = File::create(path)?;
+        Ok(Self::new(BufWriter::new(file)))
+    }
+
+    /// Record frames to the given writer, e.g. a network stream.
+    pub fn new<W>(writer: W) -> Self
+    where
+        W: Write + Send + Sync + 'static,
+    {
+        DrawRecorder {
+            writer: Box::new(writer),
+        }
+    }
+
+    /// Write the given frame as a single line of JSON.
+    pub fn write_frame(&mut self, frame: &Frame) -> Result<(), RecordError> {
+        serde_json::to_writer(&mut self.writer, frame)?;
+        self.writer.write_all(b"\n")?;
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+impl DrawPlayer {
+    /// Replay the frames recorded to the file at the given path.
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let file = File::open(path)?;
+        Ok(Self::new(BufReader::new(file)))
+    }
+
+    /// Replay the frames read from the given reader, e.g. a network stream.
+    pub fn new<R>(reader: R) -> Self
+    where
+        R: BufRead + Send + Sync + 'static,
+    {
+        DrawPlayer {
+            reader: Box::new(reader),
+            assets: None,
+            line: String::new(),
+        }
+    }
+
+    /// Load the recorded textures via the given asset server.
+    pub fn with_assets(mut self, assets: AssetServer) -> Self {
+        self.assets = Some(assets);
+        self
+    }
+
+    /// Read the next frame, or `None` once the end of the recording is reached.
+    pub fn next_frame(&mut self) -> Result<Option<Frame>, RecordError> {
+        loop {
+            self.line.clear();
+            if self.reader.read_line(&mut self.line)? == 0 {
+                return Ok(None);
+            }
+            if !self.line.trim().is_empty() {
+                return Ok(Some(serde_json::from_str(&self.line)?));
+            }
+        }
+    }
+
+    /// Draw the next frame to the given draw.
+    ///
+    /// Returns `false` once the end of the recording is reached.
+    pub fn play(&mut self, draw: &Draw) -> Result<bool, RecordError> {
+        let Some(frame) = self.next_frame()? else {
+            return Ok(false);
+        };
+        frame.replay(draw, self.assets.as_ref());
+        Ok(true)
+    }
+}
+
+// Append the `src` buffers to `dst`, returning the offsets at which they were appended.
+fn extend_buffers(dst: &mut IntermediaryState, src: &IntermediaryState) -> BufferOffsets {
+    let offsets = BufferOffsets {
+        vertices: dst.intermediary_mesh.count_vertices(),
+        indices: dst.intermediary_mesh.count_indices(),
+        path_events: dst.path_event_buffer.len(),
+        path_points: dst.path_points_vertex_buffer.len(),
+        path_points_weighted: dst.path_points_weighted_buffer.len(),
+        points: dst.points_buffer.len(),
+        text: dst.text_buffer.len(),
+    };
+    let (dst_mesh, src_mesh) = (&mut dst.intermediary_mesh, &src.intermediary_mesh);
+    dst_mesh.points_mut().extend_from_slice(src_mesh.points());
+    dst_mesh.colors_mut().extend_from_slice(src_mesh.colors());
+    dst_mesh
+        .tex_coords_mut()
+        .extend_from_slice(src_mesh.tex_coords());
+    dst_mesh.normals_mut().extend_from_slice(src_mesh.normals());
+    for i in 0..src_mesh.count_indices() {
+        dst_mesh.push_index(offsets.vertices as u32 + src_mesh.get_index(i));
+    }
+    dst.path_event_buffer
+        .extend_from_slice(&src.path_event_buffer);
+    dst.path_points_vertex_buffer
+        .extend_from_slice(&src.path_points_vertex_buffer);
+    dst.path_points_weighted_buffer
+        .extend_from_slice(&src.path_points_weighted_buffer);
+    dst.points_buffer.extend_from_slice(&src.points_buffer);
+    dst.text_buffer.push_str(&src.text_buffer);
+    offsets
+}
+
+// Write the frame drawn by each recording entity.
+pub(crate) fn record_draws(
+    mut commands: Commands,
+    mut recorders: Query<(Entity, &Draw, &mut DrawRecorder)>,
+    svgs: Res<Assets<Svg>>,
+) {
+    for (entity, draw, mut recorder) in recorders.iter_mut() {
+        let mut frame = Frame::from_draw(draw);
+        frame.resolve_svgs(&svgs);
+        if let Err(err) = recorder.write_frame(&frame) {
+            error!("failed to record frame, stopping recording: {err}");
+            commands.entity(entity).remove::<DrawRecorder>();
+        }
+    }
+}
+
+// Serialization of types from other crates.
+
+/// Shader model IDs, serialized by UUID.
+///
+/// All shader models are registered with a draw under a UUID. The type is restored as that of the
+/// default shader model, as only default models are recorded.
+pub(crate) mod asset_id {
+    use std::any::TypeId;
+
+    use bevy::asset::UntypedAssetId;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer, ser};
+    use uuid::Uuid;
+
+    use crate::render::DefaultNannouShaderModel;
+
+    pub fn serialize<S>(id: &UntypedAssetId, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match id {
+            UntypedAssetId::Uuid { uuid, .. } => uuid.serialize(serializer),
+            UntypedAssetId::Index { .. } => Err(ser::Error::custom(
+                "only shader models registered by UUID can be serialized",
+            )),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<UntypedAssetId, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let uuid = Uuid::deserialize(deserializer)?;
+        let type_id = TypeId::of::<DefaultNannouShaderModel>();
+        Ok(UntypedAssetId::Uuid { type_id, uuid })
+    }
+}
+
+/// Meshes built via [MeshExt], serialized as their vertex attributes and indices.
+pub(crate) mod mesh {
+    use bevy::prelude::*;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::draw::mesh::MeshExt;
+
+    #[derive(Serialize)]
+    struct MeshRef<'a> {
+        points: &'a [[f32; 3]],
+        colors: &'a [[f32; 4]],
+        tex_coords: &'a [[f32; 2]],
+        normals: &'a [[f32; 3]],
+        indices: Vec<u32>,
+    }
+
+    #[derive(Deserialize)]
+    struct MeshData {
+        points: Vec<[f32; 3]>,
+        colors: Vec<[f32; 4]>,
+        tex_coords: Vec<[f32; 2]>,
+        normals: Vec<[f32; 3]>,
+        indices: Vec<u32>,
+    }
+
+    pub fn serialize<S>(mesh: &Mesh, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        MeshRef {
+            points: mesh.points(),
+            colors: mesh.colors(),
+            tex_coords: mesh.tex_coords(),
+            normals: mesh.normals(),
+            indices: (0..mesh.count_indices())
+                .map(|i| mesh.get_index(i))
+                .collect(),
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Mesh, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let data = MeshData::deserialize(deserializer)?;
+        let mut mesh = Mesh::init();
+        *mesh.points_mut() = data.points;
+        *mesh.colors_mut() = data.colors;
+        *mesh.tex_coords_mut() = data.tex_coords;
+        *mesh.normals_mut() = data.normals;
+        for index in data.indices {
+            mesh.push_index(index);
+        }
+        Ok(mesh)
+    }
+}
+
+/// A [geom::Rect](nannou_core::geom::Rect), serialized as `[left, right, bottom, top]`.
+pub(crate) mod geom_rect {
+    use nannou_core::geom;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S>(rect: &geom::Rect, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        [rect.x.start, rect.x.end, rect.y.start, rect.y.end].serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<geom::Rect, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let [left, right, bottom, top] = <[f32; 4]>::deserialize(deserializer)?;
+        Ok(geom::Rect {
+            x: geom::Range::new(left, right),
+            y: geom::Range::new(bottom, top),
+        })
+    }
+}
+
+/// An optional [geom::Rect](nannou_core::geom::Rect), serialized as in [geom_rect].
+pub(crate) mod option_geom_rect {
+    use nannou_core::geom;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S>(rect: &Option<geom::Rect>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        rect.map(|r| [r.x.start, r.x.end, r.y.start, r.y.end])
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<geom::Rect>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let rect = <Option<[f32; 4]>>::deserialize(deserializer)?;
+        Ok(rect.map(|[left, right, bottom, top]| geom::Rect {
+            x: geom::Range::new(left, right),
+            y: geom::Range::new(bottom, top),
+        }))
+    }
+}
+
+/// A [geom::Quad](nannou_core::geom::Quad), serialized as its vertices.
+pub(crate) mod geom_quad {
+    use bevy::prelude::*;
+    use nannou_core::geom;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S>(quad: &geom::Quad<Vec2>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        quad.0.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<geom::Quad<Vec2>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        <[Vec2; 4]>::deserialize(deserializer).map(geom::Quad)
+    }
+}
+
+/// A [geom::Tri](nannou_core::geom::Tri), serialized as its vertices.
+pub(crate) mod geom_tri {
+    use bevy::prelude::*;
+    use nannou_core::geom;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S>(tri: &geom::Tri<Vec2>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        tri.0.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<geom::Tri<Vec2>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        <[Vec2; 3]>::deserialize(deserializer).map(geom::Tri)
+    }
+}
@@ -1,9 +1,11 @@
+use crate::accumulate::{Accumulate, AccumulationCanvas};
 use crate::draw::{
     Draw, DrawCommand, DrawContext,
     indirect::{IndirectMesh, IndirectShaderModelPlugin},
     instanced::{InstanceRange, InstancedMesh, InstancedShaderModelPlugin},
     mesh::MeshExt,
-    render::{RenderContext, RenderPrimitive},
+    primitive::points::{ATTRIBUTE_POINT_SIZE, PointShape, PointSpace},
+    render::{RenderContext, RenderPrimitive, Scissor},
 };
 use bevy::{
     asset::{Asset, AssetEventSystems, UntypedAssetId, load_internal_asset, uuid_handle},
@@ -61,6 +63,9 @@ use std::{any::TypeId, hash::Hash, marker::PhantomData};
 pub const DEFAULT_NANNOU_SHADER_HANDLE: Handle<Shader> =
     uuid_handle!("f2dbf06f-38d5-47f1-8ad4-3f188d888dd0");
 
+pub const POINTS_SHADER_HANDLE: Handle<Shader> =
+    uuid_handle!("5b0e6c1a-9d3f-4b7e-a2c8-71f4e0d93b56");
+
 /// A dyn-safe view of a [`ShaderModel`] instance, allowing the draw state to store and
 /// manipulate models of any type without knowing the concrete type.
 pub(crate) trait ErasedShaderModel: Send + Sync + 'static {
@@ -144,6 +149,7 @@ impl Plugin for NannouRenderPlugin {
             "nannou.wgsl",
             Shader::from_wgsl
         );
+        load_internal_asset!(app, POINTS_SHADER_HANDLE, "points.wgsl", Shader::from_wgsl);
 
         app.add_plugins((
             ExtractComponentPlugin::<NannouTransient>::default(),
@@ -154,9 +160,16 @@ impl Plugin for NannouRenderPlugin {
             ExtractComponentPlugin::<DrawIndex>::default(),
             ExtractComponentPlugin::<InstanceRange>::default(),
             ExtractComponentPlugin::<ShaderBufferHandle>::default(),
+            ExtractComponentPlugin::<NannouScissor>::default(),
             NannouShaderModelPlugin::<DefaultNannouShaderModel>::default(),
+            NannouShaderModelPlugin::<PointsShaderModel>::default(),
         ))
-        .init_resource::<TextModelKeepalive>()
+        .init_resource::<TransientModelKeepalive>()
+        .init_resource::<PointsModelKeepalive>()
+        .add_systems(
+            PostUpdate,
+            crate::accumulate::update_accumulation_canvases.before(update_draw_mesh),
+        )
         // Both are skipped while `DrawFrozen` is set so the last frame's meshes are
         // neither despawned (`clear_previous_frame`) nor rebuilt (`update_draw_mesh`),
         // leaving them - and the camera clear color - in place for the render graph.
@@ -173,6 +186,15 @@ impl Plugin for NannouRenderPlugin {
                 .before(VisibilitySystems::VisibilityPropagate)
                 .after(bevy::text::load_font_assets_into_font_collection),
         );
+
+        // Frames are recorded before `update_draw_mesh` drains the draw's commands.
+        #[cfg(feature = "serde")]
+        app.add_systems(
+            PostUpdate,
+            crate::record::record_draws
+                .run_if(crate::draw_active)
+                .before(update_draw_mesh),
+        );
     }
 }
 
@@ -301,8 +323,43 @@ impl<P: PhaseItem, SM: ShaderModel, const I: usize> RenderCommand<P>
     }
 }
 
+/// Clips the item to its [`NannouScissor`], or to the full view if it has none.
+///
+/// The scissor persists across draws within a render pass, so it must be reset for unclipped
+/// items too.
+pub struct SetNannouScissor;
+impl<P: PhaseItem> RenderCommand<P> for SetNannouScissor {
+    type Param = ();
+    type ViewQuery = Read<ExtractedView>;
+    type ItemQuery = Option<Read<NannouScissor>>;
+
+    #[inline]
+    fn render<'w>(
+        _item: &P,
+        view: &'w ExtractedView,
+        scissor: Option<Option<&'w NannouScissor>>,
+        _param: SystemParamItem<'w, '_, Self::Param>,
+        pass: &mut TrackedRenderPass<'w>,
+    ) -> RenderCommandResult {
+        let [vx, vy, vw, vh] = view.viewport.to_array();
+        match scissor.flatten() {
+            Some(NannouScissor(scissor)) => {
+                // Clamp to the view so that the rect is always valid for the attachment.
+                let left = scissor.left.clamp(vx, vx + vw);
+                let top = scissor.top.clamp(vy, vy + vh);
+                let right = (scissor.left + scissor.width).clamp(left, vx + vw);
+                let bottom = (scissor.top + scissor.height).clamp(top, vy + vh);
+                pass.set_scissor_rect(left, top, right - left, bottom - top);
+            }
+            None => pass.set_scissor_rect(vx, vy, vw, vh),
+        }
+        RenderCommandResult::Success
+    }
+}
+
 pub type DrawShaderModel<SM> = (
     SetItemPipeline,
+    SetNannouScissor,
     SetMeshViewBindGroup<0>,
     SetMeshViewBindingArrayBindGroup<1>,
     SetMeshBindGroup<2>,
@@ -320,6 +377,7 @@ bitflags::bitflags! {
     #[repr(transparent)]
     pub struct NannouShaderModelFlags: u32 {
         const TEXTURE       = 1 << 0;
+        const PREMULTIPLY   = 1 << 1;
         const NONE          = 0;
         const UNINITIALIZED = 0xFFFF;
     }
@@ -335,6 +393,9 @@ pub struct NannouShaderModel {
     pub texture: Option<Handle<Image>>,
     pub polygon_mode: PolygonMode,
     pub blend: Option<BlendState>,
+    /// Whether to multiply the output color by its alpha before blending, as expected by some
+    /// blend states. See [blend::BlendMode::premultiplies_alpha].
+    pub premultiply_alpha: bool,
 }
 
 impl Default for NannouShaderModel {
@@ -347,6 +408,7 @@ impl Default for NannouShaderModel {
                 color: blend::BLEND_NORMAL,
                 alpha: blend::BLEND_NORMAL,
             }),
+            premultiply_alpha: false,
         }
     }
 }
@@ -366,6 +428,9 @@ impl AsBindGroupShaderType<NannouShaderModelUniform> for NannouShaderModel {
         if self.texture.is_some() {
             flags |= NannouShaderModelFlags::TEXTURE;
         }
+        if self.premultiply_alpha {
+            flags |= NannouShaderModelFlags::PREMULTIPLY;
+        }
 
         NannouShaderModelUniform {
             color: LinearRgba::from(self.color).to_vec4(),
@@ -389,6 +454,88 @@ impl From<&NannouShaderModel> for NannouBindGroupData {
     }
 }
 
+/// The shader model used to render point clouds drawn via `draw.points(..)`.
+///
+/// Each point is a quad whose corners share the point's position, expanded into a sprite of the
+/// point's size by the vertex shader.
+#[derive(Asset, AsBindGroup, TypePath, Debug, Clone)]
+#[bind_group_data(PointsBindGroupData)]
+#[uniform(0, PointsShaderModelUniform)]
+pub struct PointsShaderModel {
+    pub shape: PointShape,
+    pub space: PointSpace,
+    /// The scale factor of the output attachment, used to size screen space points in logical
+    /// pixels.
+    pub scale_factor: f32,
+    pub blend: Option<BlendState>,
+    /// Whether to multiply the output color by its alpha before blending.
+    pub premultiply_alpha: bool,
+}
+
+impl Default for PointsShaderModel {
+    fn default() -> Self {
+        Self {
+            shape: PointShape::default(),
+            space: PointSpace::default(),
+            scale_factor: 1.0,
+            blend: NannouShaderModel::default().blend,
+            premultiply_alpha: false,
+        }
+    }
+}
+
+bitflags::bitflags! {
+    #[repr(transparent)]
+    pub struct PointsShaderModelFlags: u32 {
+        const ROUND       = 1 << 0;
+        const WORLD_SPACE = 1 << 1;
+        const PREMULTIPLY = 1 << 2;
+        const NONE        = 0;
+    }
+}
+
+#[derive(Clone, Default, ShaderType)]
+pub struct PointsShaderModelUniform {
+    pub scale_factor: f32,
+    pub flags: u32,
+}
+
+impl AsBindGroupShaderType<PointsShaderModelUniform> for PointsShaderModel {
+    fn as_bind_group_shader_type(
+        &self,
+        _images: &RenderAssets<GpuImage>,
+    ) -> PointsShaderModelUniform {
+        let mut flags = PointsShaderModelFlags::NONE;
+        if self.shape == PointShape::Round {
+            flags |= PointsShaderModelFlags::ROUND;
+        }
+        if self.space == PointSpace::World {
+            flags |= PointsShaderModelFlags::WORLD_SPACE;
+        }
+        if self.premultiply_alpha {
+            flags |= PointsShaderModelFlags::PREMULTIPLY;
+        }
+
+        PointsShaderModelUniform {
+            scale_factor: self.scale_factor,
+            flags: flags.bits(),
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Hash, Clone)]
+pub struct PointsBindGroupData {
+    blend: Option<BlendState>,
+}
+
+impl From<&PointsShaderModel> for PointsBindGroupData {
+    fn from(shader_model: &PointsShaderModel) -> Self {
+        Self {
+            blend: shader_model.blend,
+        }
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn queue_shader_model<SM, QF, RC>(
     draw_functions: Res<DrawFunctions<Transparent3d>>,
@@ -641,6 +788,43 @@ impl ShaderModel for NannouShaderModel {
     }
 }
 
+impl ShaderModel for PointsShaderModel {
+    fn vertex_shader() -> ShaderRef {
+        ShaderRef::Handle(POINTS_SHADER_HANDLE)
+    }
+
+    fn fragment_shader() -> ShaderRef {
+        ShaderRef::Handle(POINTS_SHADER_HANDLE)
+    }
+
+    fn specialize(
+        _pipeline: &ShaderModelPipeline<Self>,
+        descriptor: &mut RenderPipelineDescriptor,
+        layout: &MeshVertexBufferLayoutRef,
+        key: ShaderModelPipelineKey<Self>,
+    ) -> Result<(), SpecializedMeshPipelineError> {
+        // Point meshes carry a size per vertex and no normals, so replace the mesh pipeline's
+        // vertex layout with that expected by the points shader.
+        let vertex_layout = layout.0.get_layout(&[
+            Mesh::ATTRIBUTE_POSITION.at_shader_location(0),
+            Mesh::ATTRIBUTE_UV_0.at_shader_location(1),
+            Mesh::ATTRIBUTE_COLOR.at_shader_location(2),
+            ATTRIBUTE_POINT_SIZE.at_shader_location(3),
+        ])?;
+        descriptor.vertex.buffers = vec![vertex_layout];
+
+        if let Some(blend) = key.bind_group_data.blend {
+            let fragment = descriptor.fragment.as_mut().unwrap();
+            fragment.targets.iter_mut().for_each(|target| {
+                if let Some(target) = target {
+                    target.blend = Some(blend);
+                }
+            });
+        }
+        Ok(())
+    }
+}
+
 #[derive(Component, Deref)]
 pub struct UntypedShaderModelId(UntypedAssetId);
 
@@ -671,8 +855,15 @@ fn update_shader_model<SM>(
     }
 }
 
+// Look up the paths of SVG primitives drawn via an asset handle.
+fn resolve_svg(prim: &mut crate::draw::primitive::Primitive, svgs: &Assets<crate::svg::Svg>) {
+    if let crate::draw::primitive::Primitive::Svg(svg) = prim {
+        svg.resolve(svgs);
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
-fn update_draw_mesh(
+pub(crate) fn update_draw_mesh(
     mut commands: Commands,
     draw_q: Query<&Draw>,
     mut cameras_q: Query<(Entity, &mut Camera, &RenderTarget, &RenderLayers), With<NannouCamera>>,
@@ -682,11 +873,21 @@ fn update_draw_mesh(
     mut font_atlas_set: ResMut<bevy::text::FontAtlasSet>,
     mut images: ResMut<Assets<Image>>,
     mut scale_cx: ResMut<bevy::text::ScaleCx>,
-    mut text_models: ResMut<Assets<DefaultNannouShaderModel>>,
-    mut text_model_keepalive: ResMut<TextModelKeepalive>,
+    mut transient_models: ResMut<Assets<DefaultNannouShaderModel>>,
+    mut transient_model_keepalive: ResMut<TransientModelKeepalive>,
+    svgs: Res<Assets<crate::svg::Svg>>,
+    canvases: Query<(&AccumulationCanvas, &Accumulate)>,
+    (mut points_models, mut points_model_keepalive): (
+        ResMut<Assets<PointsShaderModel>>,
+        ResMut<PointsModelKeepalive>,
+    ),
 ) {
     for draw in draw_q.iter() {
-        let Some((camera_entity, mut window_camera, _, window_layers)) =
+        // Draws attached to a camera (e.g. those rendering to an image) use that camera,
+        // otherwise look for the camera rendering to the draw's window.
+        let camera = if cameras_q.contains(draw.window) {
+            cameras_q.get_mut(draw.window).ok()
+        } else {
             cameras_q.iter_mut().find(|(_, _, render_target, _)| {
                 if let RenderTarget::Window(WindowRef::Primary) = render_target {
                     let Ok((_, is_primary)) = windows.get(draw.window) else {
@@ -704,43 +905,118 @@ fn update_draw_mesh(
 
                 false
             })
-        else {
+        };
+        let Some((camera_entity, mut window_camera, render_target, window_layers)) = camera else {
             bevy::log::debug!("No camera found for window {:?}", draw.window);
             continue;
         };
 
+        // The size of the window or image we are rendering to.
+        let (output_attachment_size, output_attachment_scale_factor) = match render_target {
+            RenderTarget::Image(target) => match images.get(&target.handle) {
+                Some(image) => (image.size_f32(), 1.0),
+                None => continue,
+            },
+            _ => match windows.get(draw.window) {
+                Ok((window, _)) => (
+                    Vec2::new(window.width(), window.height()),
+                    window.scale_factor(),
+                ),
+                Err(_) => continue,
+            },
+        };
+
+        // Accumulating windows present their canvas, while the draw renders into the canvas.
+        let window_layers = window_layers.clone();
+        let mut first_index = 0;
+        let (camera_entity, mut window_camera) = match canvases.get(draw.window) {
+            Ok((canvas, accumulate)) => {
+                window_camera.clear_color = ClearColorConfig::Default;
+                let model = transient_models.add(DefaultNannouShaderModel {
+                    color: Color::WHITE,
+                    texture: Some(canvas.image.clone()),
+                    ..default()
+                });
+                let quad = crate::accumulate::window_quad(output_attachment_size, Color::WHITE);
+                transient_model_keepalive.0.push(model.clone());
+                let mesh = meshes.add(quad);
+                spawn_mesh(
+                    &mut commands,
+                    model.id().untyped(),
+                    mesh,
+                    Transform::default(),
+                    0,
+                    &window_layers,
+                    camera_entity,
+                );
+
+                // Fade the canvas before the draw renders on top of it.
+                if accumulate.fade > 0.0 {
+                    let fade = accumulate.fade_color.with_alpha(accumulate.fade.min(1.0));
+                    let model = transient_models.add(DefaultNannouShaderModel {
+                        color: Color::WHITE,
+                        ..default()
+                    });
+                    let quad = crate::accumulate::window_quad(output_attachment_size, fade);
+                    transient_model_keepalive.0.push(model.clone());
+                    let mesh = meshes.add(quad);
+                    spawn_mesh(
+                        &mut commands,
+                        model.id().untyped(),
+                        mesh,
+                        Transform::default(),
+                        0,
+                        &window_layers,
+                        canvas.camera,
+                    );
+                    first_index = 1;
+                }
+
+                match cameras_q.get_mut(canvas.camera) {
+                    Ok((entity, camera, _, _)) => (entity, camera),
+                    Err(_) => continue,
+                }
+            }
+            Err(_) => (camera_entity, window_camera),
+        };
+
         // Reset the clear color each frame.
         window_camera.clear_color = ClearColorConfig::None;
 
-        // The window we are rendering to.
-        let (window, _) = windows.get(draw.window).unwrap();
         let mut fill_tessellator = FillTessellator::new();
         let mut stroke_tessellator = StrokeTessellator::new();
 
         let mut last_shader_model = None;
         let mut current_mesh = None;
         let mut curr_ctx: DrawContext = Default::default();
+        let mut curr_scissor = crate::draw::Scissor::Full;
+        let mut scissor = None;
+        let mut curr_mask = None;
 
         let draw_cmds = draw.drain_commands();
         let draw_state = draw.state.read().unwrap();
         let intermediary_state = draw_state.intermediary_state.read().unwrap();
 
-        for (idx, cmd) in draw_cmds.enumerate() {
+        for (idx, cmd) in (first_index..).zip(draw_cmds) {
             match cmd {
                 // Text renders as glyph-atlas-textured quads, so it cannot join the
                 // current batch: each run of glyphs gets its own mesh entity bound to
                 // the atlas texture it samples.
                 DrawCommand::Primitive(crate::draw::primitive::Primitive::Text(prim)) => {
+                    // Nothing is visible within a scissor with no overlap.
+                    if curr_scissor == crate::draw::Scissor::NoOverlap {
+                        continue;
+                    }
                     // End the current batch so that primitives drawn after this text
                     // get a fresh mesh entity with a higher `DrawIndex`.
                     current_mesh.take();
 
                     let batches = prim.render_atlas_quads(
-                        &intermediary_state.text_buffer,
+                        &intermediary_state,
                         &draw_state.theme,
                         &curr_ctx.transform,
-                        Vec2::new(window.width(), window.height()),
-                        window.scale_factor(),
+                        output_attachment_size,
+                        output_attachment_scale_factor,
                         &text_cx,
                         &mut font_atlas_set,
                         &mut images,
@@ -758,14 +1034,18 @@ fn update_draw_mesh(
                         .cloned()
                         .unwrap_or_default();
 
-                    for crate::draw::primitive::text::TextQuadBatch { texture, mesh } in batches {
+                    for crate::draw::primitive::text::TextQuadBatch { texture, mut mesh } in batches
+                    {
+                        if let Some(mask) = &curr_mask {
+                            mask.apply(&mut mesh, 0, 0);
+                        }
                         let mut model = base.clone();
                         // Glyph colour is carried per-vertex; the model tints white so
                         // it passes through.
                         model.color = Color::WHITE;
-                        model.texture = Some(texture);
-                        let handle = text_models.add(model);
-                        commands.spawn((
+                        model.texture = texture;
+                        let handle = transient_models.add(model);
+                        let mut entity = commands.spawn((
                             UntypedShaderModelId(handle.id().untyped()),
                             Mesh3d(meshes.add(mesh)),
                             Transform::default(),
@@ -781,22 +1061,140 @@ fn update_draw_mesh(
                             window_layers.clone(),
                             NannouMeshCamera(camera_entity),
                         ));
-                        text_model_keepalive.0.push(handle);
+                        if let Some(scissor) = scissor {
+                            entity.insert(NannouScissor(scissor));
+                        }
+                        transient_model_keepalive.0.push(handle);
                     }
                 }
-                DrawCommand::Primitive(prim) => {
+                // Pictures are drawn via the meshes retained for each of their layers, each with
+                // the shader model that the layer was recorded with.
+                DrawCommand::Primitive(crate::draw::primitive::Primitive::Picture(prim)) => {
+                    // Nothing is visible within a scissor with no overlap.
+                    if curr_scissor == crate::draw::Scissor::NoOverlap {
+                        continue;
+                    }
+                    current_mesh.take();
+
+                    let transform = curr_ctx.transform * prim.local_transform();
+                    for layer in prim.layers() {
+                        let default_model = layer
+                            .model
+                            .as_any()
+                            .downcast_ref::<DefaultNannouShaderModel>();
+                        // Retained meshes can't be clipped and may only be tinted via the model.
+                        let retained =
+                            curr_mask.is_none() && (!prim.is_tinted() || default_model.is_some());
+                        let mut entity = if retained {
+                            let mut model_id = layer.model_id;
+                            if let Some(model) = default_model.filter(|_| prim.is_tinted()) {
+                                let mut model = model.clone();
+                                model.color = prim.tinted(model.color);
+                                let handle = transient_models.add(model);
+                                model_id = handle.id().untyped();
+                                transient_model_keepalive.0.push(handle);
+                            }
+                            let mesh = layer.mesh_handle(&mut meshes);
+                            spawn_mesh(
+                                &mut commands,
+                                model_id,
+                                mesh,
+                                Transform::from_matrix(transform),
+                                idx,
+                                &window_layers,
+                                camera_entity,
+                            )
+                        } else {
+                            let mut mesh = Mesh::init();
+                            prim.render_layer(layer, transform, &mut mesh);
+                            if let Some(mask) = &curr_mask {
+                                mask.apply(&mut mesh, 0, 0);
+                            }
+                            spawn_mesh(
+                                &mut commands,
+                                layer.model_id,
+                                meshes.add(mesh),
+                                Transform::default(),
+                                idx,
+                                &window_layers,
+                                camera_entity,
+                            )
+                        };
+                        if let Some(scissor) = scissor {
+                            entity.insert(NannouScissor(scissor));
+                        }
+                    }
+                }
+                // Points are expanded into sprites by the points shader model, so each point
+                // cloud gets its own mesh entity.
+                DrawCommand::Primitive(crate::draw::primitive::Primitive::Points(prim)) => {
+                    // Nothing is visible within a scissor with no overlap.
+                    if curr_scissor == crate::draw::Scissor::NoOverlap {
+                        continue;
+                    }
+                    // Sprites are sized on the GPU, so their geometry can't be masked.
+                    if curr_mask.is_some() {
+                        bevy::log::warn_once!("masks are not supported for points");
+                    }
+                    current_mesh.take();
+
+                    let mesh = prim.sprite_mesh(
+                        &intermediary_state.points_buffer,
+                        &draw_state.theme,
+                        &curr_ctx.transform,
+                    );
+
+                    // Blend as the active shader model does when it is the default nannou
+                    // model, as for text.
+                    let (blend, premultiply_alpha) = last_shader_model
+                        .as_ref()
+                        .and_then(|id| draw_state.shader_models.get(id))
+                        .and_then(|model| model.as_any().downcast_ref::<DefaultNannouShaderModel>())
+                        .map(|model| (model.blend, model.premultiply_alpha))
+                        .unwrap_or((NannouShaderModel::default().blend, false));
+                    let handle = points_models.add(PointsShaderModel {
+                        shape: prim.point_shape(),
+                        space: prim.point_space(),
+                        scale_factor: output_attachment_scale_factor,
+                        blend,
+                        premultiply_alpha,
+                    });
+                    let mut entity = spawn_mesh(
+                        &mut commands,
+                        handle.id().untyped(),
+                        meshes.add(mesh),
+                        Transform::default(),
+                        idx,
+                        &window_layers,
+                        camera_entity,
+                    );
+                    if let Some(scissor) = scissor {
+                        entity.insert(NannouScissor(scissor));
+                    }
+                    points_model_keepalive.0.push(handle);
+                }
+                DrawCommand::Primitive(mut prim) => {
+                    // Nothing is visible within a scissor with no overlap.
+                    if curr_scissor == crate::draw::Scissor::NoOverlap {
+                        continue;
+                    }
+                    resolve_svg(&mut prim, &svgs);
                     // Info required during rendering.
                     let ctxt = RenderContext {
                         intermediary_mesh: &intermediary_state.intermediary_mesh,
                         path_event_buffer: &intermediary_state.path_event_buffer,
                         path_points_vertex_buffer: &intermediary_state.path_points_vertex_buffer,
+                        path_points_weighted_buffer: &intermediary_state
+                            .path_points_weighted_buffer,
+                        points_buffer: &intermediary_state.points_buffer,
                         text_buffer: &intermediary_state.text_buffer,
                         theme: &draw_state.theme,
                         transform: &curr_ctx.transform,
                         fill_tessellator: &mut fill_tessellator,
                         stroke_tessellator: &mut stroke_tessellator,
-                        output_attachment_size: Vec2::new(window.width(), window.height()),
-                        output_attachment_scale_factor: window.scale_factor(),
+                        output_attachment_size,
+                        output_attachment_scale_factor,
+                        vector_paths: None,
                     };
 
                     // If no mesh is currently set, initialise a new one.
@@ -804,7 +1202,7 @@ fn update_draw_mesh(
                         let mesh = meshes.add(Mesh::init());
                         let model_id =
                             last_shader_model.expect("No shader model set for draw command");
-                        commands.spawn((
+                        let mut entity = commands.spawn((
                             UntypedShaderModelId(model_id),
                             Mesh3d(mesh.clone()),
                             Transform::default(),
@@ -820,25 +1218,48 @@ fn update_draw_mesh(
                             window_layers.clone(),
                             NannouMeshCamera(camera_entity),
                         ));
+                        if let Some(scissor) = scissor {
+                            entity.insert(NannouScissor(scissor));
+                        }
                         mesh
                     });
 
-                    // Render the primitive.
+                    // Render the primitive, clipping the new geometry to the active mask.
                     let mut mesh = meshes.get_mut(mesh).unwrap();
+                    let vertex_start = mesh.count_vertices();
+                    let index_start = mesh.count_indices();
                     prim.render_primitive(ctxt, &mut mesh);
+                    if let Some(mask) = &curr_mask {
+                        mask.apply(&mut mesh, vertex_start, index_start);
+                    }
                 }
-                DrawCommand::Instanced(prim, range) => {
+                DrawCommand::Instanced(mut prim, range) => {
+                    // Nothing is visible within a scissor with no overlap.
+                    if curr_scissor == crate::draw::Scissor::NoOverlap {
+                        continue;
+                    }
+                    resolve_svg(&mut prim, &svgs);
+                    // Instances are positioned on the GPU, so their geometry can't be masked.
+                    if curr_mask.is_some() {
+                        bevy::log::warn_once!(
+                            "masks are not supported for instanced or indirect drawings"
+                        );
+                    }
                     let ctxt = RenderContext {
                         intermediary_mesh: &intermediary_state.intermediary_mesh,
                         path_event_buffer: &intermediary_state.path_event_buffer,
                         path_points_vertex_buffer: &intermediary_state.path_points_vertex_buffer,
+                        path_points_weighted_buffer: &intermediary_state
+                            .path_points_weighted_buffer,
+                        points_buffer: &intermediary_state.points_buffer,
                         text_buffer: &intermediary_state.text_buffer,
                         theme: &draw_state.theme,
                         transform: &curr_ctx.transform,
                         fill_tessellator: &mut fill_tessellator,
                         stroke_tessellator: &mut stroke_tessellator,
-                        output_attachment_size: Vec2::new(window.width(), window.height()),
-                        output_attachment_scale_factor: window.scale_factor(),
+                        output_attachment_size,
+                        output_attachment_scale_factor,
+                        vector_paths: None,
                     };
 
                     // Render the primitive.
@@ -847,7 +1268,7 @@ fn update_draw_mesh(
                     let mesh = meshes.add(mesh);
                     let model_id =
                         last_shader_model.expect("No shader model set for instanced draw command");
-                    commands.spawn((
+                    let mut entity = commands.spawn((
                         InstancedMesh,
                         InstanceRange(range),
                         UntypedShaderModelId(model_id),
@@ -864,20 +1285,38 @@ fn update_draw_mesh(
                         window_layers.clone(),
                         NannouMeshCamera(camera_entity),
                     ));
+                    if let Some(scissor) = scissor {
+                        entity.insert(NannouScissor(scissor));
+                    }
                 }
-                DrawCommand::Indirect(prim, indirect_buffer) => {
+                DrawCommand::Indirect(mut prim, indirect_buffer) => {
+                    // Nothing is visible within a scissor with no overlap.
+                    if curr_scissor == crate::draw::Scissor::NoOverlap {
+                        continue;
+                    }
+                    resolve_svg(&mut prim, &svgs);
+                    // Instances are positioned on the GPU, so their geometry can't be masked.
+                    if curr_mask.is_some() {
+                        bevy::log::warn_once!(
+                            "masks are not supported for instanced or indirect drawings"
+                        );
+                    }
                     // Info required during rendering.
                     let ctxt = RenderContext {
                         intermediary_mesh: &intermediary_state.intermediary_mesh,
                         path_event_buffer: &intermediary_state.path_event_buffer,
                         path_points_vertex_buffer: &intermediary_state.path_points_vertex_buffer,
+                        path_points_weighted_buffer: &intermediary_state
+                            .path_points_weighted_buffer,
+                        points_buffer: &intermediary_state.points_buffer,
                         text_buffer: &intermediary_state.text_buffer,
                         theme: &draw_state.theme,
                         transform: &curr_ctx.transform,
                         fill_tessellator: &mut fill_tessellator,
                         stroke_tessellator: &mut stroke_tessellator,
-                        output_attachment_size: Vec2::new(window.width(), window.height()),
-                        output_attachment_scale_factor: window.scale_factor(),
+                        output_attachment_size,
+                        output_attachment_scale_factor,
+                        vector_paths: None,
                     };
 
                     // Render the primitive.
@@ -886,7 +1325,7 @@ fn update_draw_mesh(
                     let mesh = meshes.add(mesh);
                     let model_id =
                         last_shader_model.expect("No shader model set for instanced draw command");
-                    commands.spawn((
+                    let mut entity = commands.spawn((
                         IndirectMesh,
                         ShaderBufferHandle(indirect_buffer),
                         UntypedShaderModelId(model_id),
@@ -903,6 +1342,9 @@ fn update_draw_mesh(
                         window_layers.clone(),
                         NannouMeshCamera(camera_entity),
                     ));
+                    if let Some(scissor) = scissor {
+                        entity.insert(NannouScissor(scissor));
+                    }
                 }
                 DrawCommand::Context(ctx) => {
                     curr_ctx = ctx;
@@ -913,6 +1355,23 @@ fn update_draw_mesh(
                     last_shader_model = Some(model_id.clone());
                     current_mesh.take();
                 }
+                DrawCommand::Scissor(new_scissor) => {
+                    // Meshes are clipped as a whole, so start a new one for the new scissor.
+                    if new_scissor != curr_scissor {
+                        current_mesh.take();
+                    }
+                    curr_scissor = new_scissor;
+                    scissor = Scissor::from_draw_scissor(
+                        curr_scissor,
+                        output_attachment_size,
+                        output_attachment_scale_factor,
+                    );
+                }
+                DrawCommand::Mask(mask) => {
+                    // Masks are applied to each primitive as it is rendered, so the current
+                    // mesh may continue to be used.
+                    curr_mask = mask;
+                }
                 DrawCommand::BackgroundColor(color) => {
                     window_camera.clear_color = ClearColorConfig::Custom(color);
                 }
@@ -921,6 +1380,35 @@ fn update_draw_mesh(
     }
 }
 
+// Spawn a mesh rendered with the given shader model, e.g. to present or fade a canvas or to
+// replay a picture.
+fn spawn_mesh<'a>(
+    commands: &'a mut Commands,
+    model: UntypedAssetId,
+    mesh: Handle<Mesh>,
+    transform: Transform,
+    idx: usize,
+    layers: &RenderLayers,
+    camera: Entity,
+) -> EntityCommands<'a> {
+    commands.spawn((
+        UntypedShaderModelId(model),
+        Mesh3d(mesh),
+        transform,
+        GlobalTransform::from(transform),
+        Visibility::default(),
+        InheritedVisibility::default(),
+        ViewVisibility::default(),
+        ShaderModelMesh,
+        NannouTransient,
+        NoFrustumCulling,
+        NoAutomaticBatching,
+        DrawIndex(idx),
+        layers.clone(),
+        NannouMeshCamera(camera),
+    ))
+}
+
 #[derive(Component, ExtractComponent, Clone)]
 pub struct DrawIndex(pub usize);
 
@@ -934,17 +1422,30 @@ pub struct NannouMeshCamera(pub Entity);
 #[derive(Component, ExtractComponent, Clone)]
 pub struct NannouTransient;
 
-/// Keeps the shader models created for text quad batches alive for the frame they
-/// are drawn in; dropping the handles the following frame lets the assets clean up.
+/// The scissor rect to which a draw mesh is clipped, as set via `Draw::scissor`.
+///
+/// Meshes without this component are drawn to the full view.
+#[derive(Component, ExtractComponent, Clone, Copy)]
+pub struct NannouScissor(pub Scissor);
+
+/// Keeps the shader models created while rendering a frame - for text quad batches, tinted
+/// pictures and accumulation canvas quads - alive for the frame they are drawn in; dropping the
+/// handles the following frame lets the assets clean up.
 #[derive(Resource, Default)]
-pub struct TextModelKeepalive(Vec<Handle<DefaultNannouShaderModel>>);
+pub struct TransientModelKeepalive(Vec<Handle<DefaultNannouShaderModel>>);
+
+/// Keeps the shader models created for point clouds alive for the frame they are drawn in.
+#[derive(Resource, Default)]
+pub struct PointsModelKeepalive(Vec<Handle<PointsShaderModel>>);
 
 fn clear_previous_frame(
     mut commands: Commands,
     meshes_q: Query<Entity, With<NannouTransient>>,
-    mut text_model_keepalive: ResMut<TextModelKeepalive>,
+    mut transient_model_keepalive: ResMut<TransientModelKeepalive>,
+    mut points_model_keepalive: ResMut<PointsModelKeepalive>,
 ) {
-    text_model_keepalive.0.clear();
+    transient_model_keepalive.0.clear();
+    points_model_keepalive.0.clear();
     for entity in meshes_q.iter() {
         commands.entity(entity).despawn();
     }
@@ -1000,6 +1501,105 @@ pub mod blend {
         dst_factor: wgpu::BlendFactor::One,
         operation: wgpu::BlendOperation::Max,
     };
+
+    // Composite the alpha of drawn colors over that of existing colors.
+    const BLEND_ALPHA_OVER: wgpu::BlendComponent = wgpu::BlendComponent {
+        src_factor: wgpu::BlendFactor::One,
+        dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+        operation: wgpu::BlendOperation::Add,
+    };
+
+    /// Named presets describing how drawn colors are combined with those already drawn.
+    ///
+    /// Drawn colors are assumed not to be premultiplied by their alpha unless stated otherwise.
+    /// Set via [Draw::blend_mode](crate::draw::Draw::blend_mode).
+    #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+    pub enum BlendMode {
+        /// Draw over existing colors according to the drawn alpha. This is the default.
+        #[default]
+        Normal,
+        /// Add the drawn color, scaled by its alpha, to existing colors.
+        Add,
+        /// Multiply existing colors by the drawn color, darkening them.
+        Multiply,
+        /// Multiply existing colors by the inverse of the drawn color, lightening them.
+        Screen,
+        /// Subtract the drawn color, scaled by its alpha, from existing colors.
+        Subtract,
+        /// Keep the lightest of the drawn and existing colors per channel, ignoring alpha.
+        Lighten,
+        /// Keep the darkest of the drawn and existing colors per channel, ignoring alpha.
+        Darken,
+        /// Draw over existing colors, where drawn colors are already premultiplied by their
+        /// alpha, e.g. as for premultiplied textures.
+        Premultiplied,
+        /// Replace existing colors and alpha with those drawn.
+        Replace,
+    }
+
+    impl BlendMode {
+        /// All blend modes, in the order in which they are declared.
+        pub const ALL: [BlendMode; 9] = [
+            BlendMode::Normal,
+            BlendMode::Add,
+            BlendMode::Multiply,
+            BlendMode::Screen,
+            BlendMode::Subtract,
+            BlendMode::Lighten,
+            BlendMode::Darken,
+            BlendMode::Premultiplied,
+            BlendMode::Replace,
+        ];
+
+        /// The pipeline blend state for this mode.
+        ///
+        /// Modes for which [BlendMode::premultiplies_alpha] is `true` expect the shader to output
+        /// colors premultiplied by their alpha.
+        pub fn blend_state(self) -> wgpu::BlendState {
+            let color = match self {
+                BlendMode::Normal => BLEND_NORMAL,
+                BlendMode::Add => wgpu::BlendComponent {
+                    src_factor: wgpu::BlendFactor::SrcAlpha,
+                    dst_factor: wgpu::BlendFactor::One,
+                    operation: wgpu::BlendOperation::Add,
+                },
+                BlendMode::Multiply => wgpu::BlendComponent {
+                    src_factor: wgpu::BlendFactor::Dst,
+                    dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                    operation: wgpu::BlendOperation::Add,
+                },
+                BlendMode::Screen => wgpu::BlendComponent {
+                    src_factor: wgpu::BlendFactor::One,
+                    dst_factor: wgpu::BlendFactor::OneMinusSrc,
+                    operation: wgpu::BlendOperation::Add,
+                },
+                BlendMode::Subtract => wgpu::BlendComponent {
+                    src_factor: wgpu::BlendFactor::SrcAlpha,
+                    dst_factor: wgpu::BlendFactor::One,
+                    operation: wgpu::BlendOperation::ReverseSubtract,
+                },
+                BlendMode::Lighten => BLEND_LIGHTEST,
+                BlendMode::Darken => BLEND_DARKEST,
+                BlendMode::Premultiplied => BLEND_ALPHA_OVER,
+                BlendMode::Replace => wgpu::BlendComponent::REPLACE,
+            };
+            let alpha = match self {
+                // Matches the default shader model's blend state.
+                BlendMode::Normal => BLEND_NORMAL,
+                BlendMode::Replace => wgpu::BlendComponent::REPLACE,
+                _ => BLEND_ALPHA_OVER,
+            };
+            wgpu::BlendState { color, alpha }
+        }
+
+        /// Whether the shader must multiply colors by their alpha for the blend state to account
+        /// for alpha.
+        ///
+        /// This is the case for modes that scale existing colors by the drawn color.
+        pub fn premultiplies_alpha(self) -> bool {
+            matches!(self, BlendMode::Multiply | BlendMode::Screen)
+        }
+    }
 }
 
 #[derive(Component)]
@@ -1018,6 +1618,66 @@ impl NannouCamera {
             RenderTarget::Window(WindowRef::Entity(window)),
         )
     }
+
+    /// A camera rendering into the given image, where one unit is one pixel.
+    ///
+    /// The camera renders before those of windows so that the image may be sampled by window
+    /// drawings within the same frame. The image must be usable as a render attachment, e.g. as
+    /// created via [new_target_image].
+    pub fn for_image(image: Handle<Image>) -> impl Bundle {
+        (
+            Self,
+            Camera {
+                order: -1,
+                clear_color: ClearColorConfig::None,
+                ..default()
+            },
+            RenderTarget::Image(image.into()),
+            Transform::from_xyz(0.0, 0.0, 10.0).looking_at(Vec3::ZERO, Vec3::Y),
+            Projection::Orthographic(Self::image_projection()),
+            // Tonemapping is left to the camera that eventually presents the image.
+            Tonemapping::None,
+        )
+    }
+
+    /// The projection used by cameras rendering into images.
+    pub fn image_projection() -> OrthographicProjection {
+        let size = IMAGE_CAMERA_HALF_Z_RANGE;
+        OrthographicProjection {
+            near: -size,
+            far: size,
+            ..OrthographicProjection::default_3d()
+        }
+    }
+}
+
+// The half-extent of the z range visible to image cameras, deep enough for most 3D content.
+const IMAGE_CAMERA_HALF_Z_RANGE: f32 = 4096.0;
+
+/// Create a transparent image of the given size that may be rendered into by a [Draw], e.g. via
+/// [crate::ImageDraws].
+pub fn new_target_image(width: u32, height: u32) -> Image {
+    new_target_image_filled(width, height, [0, 0, 0, 0])
+}
+
+// Create a render target image filled with the given sRGBA pixel.
+pub(crate) fn new_target_image_filled(width: u32, height: u32, pixel: [u8; 4]) -> Image {
+    use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat, TextureUsages};
+    let size = Extent3d {
+        width: width.max(1),
+        height: height.max(1),
+        depth_or_array_layers: 1,
+    };
+    let mut image = Image::new_fill(
+        size,
+        TextureDimension::D2,
+        &pixel,
+        TextureFormat::Rgba8UnormSrgb,
+        bevy::asset::RenderAssetUsages::default(),
+    );
+    image.texture_descriptor.usage =
+        TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST | TextureUsages::RENDER_ATTACHMENT;
+    image
 }
 
 #[derive(Component, ExtractComponent, Clone)]
@@ -0,0 +1,338 @@
+//! Loading SVG files as assets that may be drawn via `draw.svg(&handle)`.
+//!
+//! SVG files are parsed with [usvg], which resolves basic shapes, text, `use` elements, CSS and
+//! nested transforms into plain paths. Each path is converted into lyon [PathEvent]s along with
+//! its fill and stroke style, ready to be tessellated like any other nannou path.
+
+use std::sync::Arc;
+
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::prelude::*;
+use lyon::path::PathEvent;
+use lyon::tessellation::{FillRule, LineCap, LineJoin, StrokeOptions};
+use thiserror::Error;
+use usvg::tiny_skia_path::PathSegment;
+
+use crate::draw::properties::gradient::{Gradient, GradientKind};
+
+/// A loaded SVG document, converted to a list of paths.
+#[derive(Asset, TypePath, Clone, Debug)]
+pub struct Svg {
+    data: Arc<SvgData>,
+}
+
+/// The paths of an SVG document.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SvgData {
+    /// The size of the document, as specified by its `width` and `height`.
+    pub size: Vec2,
+    /// All visible paths in the order in which they are drawn.
+    pub paths: Vec<SvgPath>,
+}
+
+/// A single path within an SVG document.
+///
+/// Points are described in nannou's coordinate space - *y* points up and the origin is the
+/// centre of the document.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SvgPath {
+    pub events: Vec<PathEvent>,
+    pub fill: Option<SvgFill>,
+    pub stroke: Option<SvgStroke>,
+}
+
+/// The fill style of an [SvgPath].
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SvgFill {
+    /// The fill color, or the color of the first stop if filled with a gradient.
+    pub color: Color,
+    /// The linear or radial gradient with which the path is filled, if any.
+    pub gradient: Option<Gradient>,
+    pub rule: FillRule,
+}
+
+/// The stroke style of an [SvgPath].
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SvgStroke {
+    /// The stroke color, or the color of the first stop if stroked with a gradient.
+    pub color: Color,
+    /// The linear or radial gradient with which the path is stroked, if any.
+    pub gradient: Option<Gradient>,
+    pub options: StrokeOptions,
+}
+
+#[derive(Default, TypePath)]
+pub struct SvgLoader;
+
+#[derive(Debug, Error)]
+pub enum SvgAssetLoaderError {
+    #[error("Failed to load SVG file")]
+    Io(#[from] std::io::Error),
+    #[error("Failed to parse SVG")]
+    Parse(#[from] usvg::Error),
+}
+
+impl Svg {
+    /// Parse an SVG document from the given bytes.
+    pub fn from_data(data: &[u8]) -> Result<Self, usvg::Error> {
+        let tree = usvg::Tree::from_data(data, &usvg::Options::default())?;
+        Ok(Self::from_tree(&tree))
+    }
+
+    /// Convert a parsed **usvg** tree.
+    pub fn from_tree(tree: &usvg::Tree) -> Self {
+        let size = Vec2::new(tree.size().width(), tree.size().height());
+        let mut paths = vec![];
+        collect_paths(tree.root(), size, 1.0, &mut paths);
+        let data = Arc::new(SvgData { size, paths });
+        Svg { data }
+    }
+
+    /// The converted paths of the document.
+    pub fn data(&self) -> &Arc<SvgData> {
+        &self.data
+    }
+
+    /// The size of the document.
+    pub fn size(&self) -> Vec2 {
+        self.data.size
+    }
+
+    /// All visible paths in the order in which they are drawn.
+    pub fn paths(&self) -> &[SvgPath] {
+        &self.data.paths
+    }
+}
+
+impl AssetLoader for SvgLoader {
+    type Asset = Svg;
+    type Settings = ();
+    type Error = SvgAssetLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(Svg::from_data(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["svg", "svgz"]
+    }
+}
+
+// Recursively collect the paths of the group, multiplying through group opacity.
+fn collect_paths(group: &usvg::Group, size: Vec2, opacity: f32, paths: &mut Vec<SvgPath>) {
+    let opacity = opacity * group.opacity().get();
+    for node in group.children() {
+        match node {
+            usvg::Node::Group(group) => collect_paths(group, size, opacity, paths),
+            usvg::Node::Path(path) => {
+                if !path.is_visible() {
+                    continue;
+                }
+                if let Some(path) = convert_path(path, size, opacity) {
+                    paths.push(path);
+                }
+            }
+            usvg::Node::Text(text) => collect_paths(text.flattened(), size, opacity, paths),
+            // Raster and nested SVG images are not supported.
+            usvg::Node::Image(_) => (),
+        }
+    }
+}
+
+fn convert_path(path: &usvg::Path, size: Vec2, opacity: f32) -> Option<SvgPath> {
+    let transform = path.abs_transform();
+    let point = |p: usvg::tiny_skia_path::Point| {
+        let p = map_point(transform, size, p.x, p.y);
+        lyon::math::point(p.x, p.y)
+    };
+
+    let mut events = vec![];
+    let (mut first, mut prev) = (lyon::math::point(0.0, 0.0), None);
+    let end = |events: &mut Vec<PathEvent>, first, last, close| {
+        events.push(PathEvent::End { last, first, close })
+    };
+    for segment in path.data().segments() {
+        match segment {
+            PathSegment::MoveTo(p) => {
+                if let Some(last) = prev.take() {
+                    end(&mut events, first, last, false);
+                }
+                first = point(p);
+                prev = Some(first);
+                events.push(PathEvent::Begin { at: first });
+            }
+            PathSegment::LineTo(p) => {
+                let from = prev?;
+                let to = point(p);
+                prev = Some(to);
+                events.push(PathEvent::Line { from, to });
+            }
+            PathSegment::QuadTo(ctrl, p) => {
+                let from = prev?;
+                let to = point(p);
+                prev = Some(to);
+                let ctrl = point(ctrl);
+                events.push(PathEvent::Quadratic { from, ctrl, to });
+            }
+            PathSegment::CubicTo(ctrl1, ctrl2, p) => {
+                let from = prev?;
+                let to = point(p);
+                prev = Some(to);
+                let (ctrl1, ctrl2) = (point(ctrl1), point(ctrl2));
+                events.push(PathEvent::Cubic {
+                    from,
+                    ctrl1,
+                    ctrl2,
+                    to,
+                });
+            }
+            PathSegment::Close => {
+                if let Some(last) = prev.take() {
+                    end(&mut events, first, last, true);
+                }
+            }
+        }
+    }
+    if let Some(last) = prev {
+        end(&mut events, first, last, false);
+    }
+
+    let fill = path.fill().map(|fill| {
+        let opacity = opacity * fill.opacity().get();
+        let (color, gradient) = convert_paint(fill.paint(), opacity, transform, size);
+        SvgFill {
+            color,
+            gradient,
+            rule: match fill.rule() {
+                usvg::FillRule::NonZero => FillRule::NonZero,
+                usvg::FillRule::EvenOdd => FillRule::EvenOdd,
+            },
+        }
+    });
+
+    // Stroke widths are scaled by the uniform scale of the path's transform.
+    let scale = (transform.sx * transform.sy - transform.kx * transform.ky)
+        .abs()
+        .sqrt();
+    let stroke = path.stroke().map(|stroke| {
+        let cap = match stroke.linecap() {
+            usvg::LineCap::Butt => LineCap::Butt,
+            usvg::LineCap::Round => LineCap::Round,
+            usvg::LineCap::Square => LineCap::Square,
+        };
+        let join = match stroke.linejoin() {
+            usvg::LineJoin::Miter => LineJoin::Miter,
+            usvg::LineJoin::MiterClip => LineJoin::MiterClip,
+            usvg::LineJoin::Round => LineJoin::Round,
+            usvg::LineJoin::Bevel => LineJoin::Bevel,
+        };
+        let options = StrokeOptions::default()
+            .with_line_width(stroke.width().get() * scale)
+            .with_line_cap(cap)
+            .with_line_join(join)
+            .with_miter_limit(
+                stroke
+                    .miterlimit()
+                    .get()
+                    .max(StrokeOptions::MINIMUM_MITER_LIMIT),
+            );
+        let opacity = opacity * stroke.opacity().get();
+        let (color, gradient) = convert_paint(stroke.paint(), opacity, transform, size);
+        SvgStroke {
+            color,
+            gradient,
+            options,
+        }
+    });
+
+    Some(SvgPath {
+        events,
+        fill,
+        stroke,
+    })
+}
+
+// Map a point in the user space of the given transform to nannou's coordinate space: y-up,
+// centred on the document.
+fn map_point(transform: usvg::Transform, size: Vec2, x: f32, y: f32) -> Vec2 {
+    let tx = transform.sx * x + transform.kx * y + transform.tx;
+    let ty = transform.ky * x + transform.sy * y + transform.ty;
+    Vec2::new(tx - size.x * 0.5, size.y * 0.5 - ty)
+}
+
+// The color and gradient of the given paint within a path with the given transform.
+//
+// Gradient coordinates are mapped to the document's space, where linear gradients keep their
+// end points and radial gradients are scaled uniformly. Focal points, spread methods other than
+// `pad` and patterns are not supported; a warning is logged and the nearest approximation used.
+fn convert_paint(
+    paint: &usvg::Paint,
+    opacity: f32,
+    transform: usvg::Transform,
+    size: Vec2,
+) -> (Color, Option<Gradient>) {
+    let (kind, spread_method, stops) = match paint {
+        usvg::Paint::Color(color) => return (rgba(*color, opacity).into(), None),
+        usvg::Paint::LinearGradient(gradient) => {
+            let t = transform.pre_concat(gradient.transform());
+            let start = map_point(t, size, gradient.x1(), gradient.y1());
+            let end = map_point(t, size, gradient.x2(), gradient.y2());
+            let kind = GradientKind::Linear { start, end };
+            (kind, gradient.spread_method(), gradient.stops())
+        }
+        usvg::Paint::RadialGradient(gradient) => {
+            let t = transform.pre_concat(gradient.transform());
+            if (gradient.fx(), gradient.fy()) != (gradient.cx(), gradient.cy()) {
+                bevy::log::warn_once!(
+                    "SVG radial gradient focal points are not supported and are ignored"
+                );
+            }
+            let center = map_point(t, size, gradient.cx(), gradient.cy());
+            let scale = (t.sx * t.sy - t.kx * t.ky).abs().sqrt();
+            let radius = gradient.r().get() * scale;
+            let kind = GradientKind::Radial { center, radius };
+            (kind, gradient.spread_method(), gradient.stops())
+        }
+        usvg::Paint::Pattern(_) => {
+            bevy::log::warn_once!("SVG pattern paints are not supported and are drawn in black");
+            return (Srgba::new(0.0, 0.0, 0.0, opacity).into(), None);
+        }
+    };
+    if spread_method != usvg::SpreadMethod::Pad {
+        bevy::log::warn_once!("SVG gradient spread methods other than `pad` are not supported");
+    }
+    let stops: Vec<(f32, Color)> = stops
+        .iter()
+        .map(|stop| {
+            let color = rgba(stop.color(), opacity * stop.opacity().get());
+            (stop.offset().get(), color.into())
+        })
+        .collect();
+    let Some(&(_, color)) = stops.first() else {
+        return (Srgba::NONE.into(), None);
+    };
+    let gradient = Gradient {
+        kind: Some(kind),
+        stops,
+        ..Default::default()
+    };
+    (color, Some(gradient))
+}
+
+// An sRGB color with the given opacity.
+fn rgba(color: usvg::Color, opacity: f32) -> Srgba {
+    let [r, g, b] = [color.red, color.green, color.blue].map(|c| c as f32 / 255.0);
+    Srgba::new(r, g, b, opacity)
+}
@@ -1,9 +1,14 @@
 //! Font loading and shared text context.
 
-use std::sync::{Arc, Mutex};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
 
+use bevy::asset::AssetPath;
 use bevy::prelude::*;
+use bevy::text::Font;
 use parley::{FontContext, LayoutContext};
+use skrifa::string::StringId;
+use skrifa::{FontRef, MetadataProvider};
 
 /// Font database and layout scratch space.
 pub struct NannouTextCxInner {
@@ -15,6 +20,135 @@ pub struct NannouTextCxInner {
 #[derive(Resource, Clone)]
 pub struct SharedTextCx(pub Arc<Mutex<NannouTextCxInner>>);
 
+/// The family name used for text drawn with a [FontHandle] whose font has not loaded yet.
+pub const FALLBACK_FAMILY: &str = "sans-serif";
+
+/// A handle to a font family registered at runtime, usable via `draw.text(..).font(&handle)`.
+///
+/// Handles to fonts loaded from asset files resolve their family name once the asset has loaded,
+/// and again whenever the asset is modified. Until then, text is drawn with [FALLBACK_FAMILY].
+#[derive(Clone, Debug)]
+pub struct FontHandle {
+    family: Arc<RwLock<Option<String>>>,
+    asset: Option<Handle<Font>>,
+}
+
+/// Font assets loaded via [FontFamilies::load], along with the family name of each.
+#[derive(Resource, Clone, Default)]
+pub struct FontFamilies(Arc<Mutex<HashMap<AssetId<Font>, Arc<RwLock<Option<String>>>>>>);
+
+impl FontHandle {
+    /// The name of the font's family, or `None` if the font has not loaded yet.
+    pub fn family_name(&self) -> Option<String> {
+        self.family.read().unwrap().clone()
+    }
+
+    /// Whether the font has loaded and its family may be used to draw text.
+    pub fn is_loaded(&self) -> bool {
+        self.family.read().unwrap().is_some()
+    }
+
+    /// The font asset the family was loaded from, if any.
+    pub fn asset(&self) -> Option<&Handle<Font>> {
+        self.asset.as_ref()
+    }
+}
+
+impl From<&FontHandle> for String {
+    fn from(handle: &FontHandle) -> Self {
+        handle
+            .family_name()
+            .unwrap_or_else(|| FALLBACK_FAMILY.to_string())
+    }
+}
+
+impl From<FontHandle> for String {
+    fn from(handle: FontHandle) -> Self {
+        String::from(&handle)
+    }
+}
+
+impl SharedTextCx {
+    /// Register the font(s) within the given TrueType or OpenType file data.
+    ///
+    /// Returns a handle to the family of the first font, or `None` if the data contains no fonts.
+    /// The font is also made available to bevy's text rendering.
+    pub fn register_font_bytes(&self, bytes: impl Into<Vec<u8>>) -> Option<FontHandle> {
+        let bytes: Vec<u8> = bytes.into();
+        let mut inner = self.0.lock().unwrap();
+        let collection = &mut inner.font.collection;
+        let registered = collection.register_fonts(bytes.into(), None);
+        let (family_id, _) = registered.first()?;
+        let name = collection.family_name(*family_id)?.to_string();
+        Some(FontHandle {
+            family: Arc::new(RwLock::new(Some(name))),
+            asset: None,
+        })
+    }
+}
+
+impl FontFamilies {
+    /// Begin loading the font at the given asset path, returning a handle to its family.
+    ///
+    /// Bevy registers the font with the shared font collection once loaded, after which the
+    /// handle resolves to the font's family name.
+    pub fn load<'a>(
+        &self,
+        asset_server: &AssetServer,
+        path: impl Into<AssetPath<'a>>,
+    ) -> FontHandle {
+        let asset: Handle<Font> = asset_server.load(path);
+        let family = self
+            .0
+            .lock()
+            .unwrap()
+            .entry(asset.id())
+            .or_default()
+            .clone();
+        FontHandle {
+            family,
+            asset: Some(asset),
+        }
+    }
+}
+
+// The family name within the given font data, as used by fontique to identify the family.
+fn family_name(data: &[u8]) -> Option<String> {
+    let font = FontRef::from_index(data, 0).ok()?;
+    [StringId::TYPOGRAPHIC_FAMILY_NAME, StringId::FAMILY_NAME]
+        .into_iter()
+        .find_map(|id| font.localized_strings(id).english_or_first())
+        .map(|name| name.to_string())
+}
+
+/// Resolve the family names of loaded font assets, updating them when an asset is modified.
+pub(crate) fn update_font_families(
+    mut events: MessageReader<AssetEvent<Font>>,
+    fonts: Res<Assets<Font>>,
+    families: Res<FontFamilies>,
+) {
+    let mut families = families.0.lock().unwrap();
+    let mut modified = vec![];
+    for event in events.read() {
+        match *event {
+            AssetEvent::Modified { id } => modified.push(id),
+            AssetEvent::Removed { id } => {
+                families.remove(&id);
+            }
+            _ => (),
+        }
+    }
+    for (id, family) in families.iter() {
+        let unresolved = family.read().unwrap().is_none();
+        if !unresolved && !modified.contains(id) {
+            continue;
+        }
+        if let Some(font) = fonts.get(*id) {
+            *family.write().unwrap() = family_name(font.data.as_ref());
+        }
+    }
+}
+
 /// Initialise the font database shared between the bevy and nannou text contexts.
 ///
 /// The same fontique collection backs both bevy's [`bevy::text::FontCx`] (kept in sync with
@@ -49,5 +183,6 @@ pub(crate) fn init_shared_text_cx(app: &mut App) {
         font,
         layout: LayoutContext::new(),
     };
-    app.insert_resource(SharedTextCx(Arc::new(Mutex::new(inner))));
+    app.insert_resource(SharedTextCx(Arc::new(Mutex::new(inner))))
+        .init_resource::<FontFamilies>();
 }
@@ -2,11 +2,15 @@
 
 use bevy::prelude::*;
 use lyon::path::PathEvent;
+use lyon::path::iterator::PathIterator;
+use lyon::tessellation::{BuffersBuilder, FillOptions, FillTessellator, FillVertex, VertexBuffers};
 use skrifa::instance::{LocationRef, Size};
 use skrifa::outline::{DrawSettings, OutlinePen};
 use skrifa::raw::TableProvider;
 use skrifa::{FontRef, GlyphId, MetadataProvider};
 
+use crate::draw::mesh::MeshExt;
+
 /// Converts skrifa outline commands into lyon `PathEvent` values.
 struct LyonOutlinePen {
     events: Vec<PathEvent>,
@@ -159,3 +163,106 @@ pub fn per_glyph_path_events(
 
     per_glyph
 }
+
+/// Extract a lyon path for each glyph separately.
+///
+/// Glyphs without an outline yield an empty path, preserving glyph indexing.
+pub fn glyph_paths(
+    parley_layout: &parley::Layout<Color>,
+    pos_offset: Vec2,
+    layout_scale: f32,
+) -> Vec<lyon::path::Path> {
+    per_glyph_path_events(parley_layout, pos_offset, layout_scale)
+        .into_iter()
+        .map(|events| {
+            let mut builder = lyon::path::Path::builder();
+            for event in events {
+                builder.path_event(event);
+            }
+            builder.build()
+        })
+        .collect()
+}
+
+/// Extrude the given outline into a solid 3D mesh with the given depth along the *z* axis.
+///
+/// The outline is filled with the non-zero fill rule to produce front and back faces at
+/// `depth / 2` and `-depth / 2`, which are joined by walls along each contour. Curves are
+/// flattened with the given tolerance. Vertices are white with flat normals, ready for lighting.
+pub fn extrude<I>(events: I, depth: f32, tolerance: f32) -> Mesh
+where
+    I: IntoIterator<Item = PathEvent>,
+{
+    let events: Vec<PathEvent> = events.into_iter().collect();
+    let mut mesh = Mesh::init();
+    let half = depth * 0.5;
+
+    // The front and back faces.
+    let mut face: VertexBuffers<Vec2, u32> = VertexBuffers::new();
+    let options = FillOptions::non_zero().with_tolerance(tolerance);
+    let _ = FillTessellator::new().tessellate(
+        events.iter().copied(),
+        &options,
+        &mut BuffersBuilder::new(&mut face, |v: FillVertex| {
+            Vec2::new(v.position().x, v.position().y)
+        }),
+    );
+    for (z, normal) in [(half, Vec3::Z), (-half, Vec3::NEG_Z)] {
+        let base = mesh.points().len() as u32;
+        for v in &face.vertices {
+            push_vertex(&mut mesh, v.extend(z), normal);
+        }
+        for tri in face.indices.chunks_exact(3) {
+            // Reverse the winding of the back face so that it faces away from the front.
+            let tri = if z > 0.0 {
+                [tri[0], tri[1], tri[2]]
+            } else {
+                [tri[0], tri[2], tri[1]]
+            };
+            for i in tri {
+                mesh.push_index(base + i);
+            }
+        }
+    }
+
+    // The walls along each flattened edge, with vertices duplicated for flat normals.
+    let wall = |mesh: &mut Mesh, a: lyon::math::Point, b: lyon::math::Point| {
+        let (a, b) = (Vec2::new(a.x, a.y), Vec2::new(b.x, b.y));
+        let Some(dir) = (b - a).try_normalize() else {
+            return;
+        };
+        let normal = Vec3::new(dir.y, -dir.x, 0.0);
+        let corners = [
+            a.extend(half),
+            b.extend(half),
+            b.extend(-half),
+            a.extend(-half),
+        ];
+        let ix = corners.map(|p| push_vertex(mesh, p, normal));
+        for i in [0, 2, 1, 0, 3, 2] {
+            mesh.push_index(ix[i]);
+        }
+    };
+    for event in events.into_iter().flattened(tolerance) {
+        match event {
+            PathEvent::Line { from, to } => wall(&mut mesh, from, to),
+            PathEvent::End {
+                last,
+                first,
+                close: true,
+            } => wall(&mut mesh, last, first),
+            _ => (),
+        }
+    }
+
+    mesh
+}
+
+// Push a white vertex to the mesh, returning its index.
+fn push_vertex(mesh: &mut Mesh, point: Vec3, normal: Vec3) -> u32 {
+    mesh.points_mut().push(point.to_array());
+    mesh.colors_mut().push([1.0; 4]);
+    mesh.tex_coords_mut().push([0.0; 2]);
+    mesh.normals_mut().push(normal.to_array());
+    mesh.points().len() as u32 - 1
+}
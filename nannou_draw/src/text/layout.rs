@@ -1,9 +1,10 @@
 //! Items related to the styling of text.
 
-use crate::text::{Align, FontSize, Justify, Scalar, Wrap};
+use crate::text::{Align, FontFeature, FontSize, FontStyle, Justify, Scalar, Wrap};
 
 /// A context for building a text layout.
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Builder {
     pub line_spacing: Option<Scalar>,
     pub line_wrap: Option<Option<Wrap>>,
@@ -11,6 +12,12 @@ pub struct Builder {
     pub justify: Option<Justify>,
     pub font_family: Option<Option<String>>,
     pub y_align: Option<Align>,
+    pub font_weight: Option<f32>,
+    pub font_style: Option<FontStyle>,
+    pub font_stretch: Option<f32>,
+    pub letter_spacing: Option<Scalar>,
+    pub word_spacing: Option<Scalar>,
+    pub font_features: Option<Vec<FontFeature>>,
 }
 
 /// Properties related to the layout of multi-line text for a single font and font size.
@@ -22,6 +29,12 @@ pub struct Layout {
     pub font_size: FontSize,
     pub font_family: Option<String>,
     pub y_align: Align,
+    pub font_weight: f32,
+    pub font_style: FontStyle,
+    pub font_stretch: f32,
+    pub letter_spacing: Scalar,
+    pub word_spacing: Scalar,
+    pub font_features: Vec<FontFeature>,
 }
 
 pub const DEFAULT_LINE_WRAP: Option<Wrap> = Some(Wrap::Whitespace);
@@ -29,6 +42,11 @@ pub const DEFAULT_FONT_SIZE: u32 = 12;
 pub const DEFAULT_LINE_SPACING: f32 = 0.0;
 pub const DEFAULT_JUSTIFY: Justify = Justify::Center;
 pub const DEFAULT_Y_ALIGN: Align = Align::Middle;
+pub const DEFAULT_FONT_WEIGHT: f32 = 400.0;
+pub const DEFAULT_FONT_STYLE: FontStyle = FontStyle::Normal;
+pub const DEFAULT_FONT_STRETCH: f32 = 1.0;
+pub const DEFAULT_LETTER_SPACING: f32 = 0.0;
+pub const DEFAULT_WORD_SPACING: f32 = 0.0;
 
 impl Builder {
     /// The font size to use for the text.
@@ -122,14 +140,92 @@ impl Builder {
         self.y_align(Align::Start)
     }
 
+    /// The weight of the font, from `100.0` (thin) to `900.0` (black).
+    ///
+    /// The default value is `DEFAULT_FONT_WEIGHT`, i.e. regular.
+    pub fn font_weight(mut self, weight: f32) -> Self {
+        self.font_weight = Some(weight);
+        self
+    }
+
+    /// Short-hand for a font weight of `700.0`.
+    pub fn bold(self) -> Self {
+        self.font_weight(700.0)
+    }
+
+    /// Whether the font should be upright, italic or oblique.
+    pub fn font_style(mut self, style: FontStyle) -> Self {
+        self.font_style = Some(style);
+        self
+    }
+
+    /// Short-hand for `builder.font_style(FontStyle::Italic)`.
+    pub fn italic(self) -> Self {
+        self.font_style(FontStyle::Italic)
+    }
+
+    /// The width of the font as a ratio of its normal width, e.g. `0.75` for a condensed face or
+    /// `1.25` for an expanded face.
+    ///
+    /// Only has an effect for font families with faces of different widths.
+    pub fn font_stretch(mut self, ratio: f32) -> Self {
+        self.font_stretch = Some(ratio);
+        self
+    }
+
+    /// Additional space between each character.
+    pub fn letter_spacing(mut self, spacing: Scalar) -> Self {
+        self.letter_spacing = Some(spacing);
+        self
+    }
+
+    /// Additional space between each word.
+    pub fn word_spacing(mut self, spacing: Scalar) -> Self {
+        self.word_spacing = Some(spacing);
+        self
+    }
+
+    /// Enable, disable or select an alternative for the given OpenType feature.
+    ///
+    /// Replaces any value previously given for the same feature.
+    pub fn font_feature(mut self, feature: FontFeature) -> Self {
+        let features = self.font_features.get_or_insert_with(Vec::new);
+        features.retain(|f| f.tag != feature.tag);
+        features.push(feature);
+        self
+    }
+
+    /// Enable or disable standard ligatures, e.g. "fi".
+    ///
+    /// Ligatures are enabled by default where supported by the font.
+    pub fn ligatures(self, enabled: bool) -> Self {
+        self.font_feature(FontFeature::new(*b"liga", enabled as u16))
+    }
+
+    /// Use numerals of equal width, e.g. so that columns of numbers line up.
+    pub fn tabular_numerals(self) -> Self {
+        self.font_feature(FontFeature::on(*b"tnum"))
+    }
+
+    /// Display lowercase letters as small capitals.
+    pub fn small_caps(self) -> Self {
+        self.font_feature(FontFeature::on(*b"smcp"))
+    }
+
     /// Set all the parameters via an existing `Layout`
     pub fn layout(mut self, layout: &Layout) -> Self {
         self.font_family = Some(layout.font_family.clone());
+        self.font_features = Some(layout.font_features.clone());
         self.line_spacing(layout.line_spacing)
             .line_wrap(layout.line_wrap)
             .justify(layout.justify)
             .font_size(layout.font_size)
             .y_align(layout.y_align)
+            .font_weight(layout.font_weight)
+            .font_style(layout.font_style)
+            .font_stretch(layout.font_stretch)
+            .letter_spacing(layout.letter_spacing)
+            .word_spacing(layout.word_spacing)
     }
 
     /// Build the text layout.
@@ -141,6 +237,12 @@ impl Builder {
             font_size: self.font_size.unwrap_or(DEFAULT_FONT_SIZE),
             font_family: self.font_family.unwrap_or(None),
             y_align: self.y_align.unwrap_or(DEFAULT_Y_ALIGN),
+            font_weight: self.font_weight.unwrap_or(DEFAULT_FONT_WEIGHT),
+            font_style: self.font_style.unwrap_or(DEFAULT_FONT_STYLE),
+            font_stretch: self.font_stretch.unwrap_or(DEFAULT_FONT_STRETCH),
+            letter_spacing: self.letter_spacing.unwrap_or(DEFAULT_LETTER_SPACING),
+            word_spacing: self.word_spacing.unwrap_or(DEFAULT_WORD_SPACING),
+            font_features: self.font_features.unwrap_or_default(),
         }
     }
 }
@@ -154,6 +256,12 @@ impl Default for Layout {
             font_size: DEFAULT_FONT_SIZE,
             font_family: None,
             y_align: DEFAULT_Y_ALIGN,
+            font_weight: DEFAULT_FONT_WEIGHT,
+            font_style: DEFAULT_FONT_STYLE,
+            font_stretch: DEFAULT_FONT_STRETCH,
+            letter_spacing: DEFAULT_LETTER_SPACING,
+            word_spacing: DEFAULT_WORD_SPACING,
+            font_features: vec![],
         }
     }
 }
@@ -8,10 +8,13 @@ use parley::Alignment;
 use parley::style::{FontFamily, FontFamilyName, StyleProperty, WordBreak};
 
 pub use self::layout::Layout;
+pub use self::span::{Span, SpanStyle};
 
 pub mod font;
 pub mod glyph;
 pub mod layout;
+pub mod path;
+pub mod span;
 
 /// The type used for scalar values.
 pub type Scalar = nannou_core::geom::scalar::Default;
@@ -24,6 +27,7 @@ pub type FontSize = u32;
 
 /// Alignment along an axis.
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Align {
     Start,
     Middle,
@@ -32,6 +36,7 @@ pub enum Align {
 
 /// A type used for referring to typographic alignment of `Text`.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Justify {
     /// Align text to the start of the bounding `Rect`'s *x* axis.
     Left,
@@ -43,6 +48,7 @@ pub enum Justify {
 
 /// The way in which text should wrap around the width.
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Wrap {
     /// Wrap at the first character that exceeds the width.
     Character,
@@ -50,6 +56,67 @@ pub enum Wrap {
     Whitespace,
 }
 
+/// The slant of a font.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FontStyle {
+    /// Upright glyphs.
+    #[default]
+    Normal,
+    /// The italic face of the font family, falling back to slanting the upright glyphs.
+    Italic,
+    /// Slanted glyphs, optionally by the given angle in degrees.
+    Oblique(Option<f32>),
+}
+
+/// An OpenType feature setting, e.g. enabling tabular numerals via `tnum`.
+///
+/// A value of `0` disables the feature and `1` enables it, while features with alternatives
+/// such as stylistic sets use larger values to select between them.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FontFeature {
+    /// The four byte feature tag, e.g. `*b"liga"`.
+    pub tag: [u8; 4],
+    pub value: u16,
+}
+
+impl FontFeature {
+    /// A feature setting with the given tag and value.
+    pub fn new(tag: [u8; 4], value: u16) -> Self {
+        FontFeature { tag, value }
+    }
+
+    /// Enable the feature with the given tag.
+    pub fn on(tag: [u8; 4]) -> Self {
+        Self::new(tag, 1)
+    }
+
+    /// Disable the feature with the given tag.
+    pub fn off(tag: [u8; 4]) -> Self {
+        Self::new(tag, 0)
+    }
+}
+
+impl From<FontStyle> for parley::style::FontStyle {
+    fn from(style: FontStyle) -> Self {
+        match style {
+            FontStyle::Normal => parley::style::FontStyle::Normal,
+            FontStyle::Italic => parley::style::FontStyle::Italic,
+            FontStyle::Oblique(angle) => parley::style::FontStyle::Oblique(angle),
+        }
+    }
+}
+
+// Format font features in the CSS `font-feature-settings` syntax understood by parley.
+fn font_feature_settings(features: &[FontFeature]) -> String {
+    features
+        .iter()
+        .map(|f| format!("\"{}\" {}", String::from_utf8_lossy(&f.tag), f.value))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 /// A builder for laying out **Text** immediately.
 pub struct Builder<'a> {
     text: Cow<'a, str>,
@@ -163,6 +230,61 @@ impl<'a> Builder<'a> {
         self.map_layout(|l| l.align_bottom())
     }
 
+    /// The weight of the font, from `100.0` (thin) to `900.0` (black).
+    pub fn font_weight(self, weight: f32) -> Self {
+        self.map_layout(|l| l.font_weight(weight))
+    }
+
+    /// Short-hand for a font weight of `700.0`.
+    pub fn bold(self) -> Self {
+        self.map_layout(|l| l.bold())
+    }
+
+    /// Whether the font should be upright, italic or oblique.
+    pub fn font_style(self, style: FontStyle) -> Self {
+        self.map_layout(|l| l.font_style(style))
+    }
+
+    /// Use the italic style of the font.
+    pub fn italic(self) -> Self {
+        self.map_layout(|l| l.italic())
+    }
+
+    /// The width of the font as a ratio of its normal width.
+    pub fn font_stretch(self, ratio: f32) -> Self {
+        self.map_layout(|l| l.font_stretch(ratio))
+    }
+
+    /// Additional space between each character.
+    pub fn letter_spacing(self, spacing: Scalar) -> Self {
+        self.map_layout(|l| l.letter_spacing(spacing))
+    }
+
+    /// Additional space between each word.
+    pub fn word_spacing(self, spacing: Scalar) -> Self {
+        self.map_layout(|l| l.word_spacing(spacing))
+    }
+
+    /// Enable, disable or select an alternative for the given OpenType feature.
+    pub fn font_feature(self, feature: FontFeature) -> Self {
+        self.map_layout(|l| l.font_feature(feature))
+    }
+
+    /// Enable or disable standard ligatures.
+    pub fn ligatures(self, enabled: bool) -> Self {
+        self.map_layout(|l| l.ligatures(enabled))
+    }
+
+    /// Use numerals of equal width.
+    pub fn tabular_numerals(self) -> Self {
+        self.map_layout(|l| l.tabular_numerals())
+    }
+
+    /// Display lowercase letters as small capitals.
+    pub fn small_caps(self) -> Self {
+        self.map_layout(|l| l.small_caps())
+    }
+
     /// Set all the parameters via an existing `Layout`.
     pub fn layout(self, layout: &Layout) -> Self {
         self.map_layout(|l| l.layout(layout))
@@ -172,32 +294,51 @@ impl<'a> Builder<'a> {
     pub fn build(self, rect: geom::Rect) -> Text {
         let layout = self.layout_builder.build();
         let mut inner = self.text_cx.0.lock().unwrap();
-        Text::layout_with_inner(&mut inner, &self.text, &layout, rect, 1.0)
+        Text::layout_with_inner(
+            &mut inner,
+            &self.text,
+            &layout,
+            &[],
+            Color::WHITE,
+            rect,
+            1.0,
+        )
     }
 }
 
+// The parley font family for the given family name.
+pub(crate) fn font_family(family: &str) -> FontFamily<'_> {
+    // `parse` resolves generic names like "monospace"; fall back to treating the whole string as
+    // a family name.
+    let name = FontFamilyName::parse(family).unwrap_or(FontFamilyName::named(family));
+    FontFamily::Single(name)
+}
+
 impl Text {
     /// Compute a parley layout using an already-locked inner context.
+    ///
+    /// `color` is the color of glyphs outside of any span specifying its own.
     pub(crate) fn layout_with_inner(
         inner: &mut font::NannouTextCxInner,
         text: &str,
         layout: &Layout,
+        spans: &[Span],
+        color: Color,
         rect: geom::Rect,
         scale: f32,
     ) -> Self {
         let font_size = layout.font_size as f32;
+        let font_features = font_feature_settings(&layout.font_features);
 
         let mut builder = inner
             .layout
             .ranged_builder(&mut inner.font, text, scale, true);
 
         builder.push_default(StyleProperty::FontSize(font_size));
+        builder.push_default(StyleProperty::Brush(color));
 
         if let Some(ref family) = layout.font_family {
-            // `parse` resolves generic names like "monospace"; fall back to treating
-            // the whole string as a family name.
-            let name = FontFamilyName::parse(family).unwrap_or(FontFamilyName::named(family));
-            builder.push_default(StyleProperty::FontFamily(FontFamily::Single(name)));
+            builder.push_default(StyleProperty::FontFamily(font_family(family)));
         }
 
         if let Some(spacing) = (layout.line_spacing != 0.0).then_some(layout.line_spacing) {
@@ -206,10 +347,41 @@ impl Text {
             ));
         }
 
+        if layout.font_weight != self::layout::DEFAULT_FONT_WEIGHT {
+            let weight = parley::style::FontWeight::new(layout.font_weight);
+            builder.push_default(StyleProperty::FontWeight(weight));
+        }
+
+        if layout.font_style != FontStyle::Normal {
+            builder.push_default(StyleProperty::FontStyle(layout.font_style.into()));
+        }
+
+        if layout.font_stretch != self::layout::DEFAULT_FONT_STRETCH {
+            let width = parley::style::FontWidth::from_ratio(layout.font_stretch);
+            builder.push_default(StyleProperty::FontWidth(width));
+        }
+
+        if layout.letter_spacing != 0.0 {
+            builder.push_default(StyleProperty::LetterSpacing(layout.letter_spacing));
+        }
+
+        if layout.word_spacing != 0.0 {
+            builder.push_default(StyleProperty::WordSpacing(layout.word_spacing));
+        }
+
+        if !font_features.is_empty() {
+            builder.push_default(StyleProperty::FontFeatures(font_features.as_str().into()));
+        }
+
         if let Some(Wrap::Character) = layout.line_wrap {
             builder.push_default(StyleProperty::WordBreak(WordBreak::BreakAll));
         }
 
+        for span in spans {
+            let range = span.range.start.min(text.len())..span.range.end.min(text.len());
+            span.style.push_properties(&mut builder, range);
+        }
+
         let mut parley_layout = builder.build(text);
 
         match layout.line_wrap {
@@ -329,11 +501,69 @@ impl Text {
         rects
     }
 
+    /// The underlines and strikethroughs of each run of glyphs, along with their colors.
+    pub fn decoration_rects(&self) -> Vec<(geom::Rect, Color)> {
+        let offset = self.position_offset();
+        let scale = self.scale;
+        let mut rects = Vec::new();
+        for line in self.parley_layout.lines() {
+            for item in line.items() {
+                let parley::PositionedLayoutItem::GlyphRun(glyph_run) = item else {
+                    continue;
+                };
+                let style = glyph_run.style();
+                let metrics = glyph_run.run().metrics();
+                let decorations = [
+                    (
+                        &style.underline,
+                        metrics.underline_offset,
+                        metrics.underline_size,
+                    ),
+                    (
+                        &style.strikethrough,
+                        metrics.strikethrough_offset,
+                        metrics.strikethrough_size,
+                    ),
+                ];
+                for (decoration, default_offset, default_size) in decorations {
+                    let Some(decoration) = decoration else {
+                        continue;
+                    };
+                    // The offset is from the baseline up to the top of the line, in parley's
+                    // y-down layout space.
+                    let offset_y = decoration.offset.unwrap_or(default_offset);
+                    let size = decoration.size.unwrap_or(default_size);
+                    let top = offset.y - (glyph_run.baseline() - offset_y) / scale;
+                    let left = offset.x + glyph_run.offset() / scale;
+                    let x = geom::Range::new(left, left + glyph_run.advance() / scale);
+                    let y = geom::Range::new(top - size / scale, top);
+                    rects.push((geom::Rect { x, y }, decoration.brush));
+                }
+            }
+        }
+        rects
+    }
+
     /// Path events for every glyph, relative to the center of the layout rect.
     pub fn path_events(&self) -> Vec<lyon::path::PathEvent> {
         glyph::text_path_events(&self.parley_layout, self.position_offset(), self.scale)
     }
 
+    /// A path for each glyph, relative to the center of the layout rect.
+    ///
+    /// Glyphs without an outline, e.g. spaces, yield an empty path.
+    pub fn glyph_paths(&self) -> Vec<lyon::path::Path> {
+        glyph::glyph_paths(&self.parley_layout, self.position_offset(), self.scale)
+    }
+
+    /// Extrude the outlines of all glyphs into a solid 3D mesh with the given depth.
+    ///
+    /// See [glyph::extrude].
+    pub fn extrude(&self, depth: f32) -> Mesh {
+        let tolerance = lyon::tessellation::FillOptions::DEFAULT_TOLERANCE;
+        glyph::extrude(self.path_events(), depth, tolerance)
+    }
+
     pub(crate) fn parley_layout(&self) -> &parley::Layout<Color> {
         &self.parley_layout
     }
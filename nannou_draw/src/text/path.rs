@@ -0,0 +1,133 @@
+//! Items related to laying out text along a path.
+
+use bevy::prelude::*;
+use lyon::path::PathEvent;
+use lyon::path::iterator::PathIterator;
+
+use crate::text::Justify;
+
+/// How glyphs that fall beyond the ends of a path are handled.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Overflow {
+    /// Glyphs beyond either end of the path are not drawn.
+    #[default]
+    Hide,
+    /// Glyphs continue in a straight line in the direction of the path at either end.
+    Extend,
+    /// Glyphs wrap around to the other end of the path, e.g. for text circling a closed shape.
+    Wrap,
+}
+
+/// A path flattened to lines and measured by arc length.
+#[derive(Clone, Debug, Default)]
+pub struct ArcLengthPath {
+    segments: Vec<Segment>,
+    length: f32,
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Segment {
+    start: Vec2,
+    end: Vec2,
+    // The arc length of the path at the start of the segment.
+    distance: f32,
+}
+
+impl ArcLengthPath {
+    /// Flatten the given path with the given tolerance and measure each of its segments.
+    ///
+    /// Sub-paths are treated as a single continuous path, skipping the gaps between them.
+    pub fn new<I>(events: I, tolerance: f32) -> Self
+    where
+        I: IntoIterator<Item = PathEvent>,
+    {
+        let mut segments = vec![];
+        let mut length = 0.0;
+        let mut push = |start: lyon::math::Point, end: lyon::math::Point| {
+            let (start, end) = (Vec2::new(start.x, start.y), Vec2::new(end.x, end.y));
+            let len = start.distance(end);
+            if len > 0.0 {
+                segments.push(Segment {
+                    start,
+                    end,
+                    distance: length,
+                });
+                length += len;
+            }
+        };
+        for event in events.into_iter().flattened(tolerance) {
+            match event {
+                PathEvent::Line { from, to } => push(from, to),
+                PathEvent::End {
+                    last,
+                    first,
+                    close: true,
+                } => push(last, first),
+                _ => (),
+            }
+        }
+        ArcLengthPath { segments, length }
+    }
+
+    /// The total length of the path.
+    pub fn length(&self) -> f32 {
+        self.length
+    }
+
+    /// The point at the given distance along the path, along with the unit direction of the path
+    /// at that point.
+    ///
+    /// Returns `None` if the path is empty, or if the distance lies beyond the ends of the path
+    /// and the overflow is [Overflow::Hide].
+    pub fn sample(&self, distance: f32, overflow: Overflow) -> Option<(Vec2, Vec2)> {
+        let (first, last) = (self.segments.first()?, self.segments.last()?);
+        let distance = match overflow {
+            Overflow::Hide if distance < 0.0 || distance > self.length => return None,
+            Overflow::Wrap => distance.rem_euclid(self.length),
+            _ => distance,
+        };
+        // Find the last segment beginning at or before the distance. Distances beyond the ends
+        // of the path extend the first or last segment.
+        let ix = self
+            .segments
+            .partition_point(|s| s.distance <= distance)
+            .saturating_sub(1);
+        let segment = match distance {
+            d if d < 0.0 => first,
+            d if d > self.length => last,
+            _ => &self.segments[ix],
+        };
+        let direction = (segment.end - segment.start).normalize();
+        let point = segment.start + direction * (distance - segment.distance);
+        Some((point, direction))
+    }
+}
+
+/// The distance along a path of length `path_len` at which text of the given width begins.
+///
+/// Text is aligned to the start, middle or end of the path via the given justification before
+/// being shifted along the path by `offset`.
+pub fn start_distance(path_len: f32, text_width: f32, justify: Justify, offset: f32) -> f32 {
+    let start = match justify {
+        Justify::Left => 0.0,
+        Justify::Center => (path_len - text_width) * 0.5,
+        Justify::Right => path_len - text_width,
+    };
+    start + offset
+}
+
+/// A transform that places a glyph upright on the path at the given point and direction.
+///
+/// The glyph's anchor, i.e. the point at the middle of its advance on the first baseline, is
+/// moved to the given point and the glyph is rotated to follow the direction of the path.
+pub fn glyph_transform(anchor: Vec2, point: Vec2, direction: Vec2) -> Mat4 {
+    let normal = direction.perp();
+    let place = Mat4::from_cols(
+        direction.extend(0.0).extend(0.0),
+        normal.extend(0.0).extend(0.0),
+        Vec4::Z,
+        point.extend(0.0).extend(1.0),
+    );
+    place * Mat4::from_translation(-anchor.extend(0.0))
+}
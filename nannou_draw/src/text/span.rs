@@ -0,0 +1,118 @@
+//! Styling for spans of rich text.
+
+use std::ops::Range;
+
+use bevy::prelude::*;
+use parley::style::{FontStyle, FontWeight, StyleProperty};
+
+use crate::text::{self, FontSize};
+
+/// A range of text styled differently from the rest of its paragraph.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Span {
+    /// The byte range of the span within the text.
+    pub range: Range<usize>,
+    pub style: SpanStyle,
+}
+
+/// Styling properties for a [Span]. Properties that are not set are inherited from the text.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpanStyle {
+    pub font_family: Option<String>,
+    pub font_size: Option<FontSize>,
+    pub weight: Option<f32>,
+    pub italic: bool,
+    pub color: Option<Color>,
+    pub underline: bool,
+    pub strikethrough: bool,
+}
+
+impl SpanStyle {
+    /// The font family used for the span.
+    pub fn font(mut self, family: impl Into<String>) -> Self {
+        self.font_family = Some(family.into());
+        self
+    }
+
+    /// The font size used for the span.
+    pub fn font_size(mut self, size: FontSize) -> Self {
+        self.font_size = Some(size);
+        self
+    }
+
+    /// The weight of the font, from `100.0` (thin) to `900.0` (black), where regular text is
+    /// `400.0`.
+    pub fn weight(mut self, weight: f32) -> Self {
+        self.weight = Some(weight);
+        self
+    }
+
+    /// Short-hand for a weight of `700.0`.
+    pub fn bold(self) -> Self {
+        self.weight(700.0)
+    }
+
+    /// Use the italic style of the font.
+    pub fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+
+    /// The color of the span's glyphs and decorations.
+    pub fn color<C>(mut self, color: C) -> Self
+    where
+        C: Into<Color>,
+    {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Draw a line beneath the span.
+    pub fn underline(mut self) -> Self {
+        self.underline = true;
+        self
+    }
+
+    /// Draw a line through the middle of the span.
+    pub fn strikethrough(mut self) -> Self {
+        self.strikethrough = true;
+        self
+    }
+
+    // Push the style's properties for the given byte range to the layout builder.
+    pub(crate) fn push_properties(
+        &self,
+        builder: &mut parley::RangedBuilder<'_, Color>,
+        range: Range<usize>,
+    ) {
+        if let Some(ref family) = self.font_family {
+            builder.push(
+                StyleProperty::FontFamily(text::font_family(family)),
+                range.clone(),
+            );
+        }
+        if let Some(size) = self.font_size {
+            builder.push(StyleProperty::FontSize(size as f32), range.clone());
+        }
+        if let Some(weight) = self.weight {
+            builder.push(
+                StyleProperty::FontWeight(FontWeight::new(weight)),
+                range.clone(),
+            );
+        }
+        if self.italic {
+            builder.push(StyleProperty::FontStyle(FontStyle::Italic), range.clone());
+        }
+        if let Some(color) = self.color {
+            builder.push(StyleProperty::Brush(color), range.clone());
+        }
+        if self.underline {
+            builder.push(StyleProperty::Underline(true), range.clone());
+        }
+        if self.strikethrough {
+            builder.push(StyleProperty::Strikethrough(true), range);
+        }
+    }
+}
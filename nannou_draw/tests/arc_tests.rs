@@ -0,0 +1,89 @@
+//! Tests for the arc, pie and ring primitives.
+
+mod common;
+
+use std::f32::consts::{FRAC_PI_2, PI};
+
+use bevy::prelude::*;
+
+use common::{mesh_area, mesh_points, tessellate};
+
+// Whether the area is within 2% of the expected area.
+fn approx_area(area: f32, expected: f32) -> bool {
+    (area - expected).abs() < expected * 0.02
+}
+
+#[test]
+fn test_arc_is_open() {
+    let mesh = tessellate(|d| {
+        d.arc().radius(50.0).angles(0.0, FRAC_PI_2).weight(2.0);
+    });
+    let points = mesh_points(&mesh);
+    // The stroke begins and ends at either end of the arc.
+    for end in [Vec2::new(50.0, 0.0), Vec2::new(0.0, 50.0)] {
+        assert!(
+            points.iter().any(|p| p.distance(end) <= 1.0 + 1e-3),
+            "{end}"
+        );
+    }
+    for p in &points {
+        assert!((p.length() - 50.0).abs() < 1.1, "{p:?}");
+        assert!(p.x >= -1e-2 && p.y >= -1e-2, "{p:?}");
+    }
+    // Closing the arc would also stroke the chord between its ends.
+    let area = mesh_area(&mesh);
+    assert!(approx_area(area, FRAC_PI_2 * 50.0 * 2.0), "{area}");
+}
+
+#[test]
+fn test_pie_includes_centre() {
+    let mesh = tessellate(|d| {
+        d.pie().radius(50.0).angles(0.0, FRAC_PI_2).resolution(8.0);
+    });
+    let points = mesh_points(&mesh);
+    assert!(points.contains(&Vec2::ZERO), "{points:?}");
+    // The centre followed by each end of the 8 segments.
+    assert_eq!(points.len(), 10);
+    let area = 8.0 * 0.5 * 50.0 * 50.0 * (FRAC_PI_2 / 8.0).sin();
+    assert!(
+        (mesh_area(&mesh) - area).abs() < 1e-2,
+        "{}",
+        mesh_area(&mesh)
+    );
+}
+
+#[test]
+fn test_full_ring_has_hole() {
+    let mesh = tessellate(|d| {
+        d.ring().radius(50.0).inner_radius(20.0);
+    });
+    for p in mesh_points(&mesh) {
+        let r = p.length();
+        assert!((r - 50.0).abs() < 1e-3 || (r - 20.0).abs() < 1e-3, "{p:?}");
+    }
+    let area = mesh_area(&mesh);
+    assert!(
+        approx_area(area, PI * (50.0 * 50.0 - 20.0 * 20.0)),
+        "{area}"
+    );
+}
+
+#[test]
+fn test_partial_ring() {
+    let mesh = tessellate(|d| {
+        d.ring()
+            .radius(50.0)
+            .inner_radius(40.0)
+            .angles(-FRAC_PI_2, FRAC_PI_2);
+    });
+    for p in mesh_points(&mesh) {
+        assert!(p.x >= -1e-3, "{p:?}");
+        let r = p.length();
+        assert!(r > 40.0 - 1e-3 && r < 50.0 + 1e-3, "{p:?}");
+    }
+    let area = mesh_area(&mesh);
+    assert!(
+        approx_area(area, FRAC_PI_2 * (50.0 * 50.0 - 40.0 * 40.0)),
+        "{area}"
+    );
+}
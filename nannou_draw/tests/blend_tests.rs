@@ -0,0 +1,40 @@
+//! Tests for the named blend mode presets.
+
+use bevy::render::render_resource::{BlendComponent, BlendFactor, BlendOperation};
+use nannou_draw::render::DefaultNannouShaderModel;
+use nannou_draw::render::blend::BlendMode;
+
+#[test]
+fn test_normal_matches_default_model() {
+    let model = DefaultNannouShaderModel::default();
+    assert_eq!(model.blend, Some(BlendMode::default().blend_state()));
+    assert!(!model.premultiply_alpha);
+}
+
+#[test]
+fn test_blend_mode_states() {
+    // Modes scaling existing colors by the drawn color need it premultiplied to respect alpha.
+    assert!(BlendMode::Multiply.premultiplies_alpha());
+    assert!(BlendMode::Screen.premultiplies_alpha());
+    assert!(!BlendMode::Premultiplied.premultiplies_alpha());
+    assert_eq!(
+        BlendMode::Screen.blend_state().color,
+        BlendComponent {
+            src_factor: BlendFactor::One,
+            dst_factor: BlendFactor::OneMinusSrc,
+            operation: BlendOperation::Add,
+        }
+    );
+    assert_eq!(
+        BlendMode::Replace.blend_state().alpha,
+        BlendComponent::REPLACE
+    );
+    // Min and max ignore their factors, which wgpu requires to be `One`.
+    for mode in [BlendMode::Lighten, BlendMode::Darken] {
+        let color = mode.blend_state().color;
+        assert_eq!(
+            (color.src_factor, color.dst_factor),
+            (BlendFactor::One, BlendFactor::One)
+        );
+    }
+}
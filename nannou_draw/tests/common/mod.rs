@@ -0,0 +1,50 @@
+//! Fixtures shared between the integration tests.
+#![allow(dead_code)]
+
+use std::sync::{Arc, Mutex};
+
+use bevy::prelude::*;
+use nannou_draw::draw::Draw;
+use nannou_draw::draw::mesh::MeshExt;
+use nannou_draw::text::font::{NannouTextCxInner, SharedTextCx};
+use parley::{FontContext, LayoutContext};
+
+/// A text context with an empty font collection.
+pub fn new_text_cx() -> SharedTextCx {
+    SharedTextCx(Arc::new(Mutex::new(NannouTextCxInner {
+        font: FontContext::default(),
+        layout: LayoutContext::new(),
+    })))
+}
+
+/// A `Draw` that is not associated with any window.
+pub fn new_draw() -> Draw {
+    Draw::new(Entity::PLACEHOLDER, new_text_cx())
+}
+
+/// The mesh tessellated for everything drawn by the given function with the default shader model.
+pub fn tessellate(f: impl FnOnce(&Draw)) -> Mesh {
+    let picture = new_draw().record_picture(f);
+    picture.meshes().next().cloned().expect("nothing was drawn")
+}
+
+/// The *x* and *y* coordinates of each vertex of the mesh.
+pub fn mesh_points(mesh: &Mesh) -> Vec<Vec2> {
+    mesh.points()
+        .iter()
+        .map(|&[x, y, _]| Vec2::new(x, y))
+        .collect()
+}
+
+/// The area of the given triangle.
+pub fn triangle_area([a, b, c]: [Vec2; 3]) -> f32 {
+    ((b - a).perp_dot(c - a) * 0.5).abs()
+}
+
+/// The total area of the triangles of the mesh, projected onto the *xy* plane.
+pub fn mesh_area(mesh: &Mesh) -> f32 {
+    let point = |i: usize| Vec3::from(mesh.points()[mesh.get_index(i) as usize]).truncate();
+    (0..mesh.count_indices() / 3)
+        .map(|tri| triangle_area([point(tri * 3), point(tri * 3 + 1), point(tri * 3 + 2)]))
+        .sum()
+}
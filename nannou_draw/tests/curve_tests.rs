@@ -0,0 +1,99 @@
+//! Tests for the bezier, curve and spline primitives.
+
+mod common;
+
+use bevy::prelude::*;
+use lyon::path::PathEvent;
+use nannou_draw::draw::mesh::MeshExt;
+use nannou_draw::draw::primitive::curve::catmull_rom;
+use nannou_draw::draw::primitive::spline::b_spline;
+
+use common::{mesh_area, mesh_points, tessellate};
+
+// The end point of each segment, starting with the first point of the path.
+fn endpoints(events: &[PathEvent]) -> Vec<Vec2> {
+    events
+        .iter()
+        .filter_map(|e| match *e {
+            PathEvent::Begin { at } => Some(at),
+            PathEvent::Cubic { to, .. } => Some(to),
+            _ => None,
+        })
+        .map(|p| Vec2::new(p.x, p.y))
+        .collect()
+}
+
+const POINTS: [Vec2; 4] = [
+    Vec2::new(-40.0, 0.0),
+    Vec2::new(-10.0, 30.0),
+    Vec2::new(10.0, -30.0),
+    Vec2::new(40.0, 0.0),
+];
+
+#[test]
+fn test_catmull_rom_passes_through_points() {
+    let events = catmull_rom(&POINTS, 0.0, false);
+    assert_eq!(endpoints(&events), POINTS);
+    assert!(matches!(
+        events.last(),
+        Some(PathEvent::End { close: false, .. })
+    ));
+
+    let events = catmull_rom(&POINTS, 0.0, true);
+    let mut expected = POINTS.to_vec();
+    expected.push(POINTS[0]);
+    assert_eq!(endpoints(&events), expected);
+}
+
+#[test]
+fn test_catmull_rom_full_tension_is_straight() {
+    for event in catmull_rom(&POINTS, 1.0, false) {
+        if let PathEvent::Cubic {
+            from,
+            ctrl1,
+            ctrl2,
+            to,
+        } = event
+        {
+            assert_eq!(ctrl1, from);
+            assert_eq!(ctrl2, to);
+        }
+    }
+}
+
+#[test]
+fn test_b_spline_ends() {
+    let points = endpoints(&b_spline(&POINTS, false));
+    assert_eq!(points.first(), Some(&POINTS[0]));
+    assert_eq!(points.last(), Some(&POINTS[3]));
+    // Interior control points pull the spline without it passing through them.
+    assert!(!points.contains(&POINTS[1]));
+
+    let points = endpoints(&b_spline(&POINTS, true));
+    let (first, last) = (points[0], *points.last().unwrap());
+    assert!(first.abs_diff_eq(last, 1e-4), "{first} {last}");
+    assert!(b_spline(&POINTS[..1], false).is_empty());
+}
+
+#[test]
+fn test_draw_curves() {
+    // The region between a quadratic curve and its chord covers two thirds of the bounding
+    // triangle formed with its control point.
+    let mesh = tessellate(|d| {
+        d.bezier()
+            .quadratic(Vec2::ZERO, Vec2::new(20.0, 40.0), Vec2::new(40.0, 0.0));
+    });
+    let area = mesh_area(&mesh);
+    assert!((area - 2.0 / 3.0 * 40.0 * 20.0).abs() < 5.0, "{area}");
+
+    // The stroked curve passes through its first and last points.
+    let mesh = tessellate(|d| {
+        d.curve().no_fill().stroke(Color::WHITE).points(POINTS);
+    });
+    let points = mesh_points(&mesh);
+    for end in [POINTS[0], POINTS[3]] {
+        assert!(points.iter().any(|p| p.distance(end) <= 1.0), "{end}");
+    }
+    let white = LinearRgba::WHITE.to_f32_array();
+    assert!(mesh.colors().iter().all(|c| *c == white));
+}
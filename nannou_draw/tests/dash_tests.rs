@@ -0,0 +1,125 @@
+//! Tests for splitting strokes into dashes via `.dashes(..)` and `.dash_offset(..)`.
+
+mod common;
+
+use bevy::prelude::*;
+use nannou_core::geom;
+use nannou_draw::draw::properties::dash::Dashes;
+
+use common::new_draw;
+
+fn dashes(pattern: &[f32], offset: f32) -> Dashes {
+    Dashes {
+        pattern: pattern.to_vec(),
+        offset,
+    }
+}
+
+// Split a straight line from the origin along *x*, returning the *x* extent of each dash.
+fn split_line(dashes: &Dashes, len: f32) -> Vec<(f32, f32)> {
+    let points = [(Vec2::ZERO, ()), (Vec2::new(len, 0.0), ())];
+    dashes
+        .split_points(points, false, |_, _, _| ())
+        .into_iter()
+        .map(|dash| (dash[0].0.x, dash[dash.len() - 1].0.x))
+        .collect()
+}
+
+#[test]
+fn test_solid_patterns() {
+    assert!(dashes(&[], 0.0).is_solid());
+    assert!(dashes(&[0.0, 0.0], 0.0).is_solid());
+    assert!(dashes(&[5.0, -1.0], 0.0).is_solid());
+    assert!(!dashes(&[5.0, 5.0], 0.0).is_solid());
+}
+
+#[test]
+fn test_split_line() {
+    let split = split_line(&dashes(&[10.0, 5.0], 0.0), 40.0);
+    assert_eq!(split, [(0.0, 10.0), (15.0, 25.0), (30.0, 40.0)]);
+}
+
+#[test]
+fn test_split_odd_pattern_repeats() {
+    // `[10]` is equivalent to `[10, 10]`.
+    let split = split_line(&dashes(&[10.0], 0.0), 40.0);
+    assert_eq!(split, [(0.0, 10.0), (20.0, 30.0)]);
+}
+
+#[test]
+fn test_split_offset() {
+    let split = split_line(&dashes(&[10.0, 5.0], 5.0), 30.0);
+    assert_eq!(split, [(0.0, 5.0), (10.0, 20.0), (25.0, 30.0)]);
+    // Negative offsets wrap around the pattern.
+    let split = split_line(&dashes(&[10.0, 5.0], -5.0), 30.0);
+    assert_eq!(split, [(5.0, 15.0), (20.0, 30.0)]);
+}
+
+#[test]
+fn test_split_zero_length_dots() {
+    let split = split_line(&dashes(&[0.0, 10.0], 0.0), 25.0);
+    assert_eq!(split, [(0.0, 0.0), (10.0, 10.0), (20.0, 20.0)]);
+}
+
+#[test]
+fn test_split_tiny_pattern_terminates() {
+    assert!(dashes(&[f32::EPSILON / 4.0, f32::EPSILON / 4.0], 0.0).is_solid());
+    // Far along the line, each length is lost to rounding when added to the distance travelled.
+    let split = split_line(&dashes(&[1e-4, 1e-4], 0.0), 1e4);
+    assert!(!split.is_empty() && split.len() <= 100_000);
+    assert!(split.iter().all(|&(start, end)| start <= end && end <= 1e4));
+}
+
+#[test]
+fn test_split_across_corners_and_close() {
+    // A closed 10x10 square, perimeter 40.
+    let square = [[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0]];
+    let points = square.map(|p| (Vec2::from(p), ()));
+    let split = dashes(&[15.0, 5.0, 20.0, 0.0], 0.0).split_points(points, true, |_, _, _| ());
+    assert_eq!(split.len(), 2);
+    // The first dash turns the first corner.
+    let first: Vec<_> = split[0].iter().map(|(p, _)| *p).collect();
+    assert_eq!(
+        first,
+        [Vec2::ZERO, Vec2::new(10.0, 0.0), Vec2::new(10.0, 5.0)]
+    );
+    // The second dash follows the closing edge back to the start.
+    let second: Vec<_> = split[1].iter().map(|(p, _)| *p).collect();
+    assert_eq!(
+        second,
+        [Vec2::new(10.0, 10.0), Vec2::new(0.0, 10.0), Vec2::ZERO]
+    );
+}
+
+#[test]
+fn test_split_interpolates_attributes() {
+    let points = [(Vec2::ZERO, 0.0), (Vec2::new(10.0, 0.0), 1.0)];
+    let split = dashes(&[4.0, 2.0], 0.0).split_points(points, false, |a, b, t| a + (b - a) * t);
+    assert_eq!(split[0][1].1, 0.4);
+    assert_eq!(split[1][0].1, 0.6);
+}
+
+#[test]
+fn test_dashed_drawings() {
+    let draw = new_draw();
+    draw.line()
+        .start(Vec2::ZERO)
+        .end(Vec2::new(40.0, 0.0))
+        .dashes(&[10.0, 5.0]);
+    draw.rect()
+        .w_h(20.0, 20.0)
+        .no_fill()
+        .stroke_weight(1.0)
+        .dashes(&[5.0]);
+    draw.polyline()
+        .dashes(&[10.0, 5.0])
+        .dash_offset(5.0)
+        .points([Vec2::ZERO, Vec2::new(0.0, 30.0)]);
+    let svg = draw.to_svg_string(geom::Rect::from_w_h(200.0, 200.0));
+    let paths: Vec<_> = svg.lines().filter(|l| l.starts_with("<path")).collect();
+    // Three dashes along the line and eight around the rect's 80 unit perimeter.
+    assert_eq!(paths.len(), 3 + 8 + 1);
+    assert!(paths[..11].iter().all(|p| p.matches('M').count() == 1));
+    // Each dash of the polyline is written as its own sub-path.
+    assert!(paths[11].starts_with(r#"<path d="M0 0 L0 -5 M0 -10 L0 -20 M0 -25 L0 -30""#));
+}
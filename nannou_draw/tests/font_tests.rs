@@ -0,0 +1,24 @@
+//! Tests for registering fonts at runtime.
+
+mod common;
+
+use common::new_text_cx;
+
+#[test]
+fn test_register_invalid_font_bytes() {
+    let text_cx = new_text_cx();
+    assert!(text_cx.register_font_bytes(vec![0u8; 64]).is_none());
+}
+
+#[cfg(feature = "notosans")]
+#[test]
+fn test_register_font_bytes() {
+    let text_cx = new_text_cx();
+    let handle = text_cx
+        .register_font_bytes(notosans::REGULAR_TTF)
+        .expect("failed to register font");
+    assert!(handle.is_loaded());
+    assert!(handle.asset().is_none());
+    assert_eq!(handle.family_name().as_deref(), Some("Noto Sans"));
+    assert_eq!(String::from(&handle), "Noto Sans");
+}
@@ -0,0 +1,125 @@
+//! Tests for linear and radial gradient colors.
+
+mod common;
+
+use bevy::prelude::*;
+use nannou_core::geom;
+use nannou_draw::draw::mesh::MeshExt;
+use nannou_draw::draw::properties::SetGradient;
+use nannou_draw::draw::properties::gradient::{Gradient, Interpolation, MAX_SUBDIVISIONS};
+
+use common::{new_draw, tessellate};
+
+fn assert_srgb_eq(color: Option<Color>, expected: [f32; 3]) {
+    let srgba = Srgba::from(color.expect("expected an active gradient"));
+    let actual = [srgba.red, srgba.green, srgba.blue];
+    for (a, e) in actual.iter().zip(expected) {
+        assert!(
+            (a - e).abs() < 1e-4,
+            "expected {expected:?}, found {actual:?}"
+        );
+    }
+}
+
+#[test]
+fn test_inactive_gradient() {
+    assert!(!Gradient::default().is_active());
+    let no_stops =
+        Gradient::default().linear_gradient(Vec2::ZERO, Vec2::X, Vec::<(f32, Color)>::new());
+    assert!(!no_stops.is_active());
+    assert_eq!(no_stops.color_at(Vec2::ZERO), None);
+}
+
+#[test]
+fn test_linear_gradient() {
+    let stops = [(0.0, Color::BLACK), (1.0, Color::WHITE)];
+    let gradient = Gradient::default().linear_gradient(Vec2::ZERO, Vec2::new(10.0, 0.0), stops);
+    assert_srgb_eq(gradient.color_at(Vec2::new(5.0, 100.0)), [0.5; 3]);
+    // Points beyond either end take the color of the nearest stop.
+    assert_srgb_eq(gradient.color_at(Vec2::new(-5.0, 0.0)), [0.0; 3]);
+    assert_srgb_eq(gradient.color_at(Vec2::new(15.0, 0.0)), [1.0; 3]);
+}
+
+#[test]
+fn test_radial_gradient_with_unsorted_stops() {
+    let red = Color::srgb(1.0, 0.0, 0.0);
+    let blue = Color::srgb(0.0, 0.0, 1.0);
+    let stops = [(1.0, blue), (0.0, red), (0.5, Color::WHITE)];
+    let gradient = Gradient::default().radial_gradient(Vec2::ONE, 4.0, stops);
+    assert_srgb_eq(gradient.color_at(Vec2::ONE), [1.0, 0.0, 0.0]);
+    assert_srgb_eq(gradient.color_at(Vec2::new(1.0, 3.0)), [1.0, 1.0, 1.0]);
+    assert_srgb_eq(gradient.color_at(Vec2::new(1.0, 4.0)), [0.5, 0.5, 1.0]);
+    assert_srgb_eq(gradient.color_at(Vec2::new(5.0, 1.0)), [0.0, 0.0, 1.0]);
+}
+
+#[test]
+fn test_interpolation() {
+    let stops = [(0.0, Color::BLACK), (1.0, Color::WHITE)];
+    let gradient = Gradient::default().linear_gradient(Vec2::ZERO, Vec2::X, stops);
+    let mid = Vec2::new(0.5, 0.0);
+    let srgb = gradient.color_at(mid).unwrap();
+    let linear = gradient
+        .clone()
+        .gradient_interpolation(Interpolation::Linear)
+        .color_at(mid)
+        .unwrap();
+    let oklab = gradient
+        .gradient_interpolation(Interpolation::Oklab)
+        .color_at(mid)
+        .unwrap();
+    // Mixing in linear RGB produces a brighter midpoint than mixing encoded sRGB components.
+    assert!(Srgba::from(linear).red > Srgba::from(srgb).red);
+    assert!(Srgba::from(oklab).red != Srgba::from(srgb).red);
+    let linear_mid = Srgba::from(LinearRgba::gray(0.5)).red;
+    assert_srgb_eq(Some(linear), [linear_mid; 3]);
+}
+
+#[test]
+fn test_subdivisions_are_clamped() {
+    let mesh = tessellate(|d| {
+        d.rect()
+            .linear_gradient(
+                Vec2::ZERO,
+                Vec2::X,
+                [(0.0, Color::WHITE), (1.0, Color::BLACK)],
+            )
+            .gradient_subdivisions(12);
+    });
+    // Each of the rect's two triangles is split into four per level.
+    let triangles = 2 * 4usize.pow(MAX_SUBDIVISIONS);
+    assert_eq!(mesh.count_indices(), triangles * 3);
+}
+
+#[test]
+fn test_gradients_in_svg() {
+    let draw = new_draw();
+    draw.rect()
+        .w_h(100.0, 50.0)
+        .linear_gradient(
+            Vec2::new(-50.0, 0.0),
+            Vec2::new(50.0, 0.0),
+            [(0.0, Color::BLACK), (1.0, Color::WHITE)],
+        )
+        .stroke(Color::WHITE)
+        .stroke_weight(2.0);
+    draw.ellipse()
+        .radius(20.0)
+        .radial_gradient(Vec2::ZERO, 20.0, [(0.0, Color::WHITE), (1.0, Color::BLACK)])
+        .gradient_interpolation(Interpolation::Oklab);
+    draw.rect().w_h(10.0, 10.0).color(Color::WHITE);
+    let svg = draw.to_svg_string(geom::Rect::from_w_h(200.0, 200.0));
+
+    assert_eq!(svg.matches("<linearGradient").count(), 1);
+    assert_eq!(svg.matches("<radialGradient").count(), 1);
+    assert!(svg.contains(r#"x1="-50" y1="0" x2="50" y2="0""#));
+    assert!(svg.contains(r##"<stop offset="0" stop-color="#000000" stop-opacity="1"/>"##));
+    // Oklab gradients are approximated with additional stops.
+    assert_eq!(svg.matches("<stop").count(), 2 + 33);
+    // The fill refers to the gradient while the stroke retains its color.
+    let paths: Vec<_> = svg.lines().filter(|l| l.starts_with("<path")).collect();
+    assert_eq!(paths.len(), 4);
+    assert!(paths[0].contains(r#"fill="url(#gradient0)""#));
+    assert!(paths[1].contains(r##"stroke="#ffffff""##));
+    assert!(paths[2].contains(r#"fill="url(#gradient1)""#));
+    assert!(paths[3].contains(r##"fill="#ffffff""##));
+}
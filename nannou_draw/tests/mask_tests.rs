@@ -0,0 +1,230 @@
+//! CPU-side tests for `Draw::mask` and `Draw::inverse_mask`.
+//!
+//! These cover the geometry tessellated for a mask, the clipping of mesh geometry against it
+//! and the draw commands recorded for masked drawings. No GPU or bevy `App` is required.
+
+mod common;
+
+use std::f32::consts::PI;
+use std::sync::Arc;
+
+use bevy::prelude::*;
+use nannou_draw::draw::mesh::MeshExt;
+use nannou_draw::draw::{Draw, DrawCommand, Mask};
+
+use common::{mesh_area, new_draw, triangle_area};
+
+fn mask_area(mask: &Mask) -> f32 {
+    mask.triangles().iter().copied().map(triangle_area).sum()
+}
+
+// A mesh containing a single axis-aligned square with the given side length, centred on the
+// origin.
+fn square_mesh(side: f32) -> Mesh {
+    let mut mesh = Mesh::init();
+    let h = side / 2.0;
+    for [x, y] in [[-h, -h], [h, -h], [h, h], [-h, h]] {
+        mesh.points_mut().push([x, y, 0.0]);
+        mesh.colors_mut().push([1.0, 0.0, 0.0, 1.0]);
+        mesh.tex_coords_mut().push([x / side + 0.5, y / side + 0.5]);
+        mesh.normals_mut().push([0.0, 0.0, 1.0]);
+    }
+    for i in [0, 1, 2, 0, 2, 3] {
+        mesh.push_index(i);
+    }
+    mesh
+}
+
+// The mask of the draw, as recorded by the first drawing made with it.
+fn recorded_mask(draw: &Draw) -> Arc<Mask> {
+    draw.rect();
+    draw.drain_commands()
+        .find_map(|cmd| match cmd {
+            DrawCommand::Mask(mask) => mask,
+            _ => None,
+        })
+        .expect("no mask recorded")
+}
+
+fn assert_approx(a: f32, b: f32) {
+    assert!((a - b).abs() < 1e-2, "{a} != {b}");
+}
+
+#[test]
+fn test_mask_geometry_rect() {
+    let draw = new_draw();
+    let masked = draw.mask(|m| {
+        m.rect().w_h(100.0, 50.0);
+    });
+    let mask = recorded_mask(&masked);
+    assert!(!mask.is_inverse());
+    assert!(mask.parent().is_none());
+    assert_approx(mask_area(&mask), 5000.0);
+    for p in mask.triangles().iter().flatten() {
+        assert!(p.x.abs() <= 50.0 + 1e-3 && p.y.abs() <= 25.0 + 1e-3);
+    }
+}
+
+#[test]
+fn test_mask_geometry_ellipse() {
+    let draw = new_draw();
+    let masked = draw.mask(|m| {
+        m.ellipse().w_h(300.0, 300.0);
+    });
+    let mask = recorded_mask(&masked);
+    let area = mask_area(&mask);
+    let circle = PI * 150.0 * 150.0;
+    // The tessellated circle is a polygon inscribed within the true circle.
+    assert!(area <= circle && area > circle * 0.98, "{area} vs {circle}");
+    for p in mask.triangles().iter().flatten() {
+        assert!(p.length() <= 150.0 + 1e-3);
+    }
+}
+
+#[test]
+fn test_overlapping_mask_shapes_clip_once() {
+    let draw = new_draw();
+    let mask = recorded_mask(&draw.mask(|m| {
+        m.rect().x(-25.0).w_h(100.0, 100.0);
+        m.rect().x(25.0).w_h(100.0, 100.0);
+    }));
+    // The overlap of the two shapes is only covered once.
+    assert_approx(mask_area(&mask), 150.0 * 100.0);
+    let mut mesh = square_mesh(200.0);
+    mask.apply(&mut mesh, 0, 0);
+    assert_approx(mesh_area(&mesh), 150.0 * 100.0);
+}
+
+#[test]
+fn test_mask_geometry_uses_draw_transform() {
+    let draw = new_draw();
+    let masked = draw.x(100.0).mask(|m| {
+        m.rect().w_h(20.0, 20.0);
+    });
+    let mask = recorded_mask(&masked);
+    for p in mask.triangles().iter().flatten() {
+        assert!(p.x >= 90.0 - 1e-3 && p.x <= 110.0 + 1e-3);
+    }
+}
+
+#[test]
+fn test_mask_ignores_drawing_to_parent() {
+    let draw = new_draw();
+    let _masked = draw.mask(|m| {
+        m.rect().w_h(20.0, 20.0);
+    });
+    // Mask shapes are never drawn to the output.
+    assert_eq!(draw.drain_commands().count(), 0);
+}
+
+#[test]
+fn test_mask_clips_mesh() {
+    let draw = new_draw();
+    let mask = recorded_mask(&draw.mask(|m| {
+        m.rect().w_h(100.0, 100.0);
+    }));
+    let mut mesh = square_mesh(200.0);
+    mask.apply(&mut mesh, 0, 0);
+    assert_approx(mesh_area(&mesh), 100.0 * 100.0);
+    for p in mesh.points() {
+        assert!(p[0].abs() <= 50.0 + 1e-3 && p[1].abs() <= 50.0 + 1e-3);
+    }
+    // Attributes are interpolated across the clipped geometry.
+    for (p, uv) in mesh.points().iter().zip(mesh.tex_coords()) {
+        assert_approx(uv[0], p[0] / 200.0 + 0.5);
+        assert_approx(uv[1], p[1] / 200.0 + 0.5);
+    }
+    assert_eq!(mesh.points().len(), mesh.colors().len());
+    assert_eq!(mesh.points().len(), mesh.normals().len());
+}
+
+#[test]
+fn test_inverse_mask_clips_mesh() {
+    let draw = new_draw();
+    let mask = recorded_mask(&draw.inverse_mask(|m| {
+        m.rect().w_h(100.0, 100.0);
+    }));
+    assert!(mask.is_inverse());
+    let mut mesh = square_mesh(200.0);
+    mask.apply(&mut mesh, 0, 0);
+    assert_approx(mesh_area(&mesh), 200.0 * 200.0 - 100.0 * 100.0);
+}
+
+#[test]
+fn test_mask_only_clips_new_geometry() {
+    let draw = new_draw();
+    let mask = recorded_mask(&draw.mask(|m| {
+        m.rect().w_h(100.0, 100.0);
+    }));
+    let mut mesh = square_mesh(200.0);
+    let vertex_start = mesh.count_vertices();
+    let index_start = mesh.count_indices();
+    // Append a second, disjoint square entirely outside of the mask.
+    let second = square_mesh(10.0);
+    for (i, p) in second.points().iter().enumerate() {
+        mesh.points_mut().push([p[0] + 500.0, p[1], p[2]]);
+        mesh.colors_mut().push(second.colors()[i]);
+        mesh.tex_coords_mut().push(second.tex_coords()[i]);
+        mesh.normals_mut().push(second.normals()[i]);
+    }
+    for i in 0..second.count_indices() {
+        mesh.push_index(vertex_start as u32 + second.get_index(i));
+    }
+    mask.apply(&mut mesh, vertex_start, index_start);
+    assert_eq!(mesh.count_vertices(), vertex_start);
+    assert_eq!(mesh.count_indices(), index_start);
+    assert_approx(mesh_area(&mesh), 200.0 * 200.0);
+}
+
+#[test]
+fn test_nested_masks_intersect() {
+    let draw = new_draw();
+    let outer = draw.mask(|m| {
+        m.rect().x(-25.0).w_h(100.0, 100.0);
+    });
+    let inner = outer.mask(|m| {
+        m.rect().x(25.0).w_h(100.0, 100.0);
+    });
+    let mask = recorded_mask(&inner);
+    assert!(mask.parent().is_some());
+    let mut mesh = square_mesh(200.0);
+    mask.apply(&mut mesh, 0, 0);
+    assert_approx(mesh_area(&mesh), 50.0 * 100.0);
+}
+
+#[test]
+fn test_mask_command_stream() {
+    let draw = new_draw();
+    let masked = draw.mask(|m| {
+        m.ellipse().w_h(300.0, 300.0);
+    });
+    draw.rect();
+    masked.rect();
+    masked.ellipse();
+    draw.rect();
+    let cmds: Vec<_> = draw.drain_commands().collect();
+    let masks: Vec<_> = cmds
+        .iter()
+        .enumerate()
+        .filter_map(|(i, cmd)| match cmd {
+            DrawCommand::Mask(mask) => Some((i, mask.is_some())),
+            _ => None,
+        })
+        .collect();
+    // A mask command is only recorded when the mask changes from the unmasked default.
+    assert_eq!(masks.len(), 2);
+    assert_eq!(
+        masks.iter().map(|&(_, some)| some).collect::<Vec<_>>(),
+        [true, false]
+    );
+    // Each mask command precedes the primitives drawn with it.
+    let primitives: Vec<_> = cmds
+        .iter()
+        .enumerate()
+        .filter(|(_, cmd)| matches!(cmd, DrawCommand::Primitive(_)))
+        .map(|(i, _)| i)
+        .collect();
+    assert_eq!(primitives.len(), 4);
+    assert!(primitives[0] < masks[0].0 && masks[0].0 < primitives[1]);
+    assert!(primitives[2] < masks[1].0 && masks[1].0 < primitives[3]);
+}
@@ -0,0 +1,71 @@
+//! Tests for the ngon and star primitives.
+
+mod common;
+
+use bevy::prelude::*;
+
+use common::{mesh_area, mesh_points, tessellate};
+
+// The number of the given points at the given distance from the origin.
+fn count_at_radius(points: &[Vec2], radius: f32) -> usize {
+    points
+        .iter()
+        .filter(|p| (p.length() - radius).abs() < 1e-3)
+        .count()
+}
+
+#[test]
+fn test_ngon_vertices() {
+    let mesh = tessellate(|d| {
+        d.ngon().sides(6).radius(50.0);
+    });
+    let points = mesh_points(&mesh);
+    assert_eq!(points.len(), 6, "{points:?}");
+    assert_eq!(count_at_radius(&points, 50.0), 6, "{points:?}");
+    // The first vertex is at the top.
+    let top = Vec2::new(0.0, 50.0);
+    assert!(
+        points.iter().any(|p| p.abs_diff_eq(top, 1e-3)),
+        "{points:?}"
+    );
+    let area = 1.5 * 3f32.sqrt() * 50.0 * 50.0;
+    assert!(
+        (mesh_area(&mesh) - area).abs() < 0.1,
+        "{}",
+        mesh_area(&mesh)
+    );
+}
+
+#[test]
+fn test_ngon_sides_are_clamped() {
+    let mesh = tessellate(|d| {
+        d.ngon().sides(1).radius(50.0);
+    });
+    assert_eq!(mesh_points(&mesh).len(), 3);
+}
+
+#[test]
+fn test_star_alternates_radii() {
+    let mesh = tessellate(|d| {
+        d.star().points(5).radius(50.0).inner_radius(20.0);
+    });
+    let points = mesh_points(&mesh);
+    assert_eq!(points.len(), 10);
+    assert_eq!(count_at_radius(&points, 50.0), 5, "{points:?}");
+    assert_eq!(count_at_radius(&points, 20.0), 5, "{points:?}");
+    let top = Vec2::new(0.0, 50.0);
+    assert!(
+        points.iter().any(|p| p.abs_diff_eq(top, 1e-3)),
+        "{points:?}"
+    );
+}
+
+#[test]
+fn test_star_default_inner_radius() {
+    let mesh = tessellate(|d| {
+        d.star().radius(50.0);
+    });
+    let points = mesh_points(&mesh);
+    assert_eq!(points.len(), 10);
+    assert_eq!(count_at_radius(&points, 25.0), 5, "{points:?}");
+}
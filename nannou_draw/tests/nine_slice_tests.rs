@@ -0,0 +1,62 @@
+//! Tests for the nine-slice primitive.
+
+mod common;
+
+use bevy::prelude::*;
+use nannou_draw::draw::Draw;
+use nannou_draw::draw::mesh::MeshExt;
+
+use common::new_draw;
+
+// The positions and texture coordinates of the nine-slice grid, row by row from the top-left.
+fn grid(draw: &Draw, f: impl FnOnce(&Draw)) -> Vec<([f32; 2], [f32; 2])> {
+    let picture = draw.record_picture(f);
+    let mesh = picture.meshes().next().unwrap();
+    assert_eq!(mesh.count_indices(), 9 * 6);
+    mesh.points()
+        .iter()
+        .map(|p| [p[0], p[1]])
+        .zip(mesh.tex_coords().iter().copied())
+        .collect()
+}
+
+#[test]
+fn test_nine_slice_keeps_borders() {
+    let draw = new_draw();
+    let points = grid(&draw, |d| {
+        d.nine_slice(&Handle::default())
+            .texture_size(30.0, 30.0)
+            .borders(10.0, 10.0, 5.0, 5.0)
+            .w_h(100.0, 60.0);
+    });
+    assert_eq!(points.len(), 16);
+    let xs: Vec<f32> = points[..4].iter().map(|(p, _)| p[0]).collect();
+    let ys: Vec<f32> = points.iter().step_by(4).map(|(p, _)| p[1]).collect();
+    assert_eq!(xs, [-50.0, -40.0, 40.0, 50.0]);
+    assert_eq!(ys, [30.0, 25.0, -25.0, -30.0]);
+    // Corners sample the same number of texels as they cover, from a top-left origin.
+    let (_, uv) = points[5];
+    assert!((uv[0] - 1.0 / 3.0).abs() < 1e-5 && (uv[1] - 1.0 / 6.0).abs() < 1e-5);
+    assert_eq!(points[0].1, [0.0, 0.0]);
+    assert_eq!(points[15].1, [1.0, 1.0]);
+}
+
+#[test]
+fn test_nine_slice_fits_borders() {
+    let draw = new_draw();
+    let sized = grid(&draw, |d| {
+        d.nine_slice(&Handle::default())
+            .texture_size(30.0, 30.0)
+            .borders(10.0, 10.0, 10.0, 10.0);
+    });
+    // Defaults to the size of the texture.
+    assert_eq!(sized[15].0, [15.0, -15.0]);
+
+    let squashed = grid(&draw, |d| {
+        d.nine_slice(&Handle::default())
+            .borders(30.0, 10.0, 0.0, 0.0)
+            .w_h(20.0, 20.0);
+    });
+    let xs: Vec<f32> = squashed[..4].iter().map(|(p, _)| p[0]).collect();
+    assert_eq!(xs, [-10.0, 5.0, 5.0, 10.0]);
+}
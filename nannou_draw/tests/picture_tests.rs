@@ -0,0 +1,91 @@
+//! CPU-side tests for recording and replaying pictures via `Draw::record_picture`.
+
+mod common;
+
+use bevy::prelude::*;
+use nannou_core::geom;
+use nannou_draw::draw::DrawCommand;
+use nannou_draw::draw::mesh::MeshExt;
+use nannou_draw::draw::primitive::Primitive;
+
+use common::new_draw;
+
+#[test]
+fn test_record_picture() {
+    let draw = new_draw();
+    assert!(draw.record_picture(|_| {}).is_empty());
+
+    let picture = draw.record_picture(|d| {
+        d.rect().x(100.0).w_h(10.0, 10.0);
+        d.rect().texture(&Handle::default());
+        d.ellipse();
+    });
+    assert!(!picture.is_empty());
+    // A new mesh is started for each change in shader model.
+    let meshes: Vec<_> = picture.meshes().collect();
+    assert_eq!(meshes.len(), 3);
+    // Geometry is tessellated in the picture's local space.
+    let xs: Vec<f32> = meshes[0].points().iter().map(|p| p[0]).collect();
+    assert!(xs.iter().all(|x| (95.0..=105.0).contains(x)), "{xs:?}");
+
+    // Recording leaves the draw itself untouched.
+    assert_eq!(draw.drain_commands().count(), 0);
+}
+
+#[test]
+fn test_replay_picture() {
+    let draw = new_draw();
+    let picture = draw.record_picture(|d| {
+        d.rect().color(Color::WHITE);
+    });
+    for i in 0..3 {
+        draw.picture(&picture).x(i as f32 * 20.0);
+    }
+    let pictures = draw
+        .drain_commands()
+        .filter(|cmd| matches!(cmd, DrawCommand::Primitive(Primitive::Picture(_))))
+        .count();
+    assert_eq!(pictures, 3);
+}
+
+#[test]
+fn test_replayed_normals_are_transformed() {
+    let draw = new_draw();
+    let picture = draw.record_picture(|d| {
+        d.cuboid().w_h_d(10.0, 10.0, 10.0);
+    });
+    let rotation = Quat::from_rotation_y(std::f32::consts::FRAC_PI_2);
+    // Replaying a tinted picture within another re-tessellates its layers.
+    let replayed = draw.record_picture(|d| {
+        d.picture(&picture)
+            .quaternion(rotation)
+            .color(Color::srgb(1.0, 0.0, 0.0));
+    });
+    let src = picture.meshes().next().unwrap();
+    let dst = replayed.meshes().next().unwrap();
+    assert_eq!(src.normals().len(), dst.normals().len());
+    assert!(src.normals().iter().any(|n| n[2].abs() < 0.5));
+    for (src, dst) in src.normals().iter().zip(dst.normals()) {
+        let expected = rotation * Vec3::from(*src);
+        assert!(
+            expected.distance(Vec3::from(*dst)) < 1e-4,
+            "{expected:?} != {dst:?}"
+        );
+    }
+}
+
+#[test]
+fn test_tinted_picture_in_svg() {
+    let draw = new_draw();
+    let picture = draw.record_picture(|d| {
+        d.rect().w_h(10.0, 10.0).color(Color::WHITE);
+    });
+    draw.picture(&picture);
+    draw.picture(&picture)
+        .x(50.0)
+        .color(Color::srgb(1.0, 0.0, 0.0));
+    let svg = draw.to_svg_string(geom::Rect::from_w_h(200.0, 200.0));
+    // Pictures are written as their tessellated triangles.
+    assert_eq!(svg.matches(r##"fill="#ffffff""##).count(), 2);
+    assert_eq!(svg.matches(r##"fill="#ff0000""##).count(), 2);
+}
@@ -0,0 +1,60 @@
+//! Tests for the CPU tessellation of point clouds, as used within pictures, masks and SVG.
+
+mod common;
+
+use bevy::prelude::*;
+use nannou_core::geom;
+use nannou_draw::draw::Draw;
+use nannou_draw::draw::mesh::MeshExt;
+
+use common::new_draw;
+
+// Tessellate the drawings made by `f` into a single mesh.
+fn mesh(f: impl FnOnce(&Draw)) -> Mesh {
+    let picture = new_draw().record_picture(f);
+    let mut meshes = picture.meshes();
+    let mesh = meshes.next().unwrap().clone();
+    assert!(meshes.next().is_none());
+    mesh
+}
+
+#[test]
+fn test_square_points() {
+    let mesh = mesh(|draw| {
+        draw.points([vec2(0.0, 0.0), vec2(20.0, 10.0)])
+            .square()
+            .size(4.0);
+    });
+    assert_eq!(mesh.count_vertices(), 8);
+    assert_eq!(mesh.count_indices(), 12);
+    let points = mesh.points();
+    for (i, center) in [vec2(0.0, 0.0), vec2(20.0, 10.0)].into_iter().enumerate() {
+        for p in &points[i * 4..i * 4 + 4] {
+            let offset = (Vec2::new(p[0], p[1]) - center).abs();
+            assert!(offset.abs_diff_eq(Vec2::splat(2.0), 1e-5), "{p:?}");
+        }
+    }
+}
+
+#[test]
+fn test_per_point_color_and_size() {
+    let red = Color::srgb(1.0, 0.0, 0.0);
+    let mesh = mesh(|draw| {
+        draw.points([(vec2(0.0, 0.0), red, 10.0)])
+            .square()
+            .color(Color::WHITE);
+    });
+    for (p, c) in mesh.points().iter().zip(mesh.colors()) {
+        assert!((p[0].abs() - 5.0).abs() < 1e-5, "{p:?}");
+        assert_eq!(*c, red.to_linear().to_f32_array());
+    }
+}
+
+#[test]
+fn test_round_points_in_svg() {
+    let draw = new_draw();
+    draw.points([vec2(0.0, 0.0), vec2(50.0, 0.0), vec2(0.0, 50.0)])
+        .size(10.0);
+    let svg = draw.to_svg_string(geom::Rect::from_w_h(200.0, 200.0));
+    assert_eq!(svg.lines().filter(|l| l.starts_with("<path")).count(), 3);
+}
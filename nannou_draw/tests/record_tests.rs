@@ -0,0 +1,105 @@
+//! CPU-side tests for serializing draw commands and replaying recorded frames.
+#![cfg(feature = "serde")]
+
+mod common;
+
+use bevy::prelude::*;
+use nannou_core::geom;
+use nannou_draw::draw::{Draw, DrawCommand};
+use nannou_draw::record::{DrawPlayer, DrawRecorder, Frame};
+
+use common::new_draw;
+
+// Draw a little of everything that refers to the draw's intermediary buffers.
+fn draw_scene(draw: &Draw) {
+    draw.background().color(Color::BLACK);
+    draw.rect()
+        .x_y(-20.0, 10.0)
+        .w_h(10.0, 20.0)
+        .color(Color::WHITE);
+    draw.polyline().weight(2.0).dashes(&[4.0, 2.0]).points([
+        Vec2::ZERO,
+        Vec2::new(30.0, 10.0),
+        Vec2::new(40.0, -10.0),
+    ]);
+    draw.polygon().color(Color::srgb(0.0, 0.0, 1.0)).points([
+        Vec2::ZERO,
+        Vec2::new(10.0, 0.0),
+        Vec2::new(5.0, 8.0),
+    ]);
+    draw.mesh().points_colored([
+        (Vec2::new(-5.0, -5.0), Color::srgb(1.0, 0.0, 0.0)),
+        (Vec2::new(5.0, -5.0), Color::srgb(0.0, 1.0, 0.0)),
+        (Vec2::new(0.0, 5.0), Color::srgb(0.0, 0.0, 1.0)),
+    ]);
+    let masked = draw.scale(2.0).mask(|m| {
+        m.ellipse().radius(10.0);
+    });
+    masked
+        .scissor(geom::Rect::from_w_h(30.0, 30.0))
+        .rect()
+        .w_h(40.0, 40.0)
+        .color(Color::srgb(1.0, 1.0, 0.0));
+}
+
+fn svg(draw: &Draw) -> String {
+    draw.to_svg_string(geom::Rect::from_w_h(200.0, 200.0))
+}
+
+#[test]
+fn test_frame_round_trip() {
+    let draw = new_draw();
+    draw_scene(&draw);
+    let json = serde_json::to_string(&Frame::from_draw(&draw)).unwrap();
+    let frame: Frame = serde_json::from_str(&json).unwrap();
+
+    let replayed = new_draw();
+    frame.draw(&replayed);
+    assert_eq!(svg(&replayed), svg(&draw));
+    // The recorded draw itself is left untouched.
+    assert!(draw.drain_commands().count() > 0);
+}
+
+#[test]
+fn test_replay_after_drawing() {
+    // Replayed primitives must refer to the frame's buffers, not those already drawn.
+    let draw = new_draw();
+    draw.polyline()
+        .points([Vec2::new(-50.0, 0.0), Vec2::new(-50.0, 50.0)]);
+    draw_scene(&draw);
+
+    let recorded = new_draw();
+    draw_scene(&recorded);
+    let frame = Frame::from_draw(&recorded);
+    let replayed = new_draw();
+    replayed
+        .polyline()
+        .points([Vec2::new(-50.0, 0.0), Vec2::new(-50.0, 50.0)]);
+    frame.draw(&replayed);
+    assert_eq!(svg(&replayed), svg(&draw));
+}
+
+#[test]
+fn test_record_and_play() {
+    let path = std::env::temp_dir().join("nannou_draw_record_tests.jsonl");
+    let mut recorder = DrawRecorder::create(&path).unwrap();
+    let draw = new_draw();
+    for i in 0..2 {
+        draw.ellipse().x(i as f32 * 10.0).radius(5.0);
+        recorder.write_frame(&Frame::from_draw(&draw)).unwrap();
+        draw.drain_commands().for_each(drop);
+    }
+    drop(recorder);
+
+    let mut player = DrawPlayer::open(&path).unwrap();
+    let replayed = new_draw();
+    assert!(player.play(&replayed).unwrap());
+    assert!(player.play(&replayed).unwrap());
+    assert!(!player.play(&replayed).unwrap());
+    let ellipses = replayed
+        .drain_commands()
+        .filter(|cmd| matches!(cmd, DrawCommand::Primitive(_)))
+        .count();
+    assert_eq!(ellipses, 2);
+    std::fs::remove_file(path).unwrap();
+}
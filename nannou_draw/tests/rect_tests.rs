@@ -0,0 +1,130 @@
+//! Tests for rects with rounded corners.
+
+mod common;
+
+use std::f32::consts::PI;
+
+use bevy::prelude::*;
+use nannou_draw::draw::Draw;
+use nannou_draw::draw::mesh::MeshExt;
+
+use common::{mesh_area, mesh_points, new_draw, tessellate};
+
+// The position and texture coordinates of each vertex drawn by the given function.
+fn tex_coords(draw: &Draw, f: impl FnOnce(&Draw)) -> Vec<(Vec2, Vec2)> {
+    let picture = draw.record_picture(f);
+    let mesh = picture.meshes().next().unwrap();
+    mesh.points()
+        .iter()
+        .map(|p| Vec2::new(p[0], p[1]))
+        .zip(mesh.tex_coords().iter().copied().map(Vec2::from))
+        .collect()
+}
+
+#[test]
+fn test_rounded_rect_outline() {
+    let mesh = tessellate(|d| {
+        d.rect().w_h(80.0, 40.0).corner_radius(10.0);
+    });
+    let points = mesh_points(&mesh);
+    assert!(points.len() > 4, "{points:?}");
+    for p in &points {
+        assert!(
+            p.x.abs() <= 40.0 + 1e-3 && p.y.abs() <= 20.0 + 1e-3,
+            "{p:?}"
+        );
+    }
+    // The corners themselves are cut away.
+    assert!(
+        !points
+            .iter()
+            .any(|p| p.abs_diff_eq(Vec2::new(-40.0, 20.0), 1e-3))
+    );
+    let area = 80.0 * 40.0 - (4.0 - PI) * 10.0 * 10.0;
+    assert!(
+        (mesh_area(&mesh) - area).abs() < area * 0.01,
+        "{}",
+        mesh_area(&mesh)
+    );
+}
+
+#[test]
+fn test_oversized_radii_are_scaled() {
+    // Radii larger than the rect are scaled down so that the outline stays within it.
+    let mesh = tessellate(|d| {
+        d.rect()
+            .w_h(20.0, 20.0)
+            .corner_radii([100.0, 0.0, 100.0, 0.0]);
+    });
+    let points = mesh_points(&mesh);
+    for p in &points {
+        assert!(
+            p.x.abs() <= 10.0 + 1e-3 && p.y.abs() <= 10.0 + 1e-3,
+            "{p:?}"
+        );
+    }
+    // Sharp corners are left in place.
+    assert!(
+        points
+            .iter()
+            .any(|p| p.abs_diff_eq(Vec2::new(10.0, 10.0), 1e-3)),
+        "{points:?}"
+    );
+}
+
+#[test]
+fn test_scaled_corners_are_subdivided() {
+    // Corners are subdivided by their drawn size rather than their size before scaling.
+    let small = tessellate(|d| {
+        d.rect().w_h(8.0, 8.0).corner_radius(2.0);
+    });
+    let scaled = tessellate(|d| {
+        d.scale(50.0).rect().w_h(8.0, 8.0).corner_radius(2.0);
+    });
+    assert!(
+        scaled.count_vertices() > small.count_vertices() * 2,
+        "{} vs {}",
+        scaled.count_vertices(),
+        small.count_vertices()
+    );
+}
+
+#[test]
+fn test_zero_radius_is_sharp() {
+    let sharp = tessellate(|d| {
+        d.rect().w_h(30.0, 20.0);
+    });
+    let rounded = tessellate(|d| {
+        d.rect().w_h(30.0, 20.0).corner_radius(0.0);
+    });
+    assert_eq!(rounded.count_vertices(), 4);
+    assert_eq!(mesh_points(&rounded), mesh_points(&sharp));
+}
+
+#[test]
+fn test_rounded_tex_coords_match_square() {
+    let draw = new_draw();
+    let square = tex_coords(&draw, |d| {
+        d.rect().w_h(100.0, 100.0);
+    });
+    let rounded = tex_coords(&draw, |d| {
+        d.rect().w_h(100.0, 100.0).corner_radius(10.0);
+    });
+    // The top-left corner of the rect maps to the origin of the texture.
+    let expected = |p: Vec2| Vec2::new((p.x + 50.0) / 100.0, (50.0 - p.y) / 100.0);
+    assert_eq!(square.len(), 4);
+    for &(p, uv) in square.iter().chain(&rounded) {
+        assert!(uv.distance(expected(p)) < 1e-4, "{p:?}: {uv:?}");
+    }
+    // The vertices nearest each corner of the rounded rect map near those of the square.
+    for &(corner, square_uv) in &square {
+        let &(_, uv) = rounded
+            .iter()
+            .min_by(|a, b| a.0.distance(corner).total_cmp(&b.0.distance(corner)))
+            .unwrap();
+        assert!(
+            uv.distance(square_uv) < 0.1,
+            "{corner:?}: {uv:?} vs {square_uv:?}"
+        );
+    }
+}
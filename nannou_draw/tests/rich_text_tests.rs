@@ -0,0 +1,63 @@
+//! Tests for drawing text made of styled spans.
+#![cfg(feature = "serde")]
+
+mod common;
+
+use bevy::prelude::*;
+use nannou_draw::draw::{Draw, DrawCommand};
+use nannou_draw::record::Frame;
+use serde_json::Value;
+
+use common::new_draw;
+
+// The text and serialized spans of each text primitive drawn so far.
+fn texts(draw: &Draw) -> Vec<(String, Vec<Value>)> {
+    let frame = Frame::from_draw(draw);
+    frame
+        .commands
+        .iter()
+        .filter_map(|cmd| match cmd {
+            DrawCommand::Primitive(prim) => serde_json::to_value(prim).ok(),
+            _ => None,
+        })
+        .filter_map(|prim| prim.get("Text").cloned())
+        .map(|text| {
+            let start = text["text"]["start"].as_u64().unwrap() as usize;
+            let end = text["text"]["end"].as_u64().unwrap() as usize;
+            let s = frame.buffers.text_buffer[start..end].to_string();
+            let spans = text["spans"].as_array().cloned().unwrap_or_default();
+            (s, spans)
+        })
+        .collect()
+}
+
+#[test]
+fn test_spans_concatenate() {
+    let draw = new_draw();
+    draw.rich_text()
+        .span("bold", |s| s.bold())
+        .span(" and ", |s| s)
+        .span("red", |s| s.color(Color::srgb(1.0, 0.0, 0.0)).underline());
+    let texts = texts(&draw);
+    assert_eq!(texts.len(), 1);
+    let (s, spans) = &texts[0];
+    assert_eq!(s, "bold and red");
+    assert_eq!(spans.len(), 3);
+    assert_eq!(spans[2]["range"]["start"], 9);
+    assert_eq!(spans[2]["range"]["end"], 12);
+    assert_eq!(spans[2]["style"]["underline"], true);
+    assert_eq!(spans[0]["style"]["weight"], 700.0);
+}
+
+#[test]
+fn test_spans_interleaved_with_other_text() {
+    let draw = new_draw();
+    let rich = draw.rich_text().span("one", |s| s.italic());
+    let other = draw.text("other");
+    let rich = rich.span(" two", |s| s.strikethrough());
+    drop(other);
+    drop(rich);
+    let texts: Vec<String> = texts(&draw).into_iter().map(|(s, _)| s).collect();
+    assert!(texts.contains(&"one two".to_string()));
+    assert!(texts.contains(&"other".to_string()));
+}
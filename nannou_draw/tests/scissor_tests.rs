@@ -0,0 +1,67 @@
+//! Tests for clipping drawings to a rect via `Draw::scissor`.
+
+mod common;
+
+use bevy::prelude::*;
+use nannou_core::geom;
+use nannou_draw::draw::{Draw, DrawCommand, Scissor};
+
+use common::new_draw;
+
+// The scissor recorded by the first drawing made with the draw.
+fn recorded_scissor(draw: &Draw) -> Scissor {
+    draw.rect();
+    draw.drain_commands()
+        .find_map(|cmd| match cmd {
+            DrawCommand::Scissor(scissor) => Some(scissor),
+            _ => None,
+        })
+        .expect("no scissor recorded")
+}
+
+fn rect(min: [f32; 2], max: [f32; 2]) -> geom::Rect {
+    geom::Rect::from_corners(Vec2::from(min), Vec2::from(max))
+}
+
+#[test]
+fn test_scissor_ignores_transform() {
+    let draw = new_draw();
+    let r = rect([-10.0, -10.0], [10.0, 10.0]);
+    let scissor = recorded_scissor(&draw.x(100.0).scale(2.0).scissor(r));
+    assert_eq!(scissor, Scissor::Rect(r));
+}
+
+#[test]
+fn test_nested_scissors_overlap() {
+    let draw = new_draw();
+    let outer = draw.scissor(rect([-50.0, -50.0], [50.0, 50.0]));
+    let inner = outer.scissor(rect([0.0, 0.0], [100.0, 100.0]));
+    let Scissor::Rect(overlap) = recorded_scissor(&inner) else {
+        panic!("expected a scissor rect");
+    };
+    assert_eq!(overlap.bottom_left(), Vec2::new(0.0, 0.0));
+    assert_eq!(overlap.top_right(), Vec2::new(50.0, 50.0));
+}
+
+#[test]
+fn test_disjoint_scissors_have_no_overlap() {
+    let draw = new_draw();
+    let left = draw.scissor(rect([-100.0, -10.0], [-50.0, 10.0]));
+    let none = left.scissor(rect([50.0, -10.0], [100.0, 10.0]));
+    assert_eq!(recorded_scissor(&none), Scissor::NoOverlap);
+    // Nothing overlaps with an empty scissor.
+    let still_none = none.scissor(rect([-100.0, -10.0], [100.0, 10.0]));
+    assert_eq!(recorded_scissor(&still_none), Scissor::NoOverlap);
+}
+
+#[test]
+fn test_unclipped_drawings_record_no_scissor() {
+    let draw = new_draw();
+    draw.rect();
+    draw.ellipse();
+    let scissors = draw
+        .drain_commands()
+        .filter(|cmd| matches!(cmd, DrawCommand::Scissor(_)))
+        .count();
+    assert_eq!(scissors, 0);
+}
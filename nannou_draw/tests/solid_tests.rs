@@ -0,0 +1,105 @@
+//! CPU-side tests for the 3D solid primitives.
+
+mod common;
+
+use bevy::prelude::*;
+use nannou_draw::draw::Draw;
+use nannou_draw::draw::mesh::MeshExt;
+
+use common::new_draw;
+
+// Tessellate the drawings made by `f` into a single mesh.
+fn mesh(f: impl FnOnce(&Draw)) -> Mesh {
+    let picture = new_draw().record_picture(f);
+    let mut meshes = picture.meshes();
+    let mesh = meshes.next().unwrap().clone();
+    assert!(meshes.next().is_none());
+    mesh
+}
+
+// Assert that every triangle winds counter-clockwise around the normals of its vertices.
+fn assert_wound_outwards(mesh: &Mesh) {
+    let (points, normals) = (mesh.points(), mesh.normals());
+    assert_eq!(mesh.count_indices() % 3, 0);
+    for tri in 0..mesh.count_indices() / 3 {
+        let [a, b, c] = [0, 1, 2].map(|i| mesh.get_index(tri * 3 + i) as usize);
+        let [pa, pb, pc] = [a, b, c].map(|i| Vec3::from(points[i]));
+        let face = (pb - pa).cross(pc - pa);
+        // Skip the degenerate triangles at the poles and apexes.
+        if face.length() < 1e-3 {
+            continue;
+        }
+        for i in [a, b, c] {
+            let normal = Vec3::from(normals[i]);
+            assert!((normal.length() - 1.0).abs() < 1e-3, "{normal:?}");
+            assert!(face.dot(normal) > 0.0, "triangle {tri} faces inwards");
+        }
+    }
+}
+
+#[test]
+fn test_cuboid() {
+    let mesh = mesh(|draw| {
+        draw.cuboid().w_h_d(10.0, 20.0, 30.0);
+    });
+    assert_eq!(mesh.count_vertices(), 24);
+    assert_eq!(mesh.count_indices(), 36);
+    assert_wound_outwards(&mesh);
+    for (p, n) in mesh.points().iter().zip(mesh.normals()) {
+        let (p, n) = (Vec3::from(*p), Vec3::from(*n));
+        assert!((p.abs() - Vec3::new(5.0, 10.0, 15.0)).abs().max_element() < 1e-3);
+        // Each normal points outwards along the axis of its face.
+        assert_eq!(n.abs().max_element(), 1.0);
+        assert!(p.dot(n) > 0.0);
+    }
+}
+
+#[test]
+fn test_sphere() {
+    let mesh = mesh(|draw| {
+        draw.sphere().radius(50.0).resolution(8);
+    });
+    // Rings default to half the resolution, with a seam of repeated vertices.
+    assert_eq!(mesh.count_vertices(), 9 * 5);
+    assert_wound_outwards(&mesh);
+    for (p, n) in mesh.points().iter().zip(mesh.normals()) {
+        let (p, n) = (Vec3::from(*p), Vec3::from(*n));
+        assert!((p.length() - 50.0).abs() < 1e-3, "{p:?}");
+        assert!(p.normalize().abs_diff_eq(n, 1e-3), "{p:?} {n:?}");
+    }
+}
+
+#[test]
+fn test_solids_wind_outwards() {
+    let meshes = [
+        mesh(|draw| {
+            draw.cylinder().radius(20.0).h(50.0);
+        }),
+        mesh(|draw| {
+            draw.cone().radius(20.0).h(50.0).resolution(12);
+        }),
+        mesh(|draw| {
+            draw.torus().radius(40.0).tube_radius(10.0);
+        }),
+        mesh(|draw| {
+            draw.sphere().w_h_d(20.0, 60.0, 40.0);
+        }),
+    ];
+    for mesh in &meshes {
+        assert_wound_outwards(mesh);
+    }
+}
+
+#[test]
+fn test_plane_subdivisions() {
+    let mesh = mesh(|draw| {
+        draw.plane().w_h(40.0, 20.0).subdivisions(4);
+    });
+    assert_eq!(mesh.count_vertices(), 25);
+    assert_eq!(mesh.count_indices(), 4 * 4 * 6);
+    assert_wound_outwards(&mesh);
+    assert!(mesh.normals().iter().all(|n| *n == [0.0, 0.0, 1.0]));
+    let tex_coords = mesh.tex_coords();
+    assert_eq!(tex_coords[0], [0.0, 0.0]);
+    assert_eq!(tex_coords[24], [1.0, 1.0]);
+}
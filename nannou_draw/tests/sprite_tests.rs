@@ -0,0 +1,92 @@
+//! Tests for drawing sprite sheet frames and sprite animations.
+
+mod common;
+
+use bevy::image::TextureAtlasLayout;
+use bevy::prelude::*;
+use nannou_draw::draw::mesh::MeshExt;
+use nannou_draw::draw::sprite::Playback;
+use nannou_draw::draw::{Draw, SpriteAnimation, SpriteSheet};
+
+use common::new_draw;
+
+fn assert_approx(a: f32, b: f32) {
+    assert!((a - b).abs() < 1e-4, "{a} != {b}");
+}
+
+// Check that each vertex of a 100x100 sprite maps to the given frame, top-left origin.
+fn assert_frame_tex_coords(draw: &Draw, f: impl FnOnce(&Draw), min: Vec2, max: Vec2) {
+    let picture = draw.record_picture(f);
+    let mesh = picture.meshes().next().unwrap();
+    assert!(!mesh.points().is_empty());
+    for (p, uv) in mesh.points().iter().zip(mesh.tex_coords()) {
+        let u = (p[0] + 50.0) / 100.0;
+        let v = (50.0 - p[1]) / 100.0;
+        assert_approx(uv[0], min.x + u * (max.x - min.x));
+        assert_approx(uv[1], min.y + v * (max.y - min.y));
+    }
+}
+
+#[test]
+fn test_grid_frames() {
+    let sheet = SpriteSheet::from_grid(Handle::default(), 4, 2);
+    assert_eq!(sheet.len(), 8);
+    let area = sheet.area(5).unwrap();
+    assert_eq!(area.bottom_left(), Vec2::new(0.25, 0.5));
+    assert_eq!(area.top_right(), Vec2::new(0.5, 1.0));
+    assert!(sheet.area(8).is_none());
+    assert!(sheet.frame_size(0).is_none());
+}
+
+#[test]
+fn test_layout_frames() {
+    let layout = TextureAtlasLayout::from_grid(UVec2::new(16, 32), 4, 1, None, None);
+    let sheet = SpriteSheet::from_layout(Handle::default(), &layout);
+    assert_eq!(sheet.len(), 4);
+    assert_eq!(sheet.frame_size(2), Some(Vec2::new(16.0, 32.0)));
+    assert_eq!(sheet.area(2).unwrap().bottom_left(), Vec2::new(0.5, 0.0));
+}
+
+#[test]
+fn test_sprite_tex_coords() {
+    let draw = new_draw();
+    let sheet = SpriteSheet::from_grid(Handle::default(), 4, 2);
+    let (min, max) = (Vec2::new(0.25, 0.5), Vec2::new(0.5, 1.0));
+    assert_frame_tex_coords(
+        &draw,
+        |d| {
+            d.sprite(&sheet, 5).w_h(100.0, 100.0);
+        },
+        min,
+        max,
+    );
+    // Rounding the corners keeps the same mapping.
+    assert_frame_tex_coords(
+        &draw,
+        |d| {
+            d.sprite(&sheet, 5).w_h(100.0, 100.0).corner_radius(20.0);
+        },
+        min,
+        max,
+    );
+}
+
+#[test]
+fn test_animation_frames() {
+    let anim = SpriteAnimation::new(2..6, 10.0);
+    let frames: Vec<usize> = (0..10).map(|i| anim.frame(i as f32 * 0.1 + 0.05)).collect();
+    assert_eq!(frames, [2, 3, 4, 5, 2, 3, 4, 5, 2, 3]);
+    assert!(!anim.is_finished(100.0));
+
+    let once = anim.clone().once();
+    assert_eq!(once.frame(0.35), 5);
+    assert_eq!(once.frame(10.0), 5);
+    assert!(!once.is_finished(0.35));
+    assert!(once.is_finished(0.45));
+
+    let ping_pong = anim.playback(Playback::PingPong);
+    let frames: Vec<usize> = (0..8)
+        .map(|i| ping_pong.frame(i as f32 * 0.1 + 0.05))
+        .collect();
+    assert_eq!(frames, [2, 3, 4, 5, 4, 3, 2, 3]);
+}
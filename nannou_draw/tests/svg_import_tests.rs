@@ -0,0 +1,102 @@
+//! Tests for loading SVG documents via `Svg::from_data` and drawing them via `Draw::svg`.
+
+mod common;
+
+use bevy::prelude::*;
+use lyon::path::PathEvent;
+use nannou_draw::draw::mesh::MeshExt;
+use nannou_draw::draw::properties::gradient::GradientKind;
+use nannou_draw::svg::Svg;
+
+use common::{mesh_points, tessellate};
+
+const DOC: &[u8] = br##"<svg xmlns="http://www.w3.org/2000/svg" width="100" height="50">
+  <g transform="translate(10 5)">
+    <rect x="0" y="0" width="20" height="10" fill="#ff0000" stroke="#0000ff" stroke-width="2"/>
+  </g>
+  <path d="M0 0 L10 0" fill="none" stroke="#00ff00" stroke-width="4" opacity="0.5"/>
+</svg>"##;
+
+#[test]
+fn test_svg_from_data() {
+    let svg = Svg::from_data(DOC).unwrap();
+    assert_eq!(svg.size(), Vec2::new(100.0, 50.0));
+    let paths = svg.paths();
+    assert_eq!(paths.len(), 2);
+
+    // The rect is translated, flipped to y-up and centred on the document.
+    let rect = &paths[0];
+    let begin = rect.events.iter().find_map(|e| match e {
+        PathEvent::Begin { at } => Some(*at),
+        _ => None,
+    });
+    assert_eq!(begin, Some(lyon::math::point(-40.0, 20.0)));
+    let fill = rect.fill.as_ref().unwrap();
+    assert_eq!(Srgba::from(fill.color), Srgba::rgb(1.0, 0.0, 0.0));
+    let stroke = rect.stroke.as_ref().unwrap();
+    assert_eq!(stroke.options.line_width, 2.0);
+
+    // Group opacity is applied to the paint.
+    let line = &paths[1];
+    assert!(line.fill.is_none());
+    let stroke = line.stroke.as_ref().unwrap();
+    assert_eq!(Srgba::from(stroke.color).alpha, 0.5);
+}
+
+#[test]
+fn test_draw_svg_inherits_drawing_properties() {
+    let svg = Svg::from_data(DOC).unwrap();
+    // Double the size of the document and override its colors.
+    let mesh = tessellate(|d| {
+        d.svg(&svg).x(100.0).w(200.0).color(Color::WHITE);
+    });
+    let white = LinearRgba::WHITE.to_f32_array();
+    assert!(mesh.colors().iter().all(|c| *c == white));
+    // The rect is scaled about the document's centre, then offset.
+    let points = mesh_points(&mesh);
+    for corner in [Vec2::new(20.0, 40.0), Vec2::new(60.0, 20.0)] {
+        assert!(
+            points.iter().any(|p| p.abs_diff_eq(corner, 1e-3)),
+            "{points:?}"
+        );
+    }
+    // The stroke of the line is doubled in width to 8, centred on y = 50.
+    let top = points.iter().map(|p| p.y).fold(f32::MIN, f32::max);
+    assert!((top - 54.0).abs() < 1e-3, "{top}");
+}
+
+const GRADIENT_DOC: &[u8] = br##"<svg xmlns="http://www.w3.org/2000/svg" width="100" height="50">
+  <linearGradient id="g" x1="0" y1="0" x2="100" y2="0" gradientUnits="userSpaceOnUse">
+    <stop offset="0" stop-color="#ff0000"/>
+    <stop offset="1" stop-color="#0000ff"/>
+  </linearGradient>
+  <rect x="0" y="0" width="100" height="50" fill="url(#g)"/>
+</svg>"##;
+
+#[test]
+fn test_svg_gradient_fill() {
+    let svg = Svg::from_data(GRADIENT_DOC).unwrap();
+    let fill = svg.paths()[0].fill.as_ref().unwrap();
+    assert_eq!(Srgba::from(fill.color), Srgba::rgb(1.0, 0.0, 0.0));
+    let gradient = fill.gradient.as_ref().unwrap();
+    // The end points are mapped to the document's y-up, centred space.
+    let kind = GradientKind::Linear {
+        start: Vec2::new(-50.0, 25.0),
+        end: Vec2::new(50.0, 25.0),
+    };
+    assert_eq!(gradient.kind, Some(kind));
+    assert_eq!(gradient.stops.len(), 2);
+
+    // Vertices are colored by their position along the gradient.
+    let mesh = tessellate(|d| {
+        d.svg(&svg);
+    });
+    for (point, color) in mesh.points().iter().zip(mesh.colors()) {
+        let expected = if point[0] < 0.0 {
+            LinearRgba::RED
+        } else {
+            LinearRgba::BLUE
+        };
+        assert_eq!(LinearRgba::from_f32_array(*color), expected, "{point:?}");
+    }
+}
@@ -0,0 +1,85 @@
+//! Tests for writing a `Draw` to SVG via `Draw::to_svg_string`.
+
+mod common;
+
+use bevy::prelude::*;
+use nannou_core::geom;
+
+use common::new_draw;
+
+fn paths(svg: &str) -> Vec<&str> {
+    svg.lines().filter(|l| l.starts_with("<path")).collect()
+}
+
+#[test]
+fn test_svg_document() {
+    let draw = new_draw();
+    let svg = draw.to_svg_string(geom::Rect::from_w_h(200.0, 100.0));
+    assert!(svg.starts_with("<?xml"));
+    assert!(svg.contains(r#"viewBox="-100 -50 200 100""#));
+    assert!(svg.trim_end().ends_with("</svg>"));
+    assert!(paths(&svg).is_empty());
+}
+
+#[test]
+fn test_svg_rect_fill() {
+    let draw = new_draw();
+    draw.background().color(Color::BLACK);
+    draw.rect()
+        .x_y(10.0, 20.0)
+        .w_h(20.0, 10.0)
+        .color(Color::srgba(1.0, 0.0, 0.0, 0.5));
+    let svg = draw.to_svg_string(geom::Rect::from_w_h(200.0, 100.0));
+    assert!(svg.contains(r##"<rect x="-100" y="-50" width="200" height="100" fill="#000000"/>"##));
+    let paths = paths(&svg);
+    assert_eq!(paths.len(), 1);
+    // The y axis is flipped for SVG's y-down coordinate space.
+    assert!(paths[0].contains("0 -15"), "{}", paths[0]);
+    assert!(paths[0].contains("20 -25"), "{}", paths[0]);
+    assert!(paths[0].contains(r##"fill="#ff0000" fill-opacity="0.5""##));
+}
+
+#[test]
+fn test_svg_stroke_scaled_by_transform() {
+    let draw = new_draw();
+    draw.scale(2.0)
+        .line()
+        .start(Vec2::ZERO)
+        .end(Vec2::new(10.0, 0.0))
+        .weight(3.0)
+        .color(Color::WHITE);
+    let svg = draw.to_svg_string(geom::Rect::from_w_h(100.0, 100.0));
+    let paths = paths(&svg);
+    assert_eq!(paths.len(), 1);
+    assert!(paths[0].contains(r#"d="M0 0 L20 0""#), "{}", paths[0]);
+    assert!(paths[0].contains(r#"fill="none""#));
+    assert!(paths[0].contains(r#"stroke-width="6""#), "{}", paths[0]);
+}
+
+#[test]
+fn test_svg_leaves_commands_in_place() {
+    let draw = new_draw();
+    draw.ellipse().radius(10.0);
+    let rect = geom::Rect::from_w_h(100.0, 100.0);
+    assert_eq!(draw.to_svg_string(rect), draw.to_svg_string(rect));
+    assert!(draw.drain_commands().count() > 0);
+}
+
+#[test]
+fn test_svg_scissor_and_mask() {
+    let draw = new_draw();
+    let scissored = draw.scissor(geom::Rect::from_w_h(50.0, 50.0));
+    scissored.rect();
+    let masked = draw.inverse_mask(|m| {
+        m.ellipse().radius(10.0);
+    });
+    masked.rect();
+    draw.rect();
+    let svg = draw.to_svg_string(geom::Rect::from_w_h(100.0, 100.0));
+    assert!(svg.contains(r#"<clipPath id="clip0"><rect x="-25" y="-25" width="50" height="50"/>"#));
+    assert!(svg.contains(r#"<g clip-path="url(#clip0)">"#));
+    assert!(svg.contains(r#"<mask id="clip1""#));
+    assert!(svg.contains(r#"<g mask="url(#clip1)">"#));
+    assert_eq!(svg.matches("<g ").count(), svg.matches("</g>").count());
+    assert_eq!(paths(&svg).len(), 3);
+}
@@ -0,0 +1,47 @@
+//! Tests for building text layouts.
+
+use nannou_draw::text::layout::{self, Builder};
+use nannou_draw::text::{FontFeature, FontStyle};
+
+#[test]
+fn test_typographic_defaults() {
+    let layout = Builder::default().build();
+    assert_eq!(layout.font_weight, layout::DEFAULT_FONT_WEIGHT);
+    assert_eq!(layout.font_style, FontStyle::Normal);
+    assert_eq!(layout.font_stretch, 1.0);
+    assert_eq!(layout.letter_spacing, 0.0);
+    assert_eq!(layout.word_spacing, 0.0);
+    assert!(layout.font_features.is_empty());
+}
+
+#[test]
+fn test_font_features_replace_by_tag() {
+    let layout = Builder::default()
+        .ligatures(true)
+        .tabular_numerals()
+        .ligatures(false)
+        .build();
+    assert_eq!(
+        layout.font_features,
+        [FontFeature::on(*b"tnum"), FontFeature::off(*b"liga")]
+    );
+}
+
+#[test]
+fn test_layout_round_trip() {
+    let layout = Builder::default()
+        .bold()
+        .italic()
+        .font_stretch(0.75)
+        .letter_spacing(2.0)
+        .word_spacing(4.0)
+        .small_caps()
+        .build();
+    let copy = Builder::default().layout(&layout).build();
+    assert_eq!(copy.font_weight, 700.0);
+    assert_eq!(copy.font_style, FontStyle::Italic);
+    assert_eq!(copy.font_stretch, 0.75);
+    assert_eq!(copy.letter_spacing, 2.0);
+    assert_eq!(copy.word_spacing, 4.0);
+    assert_eq!(copy.font_features, layout.font_features);
+}
@@ -0,0 +1,59 @@
+//! Tests for rendering glyph outlines as geometry.
+
+mod common;
+
+use bevy::prelude::*;
+use lyon::path::Path;
+use nannou_draw::draw::mesh::MeshExt;
+use nannou_draw::text::glyph;
+
+fn square() -> Path {
+    let mut builder = Path::builder();
+    builder.begin(lyon::math::point(0.0, 0.0));
+    builder.line_to(lyon::math::point(1.0, 0.0));
+    builder.line_to(lyon::math::point(1.0, 1.0));
+    builder.line_to(lyon::math::point(0.0, 1.0));
+    builder.end(true);
+    builder.build()
+}
+
+#[test]
+fn test_extrude_square() {
+    let mesh = glyph::extrude(&square(), 2.0, 0.01);
+    // Two faces of four vertices, plus four vertices for each of the four walls.
+    assert_eq!(mesh.points().len(), 4 + 4 + 4 * 4);
+    // Two triangles for each face and wall.
+    assert_eq!(mesh.count_indices(), 6 * 6);
+    for (p, n) in mesh.points().iter().zip(mesh.normals()) {
+        assert!(p[2] == 1.0 || p[2] == -1.0);
+        assert!((Vec3::from(*n).length() - 1.0).abs() < 1e-6);
+    }
+    // The front face points towards the viewer and the back face away.
+    assert_eq!(mesh.normals()[0], [0.0, 0.0, 1.0]);
+    assert_eq!(mesh.normals()[4], [0.0, 0.0, -1.0]);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_stroke_mode() {
+    use nannou_draw::draw::DrawCommand;
+    use nannou_draw::record::Frame;
+
+    let draw = common::new_draw();
+    draw.text("stroked").stroke().stroke_weight(3.0);
+    draw.text("outlined").outline();
+    let frame = Frame::from_draw(&draw);
+    let texts: Vec<serde_json::Value> = frame
+        .commands
+        .iter()
+        .filter_map(|cmd| match cmd {
+            DrawCommand::Primitive(prim) => serde_json::to_value(prim).ok(),
+            _ => None,
+        })
+        .filter_map(|prim| prim.get("Text").cloned())
+        .collect();
+    assert_eq!(texts.len(), 2);
+    assert_eq!(texts[0]["mode"], "Stroke");
+    assert_eq!(texts[0]["stroke"]["line_width"], 3.0);
+    assert_eq!(texts[1]["mode"], "Outline");
+}
@@ -0,0 +1,71 @@
+//! Tests for laying out text along a path.
+
+use bevy::prelude::*;
+use lyon::path::Path;
+use nannou_draw::text::Justify;
+use nannou_draw::text::path::{self, ArcLengthPath, Overflow};
+
+fn assert_approx(a: Vec2, b: Vec2) {
+    assert!(a.distance(b) < 1e-4, "{a} != {b}");
+}
+
+// An open path along two sides of a square.
+fn corner() -> ArcLengthPath {
+    let mut builder = Path::builder();
+    builder.begin(lyon::math::point(0.0, 0.0));
+    builder.line_to(lyon::math::point(10.0, 0.0));
+    builder.line_to(lyon::math::point(10.0, 10.0));
+    builder.end(false);
+    ArcLengthPath::new(&builder.build(), 0.01)
+}
+
+#[test]
+fn test_sample_by_arc_length() {
+    let path = corner();
+    assert_eq!(path.length(), 20.0);
+    let (p, dir) = path.sample(5.0, Overflow::Hide).unwrap();
+    assert_approx(p, Vec2::new(5.0, 0.0));
+    assert_approx(dir, Vec2::X);
+    let (p, dir) = path.sample(15.0, Overflow::Hide).unwrap();
+    assert_approx(p, Vec2::new(10.0, 5.0));
+    assert_approx(dir, Vec2::Y);
+}
+
+#[test]
+fn test_overflow() {
+    let path = corner();
+    assert!(path.sample(-1.0, Overflow::Hide).is_none());
+    assert!(path.sample(21.0, Overflow::Hide).is_none());
+    let (p, _) = path.sample(-2.0, Overflow::Extend).unwrap();
+    assert_approx(p, Vec2::new(-2.0, 0.0));
+    let (p, _) = path.sample(22.0, Overflow::Extend).unwrap();
+    assert_approx(p, Vec2::new(10.0, 12.0));
+    let (p, _) = path.sample(25.0, Overflow::Wrap).unwrap();
+    assert_approx(p, Vec2::new(5.0, 0.0));
+}
+
+#[test]
+fn test_start_distance() {
+    assert_eq!(path::start_distance(100.0, 40.0, Justify::Left, 5.0), 5.0);
+    assert_eq!(
+        path::start_distance(100.0, 40.0, Justify::Center, 0.0),
+        30.0
+    );
+    assert_eq!(
+        path::start_distance(100.0, 40.0, Justify::Right, -5.0),
+        55.0
+    );
+}
+
+#[test]
+fn test_glyph_transform() {
+    // A glyph anchored at (3, 4) placed at (10, 0) on a path heading up the y axis.
+    let m = path::glyph_transform(Vec2::new(3.0, 4.0), Vec2::new(10.0, 0.0), Vec2::Y);
+    let anchor = m.transform_point3(Vec3::new(3.0, 4.0, 0.0));
+    assert_approx(anchor.truncate(), Vec2::new(10.0, 0.0));
+    // The glyph's x axis follows the path and its y axis points to the left of the path.
+    let right = m.transform_point3(Vec3::new(4.0, 4.0, 0.0));
+    assert_approx(right.truncate(), Vec2::new(10.0, 1.0));
+    let up = m.transform_point3(Vec3::new(3.0, 5.0, 0.0));
+    assert_approx(up.truncate(), Vec2::new(9.0, 0.0));
+}
@@ -0,0 +1,54 @@
+//! CPU-side tests for variable-width polylines.
+
+mod common;
+
+use bevy::prelude::*;
+use nannou_draw::draw::Draw;
+use nannou_draw::draw::mesh::MeshExt;
+
+use common::new_draw;
+
+// Tessellate the drawings made by `f`, returning the points of the resulting mesh.
+fn points(f: impl FnOnce(&Draw)) -> Vec<Vec2> {
+    let picture = new_draw().record_picture(f);
+    picture
+        .meshes()
+        .flat_map(|mesh| mesh.points().iter().map(|&[x, y, _]| Vec2::new(x, y)))
+        .collect()
+}
+
+// The vertical extent of the points near the given x coordinate.
+fn height_at(points: &[Vec2], x: f32) -> f32 {
+    let ys = points
+        .iter()
+        .filter(|p| (p.x - x).abs() < 1e-3)
+        .map(|p| p.y);
+    let (min, max) = ys.fold((f32::MAX, f32::MIN), |(lo, hi), y| (lo.min(y), hi.max(y)));
+    max - min
+}
+
+#[test]
+fn test_weights_set_width_at_each_point() {
+    let points = points(|draw| {
+        draw.polyline()
+            .weight(100.0)
+            .weighted_points([(vec2(-50.0, 0.0), 4.0), (vec2(50.0, 0.0), 20.0)]);
+    });
+    assert!(!points.is_empty());
+    assert!((height_at(&points, -50.0) - 4.0).abs() < 1e-3, "{points:?}");
+    assert!((height_at(&points, 50.0) - 20.0).abs() < 1e-3, "{points:?}");
+}
+
+#[test]
+fn test_weighted_points_colored() {
+    let picture = new_draw().record_picture(|draw| {
+        draw.polyline().weighted_points_colored([
+            (vec2(-50.0, 0.0), 4.0, Color::srgb(1.0, 0.0, 0.0)),
+            (vec2(50.0, 0.0), 4.0, Color::srgb(0.0, 0.0, 1.0)),
+        ]);
+    });
+    let mesh = picture.meshes().next().unwrap();
+    let colors = mesh.colors();
+    assert!(colors.iter().any(|c| c[0] > 0.99 && c[2] < 0.01));
+    assert!(colors.iter().any(|c| c[2] > 0.99 && c[0] < 0.01));
+}